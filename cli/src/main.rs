@@ -72,23 +72,99 @@ async fn process_setup_extra_metas(
     Ok(signature)
 }
 
-async fn process_create_list(
+async fn process_setup_transfer_hook_extra_metas(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    mode: allow_block_list_client::types::Mode,
+    mint_address: &Pubkey,
+    lists: &[Pubkey],
 ) -> Result<Signature, Box<dyn Error>> {
-    let seed = Keypair::new().pubkey();
-    let list_config =
-        allow_block_list_client::accounts::ListConfig::find_pda(&payer.pubkey(), &seed).0;
-    let ix = allow_block_list_client::instructions::CreateListBuilder::new()
+    let extra_metas = Pubkey::find_program_address(
+        &[b"extra-account-metas", mint_address.as_ref()],
+        &allow_block_list_client::programs::ABL_ID,
+    )
+    .0;
+    let ix = allow_block_list_client::instructions::SetupTransferHookExtraMetasBuilder::new()
         .authority(payer.pubkey())
-        .seed(seed)
-        .mode(mode)
-        .list_config(list_config)
+        .mint(*mint_address)
+        .extra_metas(extra_metas)
+        .add_remaining_accounts(
+            lists
+                .iter()
+                .map(|list| AccountMeta::new_readonly(*list, false))
+                .collect::<Vec<_>>()
+                .as_slice(),
+        )
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
 
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_create_list(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    mode: allow_block_list_client::types::Mode,
+    storage: allow_block_list_client::types::Storage,
+    risk_threshold: u8,
+    identities: bool,
+    mint: Option<Pubkey>,
+    label: &str,
+) -> Result<Signature, Box<dyn Error>> {
+    // mint bound lists are derived from the mint alone
+    let (list_config, seed, ix) = if let Some(mint) = mint {
+        let list_config = allow_block_list_client::pda::find_mint_list_config_pda(&mint).0;
+        let list_registry =
+            allow_block_list_client::accounts::ListRegistry::find_pda(&payer.pubkey()).0;
+        let ix = allow_block_list_client::instructions::CreateMintListBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(list_config)
+            .mint(mint)
+            .mint_config(token_acl_client::accounts::MintConfig::find_pda(&mint).0)
+            .list_registry(list_registry)
+            .mode(mode)
+            .storage(storage)
+            .risk_threshold(risk_threshold)
+            .identities(identities)
+            .label(encode_padded(label)?)
+            .instruction();
+        (list_config, Pubkey::default(), ix)
+    } else {
+        let seed = Keypair::new().pubkey();
+        let list_config =
+            allow_block_list_client::accounts::ListConfig::find_pda(&payer.pubkey(), &seed).0;
+        let list_registry =
+            allow_block_list_client::accounts::ListRegistry::find_pda(&payer.pubkey()).0;
+        let ix = allow_block_list_client::instructions::CreateListBuilder::new()
+            .authority(payer.pubkey())
+            .seed(seed)
+            .mode(mode)
+            .storage(storage)
+            .risk_threshold(risk_threshold)
+            .identities(identities)
+            .label(encode_padded(label)?)
+            .list_config(list_config)
+            .list_registry(list_registry)
+            .instruction();
+        (list_config, seed, ix)
+    };
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
     let blockhash = rpc_client
         .get_latest_blockhash()
         .await
@@ -109,14 +185,63 @@ async fn process_create_list(
     Ok(signature)
 }
 
-async fn process_delete_list(
+/// Encodes a string field, utf-8 padded with zeroes.
+fn encode_padded<const N: usize>(value: &str) -> Result<[u8; N], Box<dyn Error>> {
+    let bytes = value.as_bytes();
+    if bytes.len() > N {
+        return Err(format!("{:?} is longer than {} bytes", value, N).into());
+    }
+
+    let mut encoded = [0u8; N];
+    encoded[..bytes.len()].copy_from_slice(bytes);
+    Ok(encoded)
+}
+
+/// Prints the lists recorded in the registry of `authority`.
+async fn process_get_lists(
+    rpc_client: &Arc<RpcClient>,
+    authority: &Pubkey,
+) -> Result<(), Box<dyn Error>> {
+    let list_registry = allow_block_list_client::accounts::ListRegistry::find_pda(authority).0;
+    let account = rpc_client
+        .get_account(&list_registry)
+        .await
+        .map_err(|err| format!("error: unable to fetch list registry: {}", err))?;
+    let registry = allow_block_list_client::accounts::ListRegistry::from_bytes(&account.data)?;
+
+    for list in registry.lists.iter() {
+        let mode = match list.mode {
+            0 => "allow",
+            1 => "allow-all-eoas",
+            2 => "block",
+            3 => "risk-score",
+            4 => "attributes",
+            _ => "unknown",
+        };
+        let label = String::from_utf8_lossy(&list.label);
+        println!(
+            "{} {} {}",
+            list.list_config,
+            mode,
+            label.trim_end_matches('\0')
+        );
+    }
+
+    Ok(())
+}
+
+async fn process_set_risk_threshold(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
     list_address: &Pubkey,
+    risk_threshold: u8,
 ) -> Result<Signature, Box<dyn Error>> {
-    let ix = allow_block_list_client::instructions::DeleteListBuilder::new()
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetRiskThresholdBuilder::new()
         .authority(payer.pubkey())
         .list_config(*list_address)
+        .mint_config(mint_config)
+        .risk_threshold(risk_threshold)
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
@@ -138,20 +263,20 @@ async fn process_delete_list(
     Ok(signature)
 }
 
-async fn process_add_wallet(
+async fn process_set_list_limits(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    wallet_address: &Pubkey,
     list_address: &Pubkey,
+    max_changes_per_epoch: u64,
+    allow_delay: u64,
 ) -> Result<Signature, Box<dyn Error>> {
-    let ix = allow_block_list_client::instructions::AddWalletBuilder::new()
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetListLimitsBuilder::new()
         .authority(payer.pubkey())
         .list_config(*list_address)
-        .wallet(*wallet_address)
-        .wallet_entry(
-            allow_block_list_client::accounts::WalletEntry::find_pda(list_address, wallet_address)
-                .0,
-        )
+        .mint_config(mint_config)
+        .max_changes_per_epoch(max_changes_per_epoch)
+        .allow_delay(allow_delay)
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
@@ -173,19 +298,18 @@ async fn process_add_wallet(
     Ok(signature)
 }
 
-async fn process_remove_wallet(
+async fn process_set_guardian(
     rpc_client: &Arc<RpcClient>,
     payer: &Arc<dyn Signer>,
-    wallet_address: &Pubkey,
     list_address: &Pubkey,
+    guardian: &Pubkey,
 ) -> Result<Signature, Box<dyn Error>> {
-    let ix = allow_block_list_client::instructions::RemoveWalletBuilder::new()
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetGuardianBuilder::new()
         .authority(payer.pubkey())
         .list_config(*list_address)
-        .wallet_entry(
-            allow_block_list_client::accounts::WalletEntry::find_pda(list_address, wallet_address)
-                .0,
-        )
+        .mint_config(mint_config)
+        .guardian(*guardian)
         .instruction();
 
     let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
@@ -207,67 +331,1136 @@ async fn process_remove_wallet(
     Ok(signature)
 }
 
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
-    let app_matches = Command::new(crate_name!())
-        .about(crate_description!())
-        .version(crate_version!())
-        .subcommand_required(true)
-        .arg_required_else_help(true)
-        .arg({
-            let arg = Arg::new("config_file")
-                .short('C')
-                .long("config")
-                .value_name("PATH")
-                .takes_value(true)
-                .global(true)
-                .help("Configuration file to use");
-            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
-                arg.default_value(config_file)
-            } else {
-                arg
-            }
-        })
-        .arg(
-            Arg::new("payer")
-                .long("payer")
-                .short('k')
-                .value_name("KEYPAIR")
-                .value_parser(SignerSourceParserBuilder::default().allow_all().build())
-                .takes_value(true)
-                .global(true)
-                .help("Filepath or URL to a keypair [default: client keypair]"),
+async fn process_set_list_paused(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    paused: bool,
+    fail_open: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetListPausedBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .paused(paused)
+        .fail_open(fail_open)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_list_metadata(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    name: &str,
+    uri: &str,
+    policy_hash: &str,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::UpdateListMetadataBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .list_metadata(allow_block_list_client::accounts::ListMetadata::find_pda(list_address).0)
+        .mint_config(mint_config)
+        .name(encode_padded(name)?)
+        .uri(encode_padded(uri)?)
+        .policy_hash(decode_hash(policy_hash)?)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+/// Decodes a 32 byte hash from its hex encoding.
+fn decode_hash(hash: &str) -> Result<[u8; 32], Box<dyn Error>> {
+    if hash.len() != 64 || !hash.is_ascii() {
+        return Err("policy hash must be 64 hex characters".into());
+    }
+
+    let mut decoded = [0u8; 32];
+    for (byte, chunk) in decoded.iter_mut().zip(hash.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(chunk)?, 16)?;
+    }
+    Ok(decoded)
+}
+
+async fn process_set_activation_window(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    active_from: i64,
+    active_until: i64,
+    deny_when_inactive: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetActivationWindowBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .active_from(active_from)
+        .active_until(active_until)
+        .deny_when_inactive(deny_when_inactive)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_create_identity(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let id = Keypair::new().pubkey();
+    let identity = allow_block_list_client::accounts::Identity::find_pda(list_address, &id).0;
+    let ix = allow_block_list_client::instructions::CreateIdentityBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .identity(identity)
+        .id(id)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    println!("identity: {:?}", identity);
+    println!("id: {:?}", id);
+
+    Ok(signature)
+}
+
+async fn process_set_identity_status(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    identity_address: &Pubkey,
+    status: allow_block_list_client::types::IdentityStatus,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetIdentityStatusBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .identity(*identity_address)
+        .status(status)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_link_wallet_identity(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    wallet_address: &Pubkey,
+    identity_address: Option<Pubkey>,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let wallet_entry =
+        allow_block_list_client::accounts::WalletEntry::find_pda(list_address, wallet_address).0;
+    let ix = allow_block_list_client::instructions::LinkWalletIdentityBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .wallet_entry(wallet_entry)
+        .identity(identity_address)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_wallet_attributes(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    wallet_address: &Pubkey,
+    attributes: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let wallet_entry =
+        allow_block_list_client::accounts::WalletEntry::find_pda(list_address, wallet_address).0;
+    let ix = allow_block_list_client::instructions::SetWalletAttributesBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .wallet_entry(wallet_entry)
+        .attributes(attributes)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_set_attribute_policy(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    source_address: &Pubkey,
+    required_attributes: u64,
+    forbidden_attributes: u64,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::SetAttributePolicyBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .attributes_source(*source_address)
+        .required_attributes(required_attributes)
+        .forbidden_attributes(forbidden_attributes)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_create_audit_log(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = allow_block_list_client::instructions::CreateAuditLogBuilder::new()
+        .authority(payer.pubkey())
+        .list_config(*list_address)
+        .audit_log(allow_block_list_client::accounts::AuditLog::find_pda(list_address).0)
+        .mint_config(mint_config)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_migrate_account(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    account_address: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let ix = allow_block_list_client::instructions::MigrateAccountBuilder::new()
+        .payer(payer.pubkey())
+        .account(*account_address)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_delete_list(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+    force: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let audit_log = get_list_audit_log(rpc_client, list_address).await?;
+    let list_registry =
+        allow_block_list_client::accounts::ListRegistry::find_pda(&payer.pubkey()).0;
+    let list_metadata = get_list_metadata(rpc_client, list_address).await?;
+    let deposit_vault = get_list_deposit_vault(rpc_client, list_address).await?;
+    let ix = if force {
+        allow_block_list_client::instructions::ForceDeleteListBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .audit_log(audit_log)
+            .list_registry(list_registry)
+            .list_metadata(list_metadata)
+            .deposit_vault(deposit_vault)
+            .instruction()
+    } else {
+        allow_block_list_client::instructions::DeleteListBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .audit_log(audit_log)
+            .list_registry(list_registry)
+            .list_metadata(list_metadata)
+            .deposit_vault(deposit_vault)
+            .instruction()
+    };
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+/// Removes a list the payer no longer controls from the registry of its lists.
+async fn process_prune_list_registry(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    list_address: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let list_exists = rpc_client
+        .get_account_with_commitment(list_address, rpc_client.commitment())
+        .await
+        .map_err(|err| format!("error: unable to fetch list: {}", err))?
+        .value
+        .is_some();
+    let mint_config = if list_exists {
+        get_list_mint_config(rpc_client, list_address).await?
+    } else {
+        None
+    };
+
+    let list_registry =
+        allow_block_list_client::accounts::ListRegistry::find_pda(&payer.pubkey()).0;
+    let ix = allow_block_list_client::instructions::PruneListRegistryBuilder::new()
+        .authority(payer.pubkey())
+        .list_registry(list_registry)
+        .list_config(*list_address)
+        .mint_config(mint_config)
+        .instruction();
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn is_bucket_list(
+    rpc_client: &Arc<RpcClient>,
+    list_address: &Pubkey,
+) -> Result<bool, Box<dyn Error>> {
+    let account = rpc_client
+        .get_account(list_address)
+        .await
+        .map_err(|err| format!("error: unable to fetch list: {}", err))?;
+    let list_config = allow_block_list_client::accounts::ListConfig::from_bytes(&account.data)?;
+
+    Ok(list_config.storage == allow_block_list_client::types::Storage::Buckets as u8)
+}
+
+/// Token-acl mint config the list is bound to, needed to prove the freeze authority.
+async fn get_list_mint_config(
+    rpc_client: &Arc<RpcClient>,
+    list_address: &Pubkey,
+) -> Result<Option<Pubkey>, Box<dyn Error>> {
+    let account = rpc_client
+        .get_account(list_address)
+        .await
+        .map_err(|err| format!("error: unable to fetch list: {}", err))?;
+    let list_config = allow_block_list_client::accounts::ListConfig::from_bytes(&account.data)?;
+
+    if list_config.mint == Pubkey::default() {
+        return Ok(None);
+    }
+
+    Ok(Some(token_acl_client::accounts::MintConfig::find_pda(&list_config.mint).0))
+}
+
+/// Audit log of the list, needed to record wallet changes of lists keeping one.
+async fn get_list_audit_log(
+    rpc_client: &Arc<RpcClient>,
+    list_address: &Pubkey,
+) -> Result<Option<Pubkey>, Box<dyn Error>> {
+    let account = rpc_client
+        .get_account(list_address)
+        .await
+        .map_err(|err| format!("error: unable to fetch list: {}", err))?;
+    let list_config = allow_block_list_client::accounts::ListConfig::from_bytes(&account.data)?;
+
+    if !list_config.audit_log {
+        return Ok(None);
+    }
+
+    Ok(Some(allow_block_list_client::accounts::AuditLog::find_pda(list_address).0))
+}
+
+/// Metadata of the list, closed along with lists having one.
+async fn get_list_metadata(
+    rpc_client: &Arc<RpcClient>,
+    list_address: &Pubkey,
+) -> Result<Option<Pubkey>, Box<dyn Error>> {
+    let account = rpc_client
+        .get_account(list_address)
+        .await
+        .map_err(|err| format!("error: unable to fetch list: {}", err))?;
+    let list_config = allow_block_list_client::accounts::ListConfig::from_bytes(&account.data)?;
+
+    if !list_config.metadata {
+        return Ok(None);
+    }
+
+    Ok(Some(allow_block_list_client::accounts::ListMetadata::find_pda(list_address).0))
+}
+
+async fn get_list_deposit_vault(
+    rpc_client: &Arc<RpcClient>,
+    list_address: &Pubkey,
+) -> Result<Option<Pubkey>, Box<dyn Error>> {
+    let account = rpc_client
+        .get_account(list_address)
+        .await
+        .map_err(|err| format!("error: unable to fetch list: {}", err))?;
+    let list_config = allow_block_list_client::accounts::ListConfig::from_bytes(&account.data)?;
+
+    if !list_config.deposit_vault {
+        return Ok(None);
+    }
+
+    Ok(Some(allow_block_list_client::accounts::DepositVault::find_pda(list_address).0))
+}
+
+async fn process_add_wallet(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    wallet_address: &Pubkey,
+    list_address: &Pubkey,
+    score: u8,
+    idempotent: bool,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = if is_bucket_list(rpc_client, list_address).await? {
+        allow_block_list_client::instructions::AddBucketWalletsBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .bucket(
+                allow_block_list_client::accounts::Bucket::find_pda(
+                    list_address,
+                    &wallet_address.to_bytes()[0],
+                )
+                .0,
+            )
+            .wallets(vec![*wallet_address].into())
+            .instruction()
+    } else if idempotent {
+        let wallet_entry = allow_block_list_client::accounts::WalletEntry::find_pda(
+            list_address,
+            wallet_address,
+        )
+        .0;
+        allow_block_list_client::instructions::AddWalletIdempotentBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .audit_log(get_list_audit_log(rpc_client, list_address).await?)
+            .wallet(*wallet_address)
+            .wallet_entry(wallet_entry)
+            .score(score)
+            .instruction()
+    } else {
+        let wallet_entry = allow_block_list_client::accounts::WalletEntry::find_pda(
+            list_address,
+            wallet_address,
+        )
+        .0;
+        allow_block_list_client::instructions::AddWalletBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .audit_log(get_list_audit_log(rpc_client, list_address).await?)
+            .wallet(*wallet_address)
+            .wallet_entry(wallet_entry)
+            .score(score)
+            .instruction()
+    };
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+async fn process_remove_wallet(
+    rpc_client: &Arc<RpcClient>,
+    payer: &Arc<dyn Signer>,
+    wallet_address: &Pubkey,
+    list_address: &Pubkey,
+) -> Result<Signature, Box<dyn Error>> {
+    let mint_config = get_list_mint_config(rpc_client, list_address).await?;
+    let ix = if is_bucket_list(rpc_client, list_address).await? {
+        allow_block_list_client::instructions::RemoveBucketWalletsBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .bucket(
+                allow_block_list_client::accounts::Bucket::find_pda(
+                    list_address,
+                    &wallet_address.to_bytes()[0],
+                )
+                .0,
+            )
+            .wallets(vec![*wallet_address].into())
+            .instruction()
+    } else {
+        allow_block_list_client::instructions::RemoveWalletBuilder::new()
+            .authority(payer.pubkey())
+            .list_config(*list_address)
+            .mint_config(mint_config)
+            .audit_log(get_list_audit_log(rpc_client, list_address).await?)
+            .wallet_entry(
+                allow_block_list_client::accounts::WalletEntry::find_pda(
+                    list_address,
+                    wallet_address,
+                )
+                .0,
+            )
+            .instruction()
+    };
+
+    let mut transaction = Transaction::new_unsigned(Message::new(&[ix], Some(&payer.pubkey())));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .map_err(|err| format!("error: unable to get latest blockhash: {}", err))?;
+
+    transaction
+        .try_sign(&[payer], blockhash)
+        .map_err(|err| format!("error: failed to sign transaction: {}", err))?;
+
+    let signature = rpc_client
+        .send_and_confirm_transaction_with_spinner(&transaction)
+        .await
+        .map_err(|err| format!("error: send transaction: {}", err))?;
+
+    Ok(signature)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let app_matches = Command::new(crate_name!())
+        .about(crate_description!())
+        .version(crate_version!())
+        .subcommand_required(true)
+        .arg_required_else_help(true)
+        .arg({
+            let arg = Arg::new("config_file")
+                .short('C')
+                .long("config")
+                .value_name("PATH")
+                .takes_value(true)
+                .global(true)
+                .help("Configuration file to use");
+            if let Some(ref config_file) = *solana_cli_config::CONFIG_FILE {
+                arg.default_value(config_file)
+            } else {
+                arg
+            }
+        })
+        .arg(
+            Arg::new("payer")
+                .long("payer")
+                .short('k')
+                .value_name("KEYPAIR")
+                .value_parser(SignerSourceParserBuilder::default().allow_all().build())
+                .takes_value(true)
+                .global(true)
+                .help("Filepath or URL to a keypair [default: client keypair]"),
+        )
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .short('v')
+                .takes_value(false)
+                .global(true)
+                .help("Show additional information"),
+        )
+        .arg(
+            Arg::new("json_rpc_url")
+                .short('u')
+                .long("url")
+                .value_name("URL")
+                .takes_value(true)
+                .global(true)
+                .value_parser(parse_url_or_moniker)
+                .help("JSON RPC URL for the cluster [default: value from configuration file]"),
+        )
+        .subcommand(
+            Command::new("create-list")
+                .about("Creates a new list")
+                .arg(
+                    Arg::new("mode")
+                        .value_name("MODE")
+                        .takes_value(true)
+                        .short('m')
+                        .long("mode")
+                        .possible_values(["allow", "allow-all-eoas", "block", "risk-score", "attributes"])
+                        .required(true)
+                        .help("Specify the mode"),
+                )
+                .arg(
+                    Arg::new("storage")
+                        .value_name("STORAGE")
+                        .takes_value(true)
+                        .short('s')
+                        .long("storage")
+                        .possible_values(["entries", "buckets"])
+                        .default_value("entries")
+                        .help("Specify how wallets are stored, buckets are cheaper for large lists"),
+                )
+                .arg(
+                    Arg::new("risk_threshold")
+                        .value_name("RISK_THRESHOLD")
+                        .takes_value(true)
+                        .short('t')
+                        .long("risk-threshold")
+                        .value_parser(clap::value_parser!(u8))
+                        .default_value("0")
+                        .help("Specify the score at or above which wallets are blocked, for risk-score lists"),
+                )
+                .arg(
+                    Arg::new("identities")
+                        .long("identities")
+                        .takes_value(false)
+                        .help("Allow linking wallets to identities, for allow lists"),
+                )
+                .arg(
+                    Arg::new("mint")
+                        .value_name("MINT_ADDRESS")
+                        .long("mint")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .help("Bind the list to a mint, controlled by its token-acl freeze authority"),
+                )
+                .arg(
+                    Arg::new("label")
+                        .value_name("LABEL")
+                        .long("label")
+                        .takes_value(true)
+                        .conflicts_with("mint")
+                        .default_value("")
+                        .help("Label of the list in the registry of the authority, up to 32 bytes"),
+                )
+        )
+        .subcommand(
+            Command::new("get-lists")
+                .about("Shows the lists recorded in the registry of an authority")
+                .arg(
+                    Arg::new("authority")
+                        .value_name("AUTHORITY_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the authority address, defaults to the payer"),
+                )
+        )
+        .subcommand(
+            Command::new("set-wallet-attributes")
+                .about("Sets the attribute bitmask of a listed wallet")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("wallet_address")
+                        .value_name("WALLET_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .help("Specify the wallet address"),
+                )
+                .arg(
+                    Arg::new("attributes")
+                        .value_name("ATTRIBUTES")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .required(true)
+                        .index(3)
+                        .help("Specify the attribute bitmask"),
+                )
+        )
+        .subcommand(
+            Command::new("set-attribute-policy")
+                .about("Sets the required and forbidden attributes of an attributes list")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("source_address")
+                        .value_name("SOURCE_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .help("Specify the list holding the wallet entries and their attributes"),
+                )
+                .arg(
+                    Arg::new("required")
+                        .value_name("REQUIRED")
+                        .long("required")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Specify the attributes a wallet must all have"),
+                )
+                .arg(
+                    Arg::new("forbidden")
+                        .value_name("FORBIDDEN")
+                        .long("forbidden")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Specify the attributes a wallet must have none of"),
+                )
+        )
+        .subcommand(
+            Command::new("create-identity")
+                .about("Creates an identity that wallets of a list can be linked to")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+        )
+        .subcommand(
+            Command::new("set-identity-status")
+                .about("Allows or blocks an identity and all of its linked wallets")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("identity_address")
+                        .value_name("IDENTITY_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .help("Specify the identity address"),
+                )
+                .arg(
+                    Arg::new("status")
+                        .value_name("STATUS")
+                        .takes_value(true)
+                        .possible_values(["allowed", "blocked"])
+                        .required(true)
+                        .index(3)
+                        .help("Specify the status"),
+                )
+        )
+        .subcommand(
+            Command::new("link-wallet-identity")
+                .about("Links a listed wallet to an identity, or unlinks it when no identity is given")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("wallet_address")
+                        .value_name("WALLET_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .help("Specify the wallet address"),
+                )
+                .arg(
+                    Arg::new("identity_address")
+                        .value_name("IDENTITY_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(3)
+                        .help("Specify the identity address"),
+                )
+        )
+        .subcommand(
+            Command::new("set-risk-threshold")
+                .about("Sets the threshold of a risk-score list")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("risk_threshold")
+                        .value_name("RISK_THRESHOLD")
+                        .value_parser(clap::value_parser!(u8))
+                        .takes_value(true)
+                        .required(true)
+                        .index(2)
+                        .help("Specify the score at or above which wallets are blocked"),
+                )
+        )
+        .subcommand(
+            Command::new("set-list-limits")
+                .about("Sets the change limit and allow delay of a list, loosening is timelocked")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("max_changes_per_epoch")
+                        .value_name("MAX_CHANGES_PER_EPOCH")
+                        .long("max-changes-per-epoch")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Specify the maximum wallets added or removed, and policy changes, per epoch, 0 for unlimited"),
+                )
+                .arg(
+                    Arg::new("allow_delay")
+                        .value_name("SECONDS")
+                        .long("allow-delay")
+                        .value_parser(clap::value_parser!(u64))
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Specify the seconds before wallets added to allowing lists take effect"),
+                )
+        )
+        .subcommand(
+            Command::new("set-guardian")
+                .about("Sets the guardian allowed to block wallets and remove allowed wallets")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("guardian_address")
+                        .value_name("GUARDIAN_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(2)
+                        .help("Specify the guardian address, the default address removes the guardian"),
+                )
+        )
+        .subcommand(
+            Command::new("pause-list")
+                .about("Suspends a list, denying every wallet unless --fail-open is set")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("fail_open")
+                        .long("fail-open")
+                        .takes_value(false)
+                        .help("Let every wallet through while the list is paused"),
+                )
+        )
+        .subcommand(
+            Command::new("set-list-metadata")
+                .about("Sets the name, policy URI and policy hash shown for a list")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("name")
+                        .value_name("NAME")
+                        .long("name")
+                        .takes_value(true)
+                        .required(true)
+                        .help("Name of the list, up to 32 bytes"),
+                )
+                .arg(
+                    Arg::new("uri")
+                        .value_name("URI")
+                        .long("uri")
+                        .takes_value(true)
+                        .default_value("")
+                        .help("URI of the policy document of the list, up to 128 bytes"),
+                )
+                .arg(
+                    Arg::new("policy_hash")
+                        .value_name("POLICY_HASH")
+                        .long("policy-hash")
+                        .takes_value(true)
+                        .default_value("0000000000000000000000000000000000000000000000000000000000000000")
+                        .help("Hex encoded hash of the policy document"),
+                )
         )
-        .arg(
-            Arg::new("verbose")
-                .long("verbose")
-                .short('v')
-                .takes_value(false)
-                .global(true)
-                .help("Show additional information"),
+        .subcommand(
+            Command::new("resume-list")
+                .about("Resumes a paused list")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
         )
-        .arg(
-            Arg::new("json_rpc_url")
-                .short('u')
-                .long("url")
-                .value_name("URL")
-                .takes_value(true)
-                .global(true)
-                .value_parser(parse_url_or_moniker)
-                .help("JSON RPC URL for the cluster [default: value from configuration file]"),
+        .subcommand(
+            Command::new("set-activation-window")
+                .about("Sets the time window during which a list is effective")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("active_from")
+                        .value_name("UNIX_TIMESTAMP")
+                        .long("active-from")
+                        .value_parser(clap::value_parser!(i64))
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Specify when the list becomes effective, 0 for immediately"),
+                )
+                .arg(
+                    Arg::new("active_until")
+                        .value_name("UNIX_TIMESTAMP")
+                        .long("active-until")
+                        .value_parser(clap::value_parser!(i64))
+                        .takes_value(true)
+                        .default_value("0")
+                        .help("Specify when the list lapses, 0 for never"),
+                )
+                .arg(
+                    Arg::new("deny_when_inactive")
+                        .long("deny-when-inactive")
+                        .takes_value(false)
+                        .help("Deny every wallet outside the window instead of skipping the list"),
+                )
         )
         .subcommand(
-            Command::new("create-list")
-                .about("Creates a new list")
+            Command::new("create-audit-log")
+                .about("Creates the audit log recording the recent wallet changes of a list")
                 .arg(
-                    Arg::new("mode")
-                        .value_name("MODE")
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
                         .takes_value(true)
-                        .short('m')
-                        .long("mode")
-                        .possible_values(["allow", "allow-all-eoas", "block"])
-                        .required(true)
-                        .help("Specify the mode"),
+                        .index(1)
+                        .help("Specify the list address"),
+                )
+        )
+        .subcommand(
+            Command::new("migrate-account")
+                .about("Migrates a list or wallet entry of an older layout to the current one")
+                .arg(
+                    Arg::new("account_address")
+                        .value_name("ACCOUNT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list or wallet entry address"),
                 )
         )
         .subcommand(
@@ -280,7 +1473,25 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .takes_value(true)
                         .index(1)
                         .help("Specify the list address"),
+                )
+                .arg(
+                    Arg::new("force")
+                        .long("force")
+                        .takes_value(false)
+                        .help("Delete the list even if it still has wallets, leaving them to be reclaimed"),
         ))
+        .subcommand(
+            Command::new("prune-list")
+                .about("Removes a deleted list, or a mint list whose freeze authority moved, from the lists of the payer")
+                .arg(
+                    Arg::new("list_address")
+                        .value_name("LIST_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the list address"),
+                ),
+        )
         .subcommand(
             Command::new("add-wallet")
                 .about("Adds a wallet to a list")
@@ -300,6 +1511,21 @@ async fn main() -> Result<(), Box<dyn Error>> {
                         .index(2)
                         .help("Specify the wallet address to add"),
                 )
+                .arg(
+                    Arg::new("score")
+                        .value_name("SCORE")
+                        .takes_value(true)
+                        .long("score")
+                        .value_parser(clap::value_parser!(u8))
+                        .default_value("0")
+                        .help("Specify the wallet risk score, for risk-score lists"),
+                )
+                .arg(
+                    Arg::new("idempotent")
+                        .long("idempotent")
+                        .takes_value(false)
+                        .help("Succeed without changes if the wallet is already listed"),
+                )
         )
         .subcommand(
             Command::new("remove-wallet")
@@ -343,6 +1569,28 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 )
                 ,
         )
+        .subcommand(
+            Command::new("apply-lists-to-transfer-hook")
+                .about("Configures the extra metas for the mint transfer hook. This sets up which lists are used to screen the source and destination owners of every transfer.")
+                .arg(
+                    Arg::new("mint_address")
+                        .value_name("MINT_ADDRESS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .index(1)
+                        .help("Specify the mint address"),
+                )
+                .arg(
+                    Arg::new("lists")
+                        .value_name("LISTS")
+                        .value_parser(SignerSourceParserBuilder::default().allow_pubkey().build())
+                        .takes_value(true)
+                        .multiple_occurrences(true)
+                        .index(2)
+                        .help("Specify the list(s) address(es)"),
+                )
+                ,
+        )
         .get_matches();
 
     let (command, matches) = app_matches.subcommand().unwrap();
@@ -398,47 +1646,385 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 "allow" => allow_block_list_client::types::Mode::Allow,
                 "allow-all-eoas" => allow_block_list_client::types::Mode::AllowAllEoas,
                 "block" => allow_block_list_client::types::Mode::Block,
+                "risk-score" => allow_block_list_client::types::Mode::RiskScore,
+                "attributes" => allow_block_list_client::types::Mode::Attributes,
+                _ => unreachable!(),
+            };
+            let storage = arg_matches.get_one::<String>("storage").unwrap();
+            let storage = match storage.as_str() {
+                "entries" => allow_block_list_client::types::Storage::Entries,
+                "buckets" => allow_block_list_client::types::Storage::Buckets,
                 _ => unreachable!(),
             };
-            let response = process_create_list(&rpc_client, &config.payer, mode)
+            let risk_threshold = *arg_matches.get_one::<u8>("risk_threshold").unwrap();
+            let identities = arg_matches.contains_id("identities");
+            let mint = SignerSource::try_get_pubkey(arg_matches, "mint", &mut wallet_manager)
+                .unwrap();
+            let label = arg_matches.get_one::<String>("label").unwrap();
+            let response = process_create_list(
+                &rpc_client,
+                &config.payer,
+                mode,
+                storage,
+                risk_threshold,
+                identities,
+                mint,
+                label,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: create-list: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("get-lists", arg_matches) => {
+            let authority =
+                SignerSource::try_get_pubkey(arg_matches, "authority", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap_or_else(|| config.payer.pubkey());
+            process_get_lists(&rpc_client, &authority)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: create-list: {}", err);
+                    eprintln!("error: get-lists: {}", err);
                     exit(1);
                 });
+        }
+        ("set-wallet-attributes", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let wallet_address =
+                SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let attributes = *arg_matches.get_one::<u64>("attributes").unwrap();
+            let response = process_set_wallet_attributes(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                &wallet_address,
+                attributes,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-wallet-attributes: {}", err);
+                exit(1);
+            });
             println!("{}", response);
         }
-        ("delete-list", arg_matches) => {
+        ("set-attribute-policy", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let source_address =
+                SignerSource::try_get_pubkey(arg_matches, "source_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let required = *arg_matches.get_one::<u64>("required").unwrap();
+            let forbidden = *arg_matches.get_one::<u64>("forbidden").unwrap();
+            let response = process_set_attribute_policy(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                &source_address,
+                required,
+                forbidden,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-attribute-policy: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("create-identity", arg_matches) => {
             let list_address =
                 SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
-            let response = process_delete_list(&rpc_client, &config.payer, &list_address)
+            let response = process_create_identity(&rpc_client, &config.payer, &list_address)
                 .await
                 .unwrap_or_else(|err| {
-                    eprintln!("error: delete-list: {}", err);
+                    eprintln!("error: create-identity: {}", err);
                     exit(1);
                 });
             println!("{}", response);
         }
-        ("add-wallet", arg_matches) => {
+        ("set-identity-status", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let identity_address =
+                SignerSource::try_get_pubkey(arg_matches, "identity_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let status = arg_matches.get_one::<String>("status").unwrap();
+            let status = match status.as_str() {
+                "allowed" => allow_block_list_client::types::IdentityStatus::Allowed,
+                "blocked" => allow_block_list_client::types::IdentityStatus::Blocked,
+                _ => unreachable!(),
+            };
+            let response = process_set_identity_status(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                &identity_address,
+                status,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-identity-status: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("link-wallet-identity", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
             let wallet_address =
                 SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
+            let identity_address =
+                SignerSource::try_get_pubkey(arg_matches, "identity_address", &mut wallet_manager)
+                    .unwrap();
+            let response = process_link_wallet_identity(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                &wallet_address,
+                identity_address,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: link-wallet-identity: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-risk-threshold", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let risk_threshold = *arg_matches.get_one::<u8>("risk_threshold").unwrap();
+            let response = process_set_risk_threshold(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                risk_threshold,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-risk-threshold: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-list-limits", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let max_changes_per_epoch =
+                *arg_matches.get_one::<u64>("max_changes_per_epoch").unwrap();
+            let allow_delay = *arg_matches.get_one::<u64>("allow_delay").unwrap();
+            let response = process_set_list_limits(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                max_changes_per_epoch,
+                allow_delay,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-list-limits: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("set-guardian", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let guardian_address =
+                SignerSource::try_get_pubkey(arg_matches, "guardian_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_set_guardian(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                &guardian_address,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-guardian: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("pause-list", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let fail_open = arg_matches.contains_id("fail_open");
+            let response =
+                process_set_list_paused(&rpc_client, &config.payer, &list_address, true, fail_open)
+                    .await
+                    .unwrap_or_else(|err| {
+                        eprintln!("error: pause-list: {}", err);
+                        exit(1);
+                    });
+            println!("{}", response);
+        }
+        ("set-list-metadata", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let name = arg_matches.get_one::<String>("name").unwrap();
+            let uri = arg_matches.get_one::<String>("uri").unwrap();
+            let policy_hash = arg_matches.get_one::<String>("policy_hash").unwrap();
+            let response = process_set_list_metadata(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                name,
+                uri,
+                policy_hash,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-list-metadata: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("resume-list", arg_matches) => {
             let list_address =
                 SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
                     .unwrap()
                     .unwrap();
             let response =
-                process_add_wallet(&rpc_client, &config.payer, &wallet_address, &list_address)
+                process_set_list_paused(&rpc_client, &config.payer, &list_address, false, false)
                     .await
                     .unwrap_or_else(|err| {
-                        eprintln!("error: add-wallet: {}", err);
+                        eprintln!("error: resume-list: {}", err);
                         exit(1);
                     });
             println!("{}", response);
         }
+        ("set-activation-window", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let active_from = *arg_matches.get_one::<i64>("active_from").unwrap();
+            let active_until = *arg_matches.get_one::<i64>("active_until").unwrap();
+            let deny_when_inactive = arg_matches.contains_id("deny_when_inactive");
+            let response = process_set_activation_window(
+                &rpc_client,
+                &config.payer,
+                &list_address,
+                active_from,
+                active_until,
+                deny_when_inactive,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: set-activation-window: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
+        ("create-audit-log", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_create_audit_log(&rpc_client, &config.payer, &list_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: create-audit-log: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("migrate-account", arg_matches) => {
+            let account_address =
+                SignerSource::try_get_pubkey(arg_matches, "account_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_migrate_account(&rpc_client, &config.payer, &account_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: migrate-account: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("delete-list", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let force = arg_matches.contains_id("force");
+            let response = process_delete_list(&rpc_client, &config.payer, &list_address, force)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: delete-list: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("prune-list", arg_matches) => {
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let response = process_prune_list_registry(&rpc_client, &config.payer, &list_address)
+                .await
+                .unwrap_or_else(|err| {
+                    eprintln!("error: prune-list: {}", err);
+                    exit(1);
+                });
+            println!("{}", response);
+        }
+        ("add-wallet", arg_matches) => {
+            let wallet_address =
+                SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let list_address =
+                SignerSource::try_get_pubkey(arg_matches, "list_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            let score = *arg_matches.get_one::<u8>("score").unwrap();
+            let idempotent = arg_matches.contains_id("idempotent");
+            let response = process_add_wallet(
+                &rpc_client,
+                &config.payer,
+                &wallet_address,
+                &list_address,
+                score,
+                idempotent,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: add-wallet: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
         ("remove-wallet", arg_matches) => {
             let wallet_address =
                 SignerSource::try_get_pubkey(arg_matches, "wallet_address", &mut wallet_manager)
@@ -477,6 +2063,30 @@ async fn main() -> Result<(), Box<dyn Error>> {
                     });
             println!("{}", response);
         }
+        ("apply-lists-to-transfer-hook", arg_matches) => {
+            let mint_address =
+                SignerSource::try_get_pubkey(arg_matches, "mint_address", &mut wallet_manager)
+                    .unwrap()
+                    .unwrap();
+            println!("mint_address: {:?}", mint_address);
+
+            let lists = SignerSource::try_get_pubkeys(arg_matches, "lists", &mut wallet_manager)
+                .unwrap()
+                .unwrap();
+            println!("lists: {:?}", lists);
+            let response = process_setup_transfer_hook_extra_metas(
+                &rpc_client,
+                &config.payer,
+                &mint_address,
+                &lists,
+            )
+            .await
+            .unwrap_or_else(|err| {
+                eprintln!("error: apply-lists-to-transfer-hook: {}", err);
+                exit(1);
+            });
+            println!("{}", response);
+        }
         _ => unreachable!(),
     };
 
@@ -18,6 +18,32 @@ pub enum ABLError {
     InvalidData,
     InvalidTokenAclMintConfig,
     ListNotEmpty,
+    InvalidListAccount,
+    WalletNotAllowed,
+    NonEoaWalletNotAllowed,
+    WalletBlocked,
+    InvalidStorage,
+    WalletAlreadyListed,
+    WalletNotListed,
+    WalletRiskTooHigh,
+    IdentityBlocked,
+    AttributesMismatch,
+    RateLimitExceeded,
+    LimitsCannotBeLoosened,
+    ListPaused,
+    ListInactive,
+    InvalidAuditLog,
+    InvalidBump,
+    EntryAlreadyExists,
+    ListNotDeleted,
+    EnrollmentClosed,
+    EnrolledWallet,
+    InvalidDepositVault,
+    ThawNotByOwner,
+    InvalidListRegistry,
+    InvalidListMetadata,
+    ListStillControlled,
+    ChangeTimelocked,
 }
 
 impl From<ABLError> for ProgramError {
@@ -25,3 +51,37 @@ impl From<ABLError> for ProgramError {
         ProgramError::Custom(e as u32)
     }
 }
+
+/// Reason a wallet failed a list check, reported through return data as
+/// `[list_index, reason]` so callers can tell denials apart.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DenialReason {
+    InvalidListAccount = 1,
+    NotOnAllowList = 2,
+    NonEoaNotOnAllowList = 3,
+    OnBlockList = 4,
+    RiskScoreTooHigh = 5,
+    IdentityBlocked = 6,
+    AttributesMismatch = 7,
+    ListPaused = 8,
+    ListInactive = 9,
+    ThawNotByOwner = 10,
+}
+
+impl From<DenialReason> for ABLError {
+    fn from(reason: DenialReason) -> Self {
+        match reason {
+            DenialReason::InvalidListAccount => ABLError::InvalidListAccount,
+            DenialReason::NotOnAllowList => ABLError::WalletNotAllowed,
+            DenialReason::NonEoaNotOnAllowList => ABLError::NonEoaWalletNotAllowed,
+            DenialReason::OnBlockList => ABLError::WalletBlocked,
+            DenialReason::RiskScoreTooHigh => ABLError::WalletRiskTooHigh,
+            DenialReason::IdentityBlocked => ABLError::IdentityBlocked,
+            DenialReason::AttributesMismatch => ABLError::AttributesMismatch,
+            DenialReason::ListPaused => ABLError::ListPaused,
+            DenialReason::ListInactive => ABLError::ListInactive,
+            DenialReason::ThawNotByOwner => ABLError::ThawNotByOwner,
+        }
+    }
+}
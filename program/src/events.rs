@@ -0,0 +1,84 @@
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+
+use crate::ExtraMetasList;
+
+/// Structured events logged through `sol_log_data` for indexers.
+///
+/// Every event starts with [EVENT_VERSION, EventKind] followed by its fields:
+/// list created: list, authority, mint, mode (u8), storage (u8)
+/// list deleted: list
+/// wallet added: list, wallet, signer
+/// wallet removed: list, wallet, signer
+/// extra metas setup: mint, transfer hook (u8 bool), lists
+///
+/// Fields are logged as separate data segments, decoders concatenate them.
+pub const EVENT_VERSION: u8 = 1;
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum EventKind {
+    ListCreated = 1,
+    ListDeleted = 2,
+    WalletAdded = 3,
+    WalletRemoved = 4,
+    ExtraMetasSetup = 5,
+}
+
+pub(crate) fn list_created(
+    list: &Pubkey,
+    authority: &Pubkey,
+    mint: &Pubkey,
+    mode: u8,
+    storage: u8,
+) {
+    sol_log_data(&[
+        &[EVENT_VERSION, EventKind::ListCreated as u8],
+        list,
+        authority,
+        mint,
+        &[mode, storage],
+    ]);
+}
+
+pub(crate) fn list_deleted(list: &Pubkey) {
+    sol_log_data(&[&[EVENT_VERSION, EventKind::ListDeleted as u8], list]);
+}
+
+pub(crate) fn wallet_added(list: &Pubkey, wallet: &Pubkey, signer: &Pubkey) {
+    sol_log_data(&[
+        &[EVENT_VERSION, EventKind::WalletAdded as u8],
+        list,
+        wallet,
+        signer,
+    ]);
+}
+
+pub(crate) fn wallet_removed(list: &Pubkey, wallet: &Pubkey, signer: &Pubkey) {
+    sol_log_data(&[
+        &[EVENT_VERSION, EventKind::WalletRemoved as u8],
+        list,
+        wallet,
+        signer,
+    ]);
+}
+
+pub(crate) fn extra_metas_setup(
+    mint: &Pubkey,
+    transfer_hook: bool,
+    lists: &[Option<ExtraMetasList>],
+) {
+    let header = [EVENT_VERSION, EventKind::ExtraMetasSetup as u8];
+    let transfer_hook = [transfer_hook as u8];
+
+    let mut segments: [&[u8]; 8] = [&[]; 8];
+    segments[0] = &header;
+    segments[1] = mint;
+    segments[2] = &transfer_hook;
+    let mut len = 3;
+    for list in lists.iter().flatten() {
+        segments[len] = list.key;
+        len += 1;
+    }
+
+    sol_log_data(&segments[..len]);
+}
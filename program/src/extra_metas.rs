@@ -0,0 +1,143 @@
+//! Serializer of the extra account metas layout of `spl-tlv-account-resolution`,
+//! resolved by token-acl and token-2022, writing the same bytes without allocating.
+//!
+//! The account holds a single TLV entry:
+//! instruction discriminator ([u8; 8]), value length (u32), metas count (u32)
+//! followed by the metas, each [discriminator][address config (32)][is_signer][is_writable].
+
+use pinocchio::pubkey::Pubkey;
+use spl_discriminator::SplDiscriminate;
+
+use crate::ABLError;
+
+/// Instruction discriminator, value length and metas count.
+const HEADER_LEN: usize = 8 + 4 + 4;
+
+/// Seed of an extra account derived from the program, packed into the address config.
+#[derive(Clone, Copy)]
+pub enum Seed<'a> {
+    /// Fixed bytes.
+    Literal(&'a [u8]),
+    /// Key of the account at `index` of the instruction.
+    AccountKey { index: u8 },
+    /// `length` bytes at `data_index` of the data of the account at `account_index`.
+    AccountData {
+        account_index: u8,
+        data_index: u8,
+        length: u8,
+    },
+}
+
+impl Seed<'_> {
+    fn packed_len(&self) -> usize {
+        match self {
+            Seed::Literal(bytes) => 2 + bytes.len(),
+            Seed::AccountKey { .. } => 2,
+            Seed::AccountData { .. } => 4,
+        }
+    }
+
+    fn pack(&self, dst: &mut [u8]) {
+        match *self {
+            Seed::Literal(bytes) => {
+                dst[0] = 1;
+                dst[1] = bytes.len() as u8;
+                dst[2..].copy_from_slice(bytes);
+            }
+            Seed::AccountKey { index } => dst.copy_from_slice(&[3, index]),
+            Seed::AccountData {
+                account_index,
+                data_index,
+                length,
+            } => dst.copy_from_slice(&[4, account_index, data_index, length]),
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct ExtraAccountMeta {
+    /// 0 for a fixed address, 1 for an address derived from the program.
+    pub discriminator: u8,
+    /// The address itself, or the packed seeds it is derived from.
+    pub address_config: [u8; 32],
+    pub is_signer: u8,
+    pub is_writable: u8,
+}
+
+impl ExtraAccountMeta {
+    pub const LEN: usize = core::mem::size_of::<ExtraAccountMeta>();
+
+    pub fn new_with_pubkey(key: &Pubkey, is_signer: bool, is_writable: bool) -> Self {
+        Self {
+            discriminator: 0,
+            address_config: *key,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        }
+    }
+
+    /// Meta of an address derived from the program with `seeds`, which must
+    /// pack into the 32 bytes of the address config.
+    pub fn new_with_seeds(
+        seeds: &[Seed],
+        is_signer: bool,
+        is_writable: bool,
+    ) -> Result<Self, ABLError> {
+        let mut address_config = [0; 32];
+        let mut offset = 0;
+        for seed in seeds {
+            let end = offset + seed.packed_len();
+            if end > address_config.len() {
+                return Err(ABLError::InvalidData);
+            }
+            seed.pack(&mut address_config[offset..end]);
+            offset = end;
+        }
+
+        Ok(Self {
+            discriminator: 1,
+            address_config,
+            is_signer: is_signer as u8,
+            is_writable: is_writable as u8,
+        })
+    }
+
+    fn pack(&self, dst: &mut [u8]) {
+        dst[0] = self.discriminator;
+        dst[1..33].copy_from_slice(&self.address_config);
+        dst[33] = self.is_signer;
+        dst[34] = self.is_writable;
+    }
+}
+
+/// Size of the account holding `count` extra metas.
+pub const fn size_of(count: usize) -> usize {
+    HEADER_LEN + count * ExtraAccountMeta::LEN
+}
+
+/// Writes `metas` as the extra metas resolved for instruction `T` into `data`,
+/// which must be zeroed and hold at least `size_of(metas.len())` bytes.
+pub fn init<T: SplDiscriminate>(
+    data: &mut [u8],
+    metas: &[ExtraAccountMeta],
+) -> Result<(), ABLError> {
+    if data.len() < size_of(metas.len()) {
+        return Err(ABLError::InvalidData);
+    }
+
+    let (header, entries) = data.split_at_mut(HEADER_LEN);
+    let value_len = (4 + metas.len() * ExtraAccountMeta::LEN) as u32;
+    header[..8].copy_from_slice(T::SPL_DISCRIMINATOR_SLICE);
+    header[8..12].copy_from_slice(&value_len.to_le_bytes());
+    header[12..].copy_from_slice(&(metas.len() as u32).to_le_bytes());
+
+    for (meta, dst) in metas
+        .iter()
+        .zip(entries.chunks_exact_mut(ExtraAccountMeta::LEN))
+    {
+        meta.pack(dst);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::{find_program_address, Pubkey},
+    seeds,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events, load_account_mut, load_bucket_mut_unchecked, utils::resize_with_rent, ABLError, Bucket,
+    Discriminator, ListConfig, Mode, Storage,
+};
+
+/// Inserts a batch of wallets sharing the same first byte into the bucket of a
+/// bucket storage list, creating the bucket on first use.
+pub struct AddBucketWallets<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub bucket: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> AddBucketWallets<'a> {
+    pub const DISCRIMINATOR: u8 = 0x07;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let wallets = parse_bucket_wallets(remaining_data)?;
+        let prefix = wallets[0][0];
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        // the guardian can only block wallets
+        let by_guardian = list_config.check_change_authority(
+            self.authority,
+            self.mint_config,
+            matches!(list_config.get_mode()?, Mode::Block),
+        )?;
+
+        if !matches!(list_config.get_storage(), Storage::Buckets) {
+            return Err(ABLError::InvalidStorage.into());
+        }
+
+        if !by_guardian {
+            list_config.record_changes(wallets.len() as u64, Clock::get()?.epoch)?;
+        }
+
+        let prefix_seed = [prefix];
+        let (bucket_address, bucket_bump) = find_program_address(
+            &[Bucket::SEED_PREFIX, self.list_config.key(), &prefix_seed],
+            &crate::ID,
+        );
+        if bucket_address.ne(self.bucket.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        let current_count = if self.bucket.is_owned_by(&crate::ID) {
+            let count = (self.bucket.data_len() - Bucket::LEN) / 32;
+            resize_with_rent(
+                self.bucket,
+                self.authority,
+                Bucket::size(count + wallets.len()),
+            )?;
+            count
+        } else {
+            let space = Bucket::size(wallets.len());
+            let bump_seed = [bucket_bump];
+            let seeds = seeds!(
+                Bucket::SEED_PREFIX,
+                self.list_config.key(),
+                &prefix_seed,
+                &bump_seed
+            );
+            let signer = Signer::from(&seeds);
+
+            pinocchio_system::instructions::CreateAccount {
+                from: self.authority,
+                to: self.bucket,
+                lamports: Rent::get()?.minimum_balance(space),
+                space: space as u64,
+                owner: &crate::ID,
+            }
+            .invoke_signed(&[signer])?;
+            0
+        };
+
+        let mut data = self.bucket.try_borrow_mut_data()?;
+        let (bucket, entries) = load_bucket_mut_unchecked(&mut data)?;
+        if current_count == 0 {
+            bucket.discriminator = Bucket::DISCRIMINATOR;
+            bucket.list_config = *self.list_config.key();
+            bucket.prefix = prefix;
+        } else if !bucket.is_initialized() || bucket.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        // keep the bucket sorted so thaws can binary search it
+        let mut count = current_count;
+        for wallet in wallets {
+            match entries[..count].binary_search(wallet) {
+                Ok(_) => return Err(ABLError::WalletAlreadyListed.into()),
+                Err(position) => {
+                    entries.copy_within(position..count, position + 1);
+                    entries[position] = *wallet;
+                    count += 1;
+                }
+            }
+        }
+
+        list_config.add_wallets_count(wallets.len() as u64)?;
+
+        for wallet in wallets {
+            events::wallet_added(self.list_config.key(), wallet, self.authority.key());
+        }
+
+        if by_guardian {
+            pinocchio_log::log!(
+                "Guardian blocked {} wallets on list {}",
+                wallets.len(),
+                self.list_config.key()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses instruction data as a non-empty list of wallets sharing the same
+/// first byte, i.e. belonging to the same bucket.
+pub(crate) fn parse_bucket_wallets(data: &[u8]) -> Result<&[Pubkey], ABLError> {
+    if data.is_empty() || data.len() % 32 != 0 {
+        return Err(ABLError::InvalidData);
+    }
+
+    let wallets: &[Pubkey] = bytemuck::cast_slice(data);
+    if wallets.iter().any(|wallet| wallet[0] != wallets[0][0]) {
+        return Err(ABLError::InvalidData);
+    }
+
+    Ok(wallets)
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for AddBucketWallets<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, bucket, system_program, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !bucket.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            bucket,
+            system_program,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
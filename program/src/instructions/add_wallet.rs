@@ -1,14 +1,16 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Signer,
-    pubkey::find_program_address,
     seeds,
-    sysvars::{rent::Rent, Sysvar},
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
     ProgramResult,
 };
 
 use crate::{
-    load_mut, load_mut_unchecked, ABLError, Discriminator, ListConfig, Transmutable, WalletEntry,
+    events, load_account_mut, load_mut_unchecked,
+    utils::{record_audit, resolve_bump},
+    ABLError, AuditOperation, Discriminator, ListConfig, Mode, Storage, Transmutable, Versioned,
+    WalletEntry,
 };
 
 pub struct AddWallet<'a> {
@@ -17,23 +19,79 @@ pub struct AddWallet<'a> {
     pub wallet: &'a AccountInfo,
     pub wallet_entry: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
-    pub wallet_entry_bump: u8,
+    pub mint_config: Option<&'a AccountInfo>,
+    /// Audit log of the list, passed after the mint config when the list keeps one.
+    pub audit_log: Option<&'a AccountInfo>,
 }
 
 impl<'a> AddWallet<'a> {
     pub const DISCRIMINATOR: u8 = 0x02;
+    /// Same as `AddWallet`, but succeeds without changes when the wallet is
+    /// already listed so that retried additions are safe.
+    pub const IDEMPOTENT_DISCRIMINATOR: u8 = 0x18;
 
-    pub fn process(&self) -> ProgramResult {
-        let list_config =
-            unsafe { load_mut::<ListConfig>(self.list_config.borrow_mut_data_unchecked())? };
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        self.add(remaining_data, false)
+    }
+
+    pub fn process_idempotent(&self, remaining_data: &[u8]) -> ProgramResult {
+        self.add(remaining_data, true)
+    }
+
+    fn add(&self, remaining_data: &[u8], idempotent: bool) -> ProgramResult {
+        // score and attributes are optional and default to 0
+        let (score, attributes) = match remaining_data {
+            [] => (0, [0; 8]),
+            [score] => (*score, [0; 8]),
+            [score, attributes @ ..] => (
+                *score,
+                <[u8; 8]>::try_from(attributes).map_err(|_| ABLError::InvalidData)?,
+            ),
+        };
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
 
-        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
-            return Err(ABLError::InvalidAuthority.into());
+        // the guardian can only block wallets
+        let by_guardian = list_config.check_change_authority(
+            self.authority,
+            self.mint_config,
+            matches!(list_config.get_mode()?, Mode::Block),
+        )?;
+
+        if !matches!(list_config.get_storage(), Storage::Entries) {
+            return Err(ABLError::InvalidStorage.into());
+        }
+
+        let wallet_entry_bump = resolve_bump(
+            &[
+                WalletEntry::SEED_PREFIX,
+                self.list_config.key(),
+                self.wallet.key(),
+            ],
+            self.wallet_entry.key(),
+        )?;
+
+        // the address was checked above, so an owned account is the existing entry
+        if self.wallet_entry.is_owned_by(&crate::ID) {
+            return if idempotent {
+                Ok(())
+            } else {
+                Err(ABLError::EntryAlreadyExists.into())
+            };
         }
 
+        let clock = Clock::get()?;
+        if !by_guardian {
+            list_config.record_changes(1, clock.epoch)?;
+        }
+
+        // wallets added to allowing lists only take effect after the list delay,
+        // while blocking takes effect immediately
+        let active_from = list_config.get_added_wallet_active_from(clock.unix_timestamp)?;
+
         let lamports = Rent::get()?.minimum_balance(WalletEntry::LEN);
 
-        let bump_seed = [self.wallet_entry_bump];
+        let bump_seed = [wallet_entry_bump];
         let seeds = seeds!(
             WalletEntry::SEED_PREFIX,
             self.list_config.key(),
@@ -52,13 +110,42 @@ impl<'a> AddWallet<'a> {
         .invoke_signed(&[signer])?;
 
         let mut data = self.wallet_entry.try_borrow_mut_data()?;
-        let wallet_entry = unsafe { load_mut_unchecked::<WalletEntry>(&mut data)? };
+        let wallet_entry = load_mut_unchecked::<WalletEntry>(&mut data)?;
         wallet_entry.discriminator = WalletEntry::DISCRIMINATOR;
+        wallet_entry.version = WalletEntry::VERSION;
         wallet_entry.wallet_address = *self.wallet.key();
         wallet_entry.list_config = *self.list_config.key();
+        wallet_entry.score = score;
+        wallet_entry.attributes = attributes;
+        wallet_entry.active_from = active_from.to_le_bytes();
+        wallet_entry.bump = wallet_entry_bump;
 
         list_config.increment_wallets_count()?;
 
+        record_audit(
+            &list_config,
+            self.list_config.key(),
+            self.audit_log,
+            AuditOperation::WalletAdded,
+            self.wallet.key(),
+            self.authority.key(),
+            clock.slot,
+        )?;
+
+        events::wallet_added(
+            self.list_config.key(),
+            self.wallet.key(),
+            self.authority.key(),
+        );
+
+        if by_guardian {
+            pinocchio_log::log!(
+                "Guardian blocked wallet {} on list {}",
+                self.wallet.key(),
+                self.list_config.key()
+            );
+        }
+
         Ok(())
     }
 }
@@ -67,7 +154,9 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, list_config, wallet, wallet_entry, system_program] = accounts else {
+        let [authority, list_config, wallet, wallet_entry, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -79,11 +168,6 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
             return Err(ABLError::AccountNotWritable);
         }
 
-        let (_, wallet_entry_bump) = find_program_address(
-            &[WalletEntry::SEED_PREFIX, list_config.key(), wallet.key()],
-            &crate::ID,
-        );
-
         // check if system program is valid
         if system_program.key().ne(&pinocchio_system::ID) {
             return Err(ABLError::InvalidSystemProgram);
@@ -95,7 +179,8 @@ impl<'a> TryFrom<&'a [AccountInfo]> for AddWallet<'a> {
             wallet,
             wallet_entry,
             system_program,
-            wallet_entry_bump,
+            mint_config: remaining_accounts.first(),
+            audit_log: remaining_accounts.get(1),
         })
     }
 }
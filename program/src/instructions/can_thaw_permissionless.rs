@@ -1,6 +1,15 @@
-use pinocchio::{account_info::AccountInfo, ProgramResult};
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
 
-use crate::{load, ABLError, ListConfig, WalletEntry};
+use crate::{
+    load_account, load_account_versioned, load_bucket, ABLError, DenialReason, Identity,
+    IdentityStatus, ListConfig, Storage, WalletEntry,
+};
 
 use solana_curve25519::edwards::PodEdwardsPoint;
 
@@ -16,6 +25,16 @@ use solana_curve25519::edwards::PodEdwardsPoint;
 /// 4.2- only source wallet block
 /// 4.3- both source and destination wallet blocks
 /// 5- given all the above we can skip a lot of type and owner checks
+/// 6- for bucket storage lists the wallet block is the bucket derived from the owner's first byte
+/// 7- on denial, return data is set to [list_index, DenialReason] and a reason specific error is returned
+/// 8- risk score lists read the score from the wallet block, which is a wallet entry
+/// 9- allow lists with identities are followed by the identity of the wallet entry
+/// 9.1- wallets without an entry on such lists can't resolve the identity, so they can't thaw either
+/// 10- attribute lists resolve the wallet entry from their attributes source list
+/// 11- wallet entries only count once their activation time has passed (allowing list timelock)
+/// 12- paused lists pass or deny every wallet depending on their fail open setting
+/// 13- outside their activation window lists are skipped or deny every wallet, as set per list
+/// 14- self thaw only lists deny thaws not initiated by the owner of the token account, signing itself
 
 pub struct CanThawPermissionless<'a> {
     pub authority: &'a AccountInfo,
@@ -30,59 +49,228 @@ impl<'a> CanThawPermissionless<'a> {
     pub const DISCRIMINATOR: u8 = 0x8;
 
     pub fn process(&self) -> ProgramResult {
-        // remaining accounts should be pairs of list and ab_wallet
+        // remaining accounts should be pairs of list and ab_wallet,
+        // followed by the identity for lists with identities
         let mut remaining_accounts = self.remaining_accounts.iter();
+        let mut list_index: u8 = 0;
         while let Some(list) = remaining_accounts.next() {
-            let ab_wallet = remaining_accounts.next().unwrap();
-            
-            CanThawPermissionless::validate_thaw_list(list, self.owner,ab_wallet).map_err(|e| {
-                pinocchio_log::log!("Failed to pass validation for list {}", list.key());
-                e
-            })?;
+            let ab_wallet = remaining_accounts
+                .next()
+                .ok_or(ABLError::NotEnoughAccounts)?;
+            let identity = if CanThawPermissionless::has_identities(list) {
+                Some(
+                    remaining_accounts
+                        .next()
+                        .ok_or(ABLError::NotEnoughAccounts)?,
+                )
+            } else {
+                None
+            };
+
+            let result = self.check_thaw_initiator(list).and_then(|()| {
+                CanThawPermissionless::validate_thaw_list(
+                    list,
+                    self.owner.key(),
+                    Some(ab_wallet),
+                    identity,
+                )
+            });
+            if let Err(reason) = result {
+                pinocchio_log::log!(
+                    "Failed to pass validation for list {} ({}), reason {}",
+                    list.key(),
+                    list_index,
+                    reason as u8
+                );
+                set_return_data(&[list_index, reason as u8]);
+                return Err(ABLError::from(reason).into());
+            }
+
+            list_index += 1;
         }
 
         Ok(())
     }
 
-    fn validate_thaw_list(list: &AccountInfo, owner: &AccountInfo, wallet_entry: &AccountInfo) -> ProgramResult {
-        let list_data: &[u8] = &list.try_borrow_data()?;
-        let list_config = unsafe { load::<ListConfig>(list_data)? };
+    pub(crate) fn validate_thaw_list(
+        list: &AccountInfo,
+        owner: &Pubkey,
+        wallet_entry: Option<&AccountInfo>,
+        identity: Option<&AccountInfo>,
+    ) -> Result<(), DenialReason> {
+        if !list.is_owned_by(&crate::ID) {
+            return Err(DenialReason::InvalidListAccount);
+        }
+
+        let list_config = load_account_versioned::<ListConfig>(list)
+            .map_err(|_| DenialReason::InvalidListAccount)?;
+
+        if list_config.is_paused() {
+            return if list_config.is_fail_open() {
+                Ok(())
+            } else {
+                Err(DenialReason::ListPaused)
+            };
+        }
+
+        if list_config.has_activation_window()
+            && !Clock::get().is_ok_and(|clock| list_config.is_active_at(clock.unix_timestamp))
+        {
+            return if list_config.denies_when_inactive() {
+                Err(DenialReason::ListInactive)
+            } else {
+                Ok(())
+            };
+        }
 
         // 3 operation modes
         // allow: only wallets that have been allowlisted can thaw, requires previously created ABWallet account
         // block: only wallets that have been blocklisted can't thaw, thawing requires ABWallet to not exist
         // allow with permissionless eoas: all wallets that can sign can thaw, otherwise requires previously created ABWallet account (for PDAs)
-        match list_config.get_mode() {
+        let mode = list_config
+            .get_mode()
+            .map_err(|_| DenialReason::InvalidListAccount)?;
+        match mode {
             crate::Mode::Allow => {
-                let ab_wallet_data: &[u8] = &wallet_entry.try_borrow_data()?;
-                let _ = unsafe {
-                    load::<WalletEntry>(ab_wallet_data).map_err(|_| ABLError::AccountBlocked)?
-                };
+                if !Self::is_listed(&list_config, owner, wallet_entry) {
+                    return Err(DenialReason::NotOnAllowList);
+                }
+
+                if list_config.has_identities() {
+                    Self::check_identity(list.key(), wallet_entry, identity)?;
+                }
 
                 Ok(())
             }
             crate::Mode::AllowAllEoas => {
-                let pt = PodEdwardsPoint(owner.key().clone());
+                let pt = PodEdwardsPoint(*owner);
 
-                if !solana_curve25519::edwards::validate_edwards(&pt) {
-                    let ab_wallet_data: &[u8] = &wallet_entry.try_borrow_data()?;
-                    let _ = unsafe {
-                        load::<WalletEntry>(ab_wallet_data).map_err(|_| ABLError::AccountBlocked)?
-                    };
+                if !solana_curve25519::edwards::validate_edwards(&pt)
+                    && !Self::is_listed(&list_config, owner, wallet_entry)
+                {
+                    return Err(DenialReason::NonEoaNotOnAllowList);
                 }
 
                 Ok(())
             }
             crate::Mode::Block => {
-                let ab_wallet_data: &[u8] = &wallet_entry.try_borrow_data()?;
-                let res = unsafe { load::<WalletEntry>(ab_wallet_data) };
+                if Self::is_listed(&list_config, owner, wallet_entry) {
+                    return Err(DenialReason::OnBlockList);
+                }
 
-                if res.is_ok() {
-                    Err(ABLError::AccountBlocked.into())
-                } else {
-                    Ok(())
+                Ok(())
+            }
+            // attributes: only wallets whose entry in the source list matches the list masks can thaw
+            crate::Mode::Attributes => {
+                let Some(Ok(entry)) = wallet_entry.map(load_account_versioned::<WalletEntry>)
+                else {
+                    return Err(DenialReason::NotOnAllowList);
+                };
+
+                if entry.list_config.ne(&list_config.attributes_source) || !Self::is_active(&entry)
+                {
+                    return Err(DenialReason::NotOnAllowList);
                 }
+
+                if !list_config.matches_attributes(entry.get_attributes()) {
+                    return Err(DenialReason::AttributesMismatch);
+                }
+
+                Ok(())
             }
+            // risk score: wallets whose entry scores at or above the list threshold can't thaw
+            crate::Mode::RiskScore => {
+                match wallet_entry.map(load_account_versioned::<WalletEntry>) {
+                    Some(Ok(entry)) if entry.score >= list_config.risk_threshold => {
+                        Err(DenialReason::RiskScoreTooHigh)
+                    }
+                    _ => Ok(()),
+                }
+            }
+        }
+    }
+
+    /// Ensures thaws gated by a self thaw only list are initiated by the owner
+    /// of the token account, rather than bots sweeping every eligible account.
+    fn check_thaw_initiator(&self, list: &AccountInfo) -> Result<(), DenialReason> {
+        let self_thaw_only = load_account_versioned::<ListConfig>(list)
+            .is_ok_and(|list_config| list_config.is_self_thaw_only());
+
+        if self_thaw_only && (self.authority.key().ne(self.owner.key()) || !self.owner.is_signer())
+        {
+            return Err(DenialReason::ThawNotByOwner);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the list links wallet entries to identities, in which case the
+    /// identity follows the wallet block in the remaining accounts.
+    pub(crate) fn has_identities(list: &AccountInfo) -> bool {
+        load_account_versioned::<ListConfig>(list)
+            .is_ok_and(|list_config| list_config.has_identities())
+    }
+
+    /// Ensures the identity a listed wallet is linked to, if any, is not blocked.
+    fn check_identity(
+        list: &Pubkey,
+        wallet_entry: Option<&AccountInfo>,
+        identity: Option<&AccountInfo>,
+    ) -> Result<(), DenialReason> {
+        let Some(Ok(entry)) = wallet_entry.map(load_account_versioned::<WalletEntry>) else {
+            return Err(DenialReason::NotOnAllowList);
+        };
+
+        if !entry.is_linked() {
+            return Ok(());
+        }
+
+        let Some(Ok(identity)) = identity.map(load_account::<Identity>) else {
+            return Err(DenialReason::IdentityBlocked);
+        };
+
+        if identity.list_config.ne(list)
+            || identity.id.ne(&entry.identity)
+            || !matches!(identity.get_status(), Ok(IdentityStatus::Allowed))
+            || !Self::is_identity_active(&identity)
+        {
+            return Err(DenialReason::IdentityBlocked);
+        }
+
+        Ok(())
+    }
+
+    /// Whether the allowed status of the identity took effect, identities
+    /// allowed again on lists with a delay stay blocked until then.
+    fn is_identity_active(identity: &Identity) -> bool {
+        let active_from = identity.get_active_from();
+        active_from == 0 || Clock::get().is_ok_and(|clock| clock.unix_timestamp >= active_from)
+    }
+
+    /// Whether the entry took effect, entries added to allowing lists with a
+    /// delay are ignored until their activation time.
+    fn is_active(entry: &WalletEntry) -> bool {
+        let active_from = entry.get_active_from();
+        active_from == 0 || Clock::get().is_ok_and(|clock| clock.unix_timestamp >= active_from)
+    }
+
+    /// Whether `owner` is part of the list, either through its wallet entry or,
+    /// for bucket storage lists, by being in the bucket resolved for it.
+    fn is_listed(
+        list_config: &ListConfig,
+        owner: &Pubkey,
+        wallet_entry: Option<&AccountInfo>,
+    ) -> bool {
+        let Some(wallet_entry) = wallet_entry else {
+            return false;
+        };
+
+        match list_config.get_storage() {
+            Storage::Entries => load_account_versioned::<WalletEntry>(wallet_entry)
+                .is_ok_and(|entry| Self::is_active(&entry)),
+            Storage::Buckets => wallet_entry.try_borrow_data().is_ok_and(|data| {
+                load_bucket(&data).is_ok_and(|(_, wallets)| wallets.binary_search(owner).is_ok())
+            }),
         }
     }
 }
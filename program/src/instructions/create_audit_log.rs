@@ -0,0 +1,79 @@
+use pinocchio::{account_info::AccountInfo, pubkey::find_program_address, seeds, ProgramResult};
+
+use crate::{
+    load_account_mut, load_mut_unchecked, utils::create_program_account, ABLError, AuditLog,
+    Discriminator, ListConfig, Storage, Transmutable,
+};
+
+/// Creates the audit log of a list. From then on every wallet added or removed
+/// is recorded in it and has to be passed to `AddWallet` and `RemoveWallet`.
+pub struct CreateAuditLog<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub audit_log: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateAuditLog<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, audit_log, system_program, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !audit_log.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            audit_log,
+            system_program,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> CreateAuditLog<'a> {
+    pub const DISCRIMINATOR: u8 = 0x16;
+
+    pub fn process(&self) -> ProgramResult {
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        // bucket wallets are changed in batches, only entries are audited
+        if !matches!(list_config.get_storage(), Storage::Entries) {
+            return Err(ABLError::InvalidStorage.into());
+        }
+
+        let (_, audit_log_bump) =
+            find_program_address(&[AuditLog::SEED_PREFIX, self.list_config.key()], &crate::ID);
+
+        let bump_seed = [audit_log_bump];
+        let seeds = seeds!(AuditLog::SEED_PREFIX, self.list_config.key(), &bump_seed);
+        create_program_account(self.audit_log, self.authority, AuditLog::LEN, &seeds)?;
+
+        let mut data = self.audit_log.try_borrow_mut_data()?;
+        let audit_log = load_mut_unchecked::<AuditLog>(&mut data)?;
+        audit_log.discriminator = AuditLog::DISCRIMINATOR;
+        audit_log.list_config = *self.list_config.key();
+
+        list_config.audit_log = 1;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    pubkey::find_program_address,
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load_account_mut, load_mut_unchecked, ABLError, Discriminator, Identity, IdentityStatus,
+    ListConfig, Transmutable,
+};
+
+/// Creates an identity that wallet entries of a list with identities can be linked to.
+pub struct CreateIdentity<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub identity: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateIdentity<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, identity, system_program, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !identity.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            identity,
+            system_program,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> CreateIdentity<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0C;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let id: &[u8; 32] = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        {
+            let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+            list_config.check_authority(self.authority, self.mint_config)?;
+
+            if !list_config.has_identities() {
+                return Err(ABLError::InvalidConfigAccount.into());
+            }
+
+            // counted so the tombstone of the list is kept until they are reclaimed
+            list_config.increment_identities_count()?;
+        }
+
+        let (_, identity_bump) = find_program_address(
+            &[Identity::SEED_PREFIX, self.list_config.key(), id],
+            &crate::ID,
+        );
+
+        let bump_seed = [identity_bump];
+        let seeds = seeds!(
+            Identity::SEED_PREFIX,
+            self.list_config.key(),
+            id,
+            &bump_seed
+        );
+        let signer = Signer::from(&seeds);
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.authority,
+            to: self.identity,
+            lamports: Rent::get()?.minimum_balance(Identity::LEN),
+            space: Identity::LEN as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+
+        let mut data = self.identity.try_borrow_mut_data()?;
+        let identity = load_mut_unchecked::<Identity>(&mut data)?;
+        identity.discriminator = Identity::DISCRIMINATOR;
+        identity.list_config = *self.list_config.key();
+        identity.id = *id;
+        identity.status = IdentityStatus::Allowed as u8;
+
+        Ok(())
+    }
+}
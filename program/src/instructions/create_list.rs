@@ -1,25 +1,30 @@
 use pinocchio::{
     account_info::AccountInfo,
     instruction::Signer,
-    pubkey::find_program_address,
     seeds,
     sysvars::{rent::Rent, Sysvar},
     ProgramResult,
 };
 
-use crate::{load_mut_unchecked, ABLError, Discriminator, ListConfig, Transmutable};
+use crate::{
+    events, load_mut_unchecked,
+    utils::{register_list, resolve_bump},
+    ABLError, Discriminator, ListConfig, Mode, Storage, Transmutable, Versioned,
+};
 
 pub struct CreateList<'a> {
     pub authority: &'a AccountInfo,
     pub list_config: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
+    /// Registry of the lists of the authority, recording the new list.
+    pub list_registry: &'a AccountInfo,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for CreateList<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, list_config, system_program] = accounts else {
+        let [authority, list_config, system_program, list_registry, ..] = accounts else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -32,6 +37,7 @@ impl<'a> TryFrom<&'a [AccountInfo]> for CreateList<'a> {
             authority,
             list_config,
             system_program,
+            list_registry,
         })
     }
 }
@@ -40,26 +46,24 @@ impl<'a> CreateList<'a> {
     pub const DISCRIMINATOR: u8 = 0x01;
 
     pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
-        let [mode, seed @ ..] = remaining_data else {
+        let [mode, rest @ ..] = remaining_data else {
             return Err(ABLError::InvalidData.into());
         };
 
-        if seed.len() != 32 {
-            return Err(ABLError::InvalidData.into());
-        }
-
-        if *mode > 2u8 {
+        if rest.len() < 32 {
             return Err(ABLError::InvalidData.into());
         }
+        let (seed, rest) = rest.split_at(32);
 
-        let lamports = Rent::get()?.minimum_balance(ListConfig::LEN);
+        let (rest, label) = split_label(rest)?;
+        let options = ListOptions::parse(*mode, rest)?;
 
-        // find canonical bump to prepare signer seeds for cpi
+        // find the canonical bump to prepare signer seeds for cpi
         let seed = TryInto::<&[u8; 32]>::try_into(seed).unwrap();
-        let (_, config_bump) = find_program_address(
+        let config_bump = resolve_bump(
             &[ListConfig::SEED_PREFIX, self.authority.key(), seed],
-            &crate::ID,
-        );
+            self.list_config.key(),
+        )?;
 
         // prepare signer seeds for cpi
         let bump_seed = [config_bump];
@@ -71,21 +75,127 @@ impl<'a> CreateList<'a> {
         );
         let signer = Signer::from(&seeds);
 
+        options.initialize(self.list_config, self.authority, signer, |list| {
+            list.authority = *self.authority.key();
+            list.seed = *seed;
+        })?;
+
+        register_list(
+            self.list_registry,
+            self.authority,
+            self.list_config.key(),
+            *mode,
+            label,
+        )
+    }
+}
+
+/// Splits the registry label off the optional settings of a new list, which
+/// it follows once they are all set, defaulting to all zeros.
+pub(crate) fn split_label(rest: &[u8]) -> Result<(&[u8], [u8; 32]), ABLError> {
+    if rest.len() <= 3 {
+        return Ok((rest, [0; 32]));
+    }
+
+    let (rest, label) = rest.split_at(3);
+    let label = <[u8; 32]>::try_from(label).map_err(|_| ABLError::InvalidData)?;
+    Ok((rest, label))
+}
+
+/// Mode and optional settings of a new list, shared by authority and mint bound lists.
+pub(crate) struct ListOptions {
+    mode: Mode,
+    storage: u8,
+    risk_threshold: u8,
+    identities: u8,
+}
+
+impl ListOptions {
+    /// Parses the optional `[storage][risk_threshold][identities]` bytes following the mode.
+    pub(crate) fn parse(mode: u8, rest: &[u8]) -> Result<Self, ABLError> {
+        // storage is optional and defaults to one wallet entry account per wallet
+        // risk threshold is optional and defaults to 0
+        // identities is optional and defaults to false
+        let (storage, risk_threshold, identities) = match rest {
+            [] => (Storage::Entries as u8, 0, 0),
+            [storage] => (*storage, 0, 0),
+            [storage, risk_threshold] => (*storage, *risk_threshold, 0),
+            [storage, risk_threshold, identities] => (*storage, *risk_threshold, *identities),
+            _ => return Err(ABLError::InvalidData),
+        };
+
+        let mode = Mode::try_from(mode)?;
+
+        if storage > 1u8 || identities > 1u8 {
+            return Err(ABLError::InvalidData);
+        }
+
+        // identities are resolved by reading the wallet entry, which only
+        // exists for listed wallets, so they are limited to allow lists
+        // identities of wallets without an entry can't be resolved, see `Identity`
+        if identities == 1 {
+            if !matches!(mode, Mode::Allow) {
+                return Err(ABLError::InvalidData);
+            }
+            if storage != Storage::Entries as u8 {
+                return Err(ABLError::InvalidStorage);
+            }
+        }
+
+        // scores and attributes are kept in wallet entries
+        if matches!(mode, Mode::RiskScore | Mode::Attributes) && storage != Storage::Entries as u8 {
+            return Err(ABLError::InvalidStorage);
+        }
+
+        Ok(Self {
+            mode,
+            storage,
+            risk_threshold,
+            identities,
+        })
+    }
+
+    /// Creates the list account through `signer` and writes the options,
+    /// leaving what controls the list to `set_owner`.
+    pub(crate) fn initialize(
+        &self,
+        list_config: &AccountInfo,
+        payer: &AccountInfo,
+        signer: Signer,
+        set_owner: impl FnOnce(&mut ListConfig),
+    ) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(ListConfig::LEN);
+
         pinocchio_system::instructions::CreateAccount {
-            from: self.authority,
-            to: self.list_config,
+            from: payer,
+            to: list_config,
             lamports,
             space: ListConfig::LEN as u64,
             owner: &crate::ID,
         }
         .invoke_signed(&[signer])?;
 
-        let mut data = self.list_config.try_borrow_mut_data()?;
-        let list = unsafe { load_mut_unchecked::<ListConfig>(&mut data)? };
+        let mut data = list_config.try_borrow_mut_data()?;
+        let list = load_mut_unchecked::<ListConfig>(&mut data)?;
         list.discriminator = ListConfig::DISCRIMINATOR;
-        list.authority = *self.authority.key();
-        list.seed = *seed;
-        list.mode = *mode;
+        list.version = ListConfig::VERSION;
+        list.set_mode(self.mode);
+        list.storage = self.storage;
+        list.risk_threshold = self.risk_threshold;
+        list.identities = self.identities;
+        // attribute lists check their own wallet entries until a policy sets another source
+        if matches!(self.mode, Mode::Attributes) {
+            list.attributes_source = *list_config.key();
+        }
+        set_owner(list);
+
+        events::list_created(
+            list_config.key(),
+            &list.authority,
+            &list.mint,
+            list.mode,
+            list.storage,
+        );
 
         Ok(())
     }
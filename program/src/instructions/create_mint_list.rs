@@ -0,0 +1,87 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, seeds, ProgramResult};
+
+use crate::{
+    split_label,
+    utils::{get_freeze_authority, register_list, resolve_bump},
+    ABLError, ListConfig, ListOptions,
+};
+
+/// Creates the list bound to a mint, derived from the mint alone. It has no
+/// authority of its own: whoever is the current freeze authority in the
+/// token-acl mint config controls it, so rotating the freeze authority moves
+/// control of the list along with it.
+///
+/// The list is recorded in the registry of the freeze authority creating it.
+pub struct CreateMintList<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub mint_config: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    /// Registry of the lists of the authority, recording the new list.
+    pub list_registry: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateMintList<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, mint, mint_config, system_program, list_registry] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint,
+            mint_config,
+            system_program,
+            list_registry,
+        })
+    }
+}
+
+impl<'a> CreateMintList<'a> {
+    pub const DISCRIMINATOR: u8 = 0x15;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [mode, rest @ ..] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+
+        let (rest, label) = split_label(rest)?;
+        let options = ListOptions::parse(*mode, rest)?;
+
+        let freeze_authority = get_freeze_authority(self.mint_config, self.mint.key())?;
+        if !self.authority.is_signer() || freeze_authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        let config_bump = resolve_bump(
+            &[ListConfig::MINT_SEED_PREFIX, self.mint.key()],
+            self.list_config.key(),
+        )?;
+
+        let bump_seed = [config_bump];
+        let seeds = seeds!(ListConfig::MINT_SEED_PREFIX, self.mint.key(), &bump_seed);
+        let signer = Signer::from(&seeds);
+
+        options.initialize(self.list_config, self.authority, signer, |list| {
+            list.mint = *self.mint.key();
+        })?;
+
+        register_list(
+            self.list_registry,
+            self.authority,
+            self.list_config.key(),
+            *mode,
+            label,
+        )
+    }
+}
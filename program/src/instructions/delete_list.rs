@@ -1,17 +1,38 @@
 use pinocchio::{account_info::AccountInfo, ProgramResult};
 
-use crate::{load, ABLError, ListConfig};
+use crate::{
+    events, load_account, load_account_mut, load_account_versioned,
+    utils::{check_deposit_vault, close_account, unregister_list},
+    ABLError, AuditLog, ListConfig, ListMetadata,
+};
 
+/// Deletes an empty list, or force deletes a list that still has wallets.
+///
+/// A force deleted list, or a deleted list with identities, is kept as a
+/// tombstone rejected by every instruction but `ReclaimWalletEntry`, which lets
+/// anyone close its orphaned wallet entries, buckets and identities, refunding
+/// the authority that deleted it. The tombstone closes along with the last of
+/// them, as does the deposit vault of the list, kept until then to refund the
+/// deposits of enrolled wallets.
 pub struct DeleteList<'a> {
     pub authority: &'a AccountInfo,
     pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+    /// Audit log of the list, passed after the mint config when the list keeps one.
+    pub audit_log: Option<&'a AccountInfo>,
+    /// Registry of the lists of the authority, the list is removed from it.
+    pub list_registry: &'a AccountInfo,
+    /// Metadata of the list, passed after the registry when the list has one.
+    pub list_metadata: Option<&'a AccountInfo>,
+    /// Deposit vault of the list, passed after the metadata when the list has one.
+    pub deposit_vault: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for DeleteList<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, list_config] = accounts else {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -22,31 +43,104 @@ impl<'a> TryFrom<&'a [AccountInfo]> for DeleteList<'a> {
         Ok(Self {
             authority,
             list_config,
+            mint_config: remaining_accounts.first(),
+            audit_log: remaining_accounts.get(1),
+            list_registry: remaining_accounts
+                .get(2)
+                .ok_or(ABLError::NotEnoughAccounts)?,
+            list_metadata: remaining_accounts.get(3),
+            deposit_vault: remaining_accounts.get(4),
         })
     }
 }
 
 impl<'a> DeleteList<'a> {
     pub const DISCRIMINATOR: u8 = 0x05;
+    pub const FORCE_DISCRIMINATOR: u8 = 0x19;
 
     pub fn process(&self) -> ProgramResult {
-        {
-            let list_config =
-                unsafe { load::<ListConfig>(self.list_config.borrow_data_unchecked())? };
+        self.delete(false)
+    }
+
+    pub fn process_force(&self) -> ProgramResult {
+        self.delete(true)
+    }
 
-            if list_config.get_wallets_count() > 0 {
+    fn delete(&self, force: bool) -> ProgramResult {
+        let (has_audit_log, has_metadata, has_deposit_vault, wallets_count, identities_count) = {
+            let list_config = load_account_versioned::<ListConfig>(self.list_config)?;
+
+            list_config.check_authority(self.authority, self.mint_config)?;
+
+            let wallets_count = list_config.get_wallets_count();
+            if wallets_count > 0 && !force {
                 return Err(ABLError::ListNotEmpty.into());
             }
+
+            (
+                list_config.has_audit_log(),
+                list_config.has_metadata(),
+                list_config.has_deposit_vault(),
+                wallets_count,
+                list_config.get_identities_count(),
+            )
+        };
+
+        // the audit log and metadata go with the list, otherwise their rent would be stranded
+        if has_audit_log {
+            let audit_log = self.audit_log.ok_or(ABLError::InvalidAuditLog)?;
+            if !audit_log.is_owned_by(&crate::ID) || !audit_log.is_writable() {
+                return Err(ABLError::InvalidAuditLog.into());
+            }
+
+            match load_account::<AuditLog>(audit_log) {
+                Ok(log) if log.list_config.eq(self.list_config.key()) => {}
+                _ => return Err(ABLError::InvalidAuditLog.into()),
+            }
+
+            close_account(audit_log, self.authority)?;
         }
 
-        let list_config_lamports = unsafe { self.list_config.borrow_mut_lamports_unchecked() };
-        let authority_lamports = unsafe { self.authority.borrow_mut_lamports_unchecked() };
+        if has_metadata {
+            let list_metadata = self.list_metadata.ok_or(ABLError::InvalidListMetadata)?;
+            if !list_metadata.is_owned_by(&crate::ID) || !list_metadata.is_writable() {
+                return Err(ABLError::InvalidListMetadata.into());
+            }
+
+            match load_account::<ListMetadata>(list_metadata) {
+                Ok(metadata) if metadata.list_config.eq(self.list_config.key()) => {}
+                _ => return Err(ABLError::InvalidListMetadata.into()),
+            }
 
-        *authority_lamports += *list_config_lamports;
-        *list_config_lamports = 0;
+            close_account(list_metadata, self.authority)?;
+        }
 
-        self.list_config.resize(0)?;
+        // an empty list holds no deposits, otherwise the vault is closed by the
+        // last reclaim, enrolled wallets getting their deposit back until then
+        if has_deposit_vault && wallets_count == 0 {
+            let deposit_vault = self.deposit_vault.ok_or(ABLError::InvalidDepositVault)?;
+            check_deposit_vault(deposit_vault, self.list_config.key())?;
+            close_account(deposit_vault, self.authority)?;
+        }
+
+        unregister_list(self.list_registry, self.authority, self.list_config.key())?;
+
+        events::list_deleted(self.list_config.key());
+
+        // identities are reclaimed from the tombstone as wallets are
+        if wallets_count > 0 || identities_count > 0 {
+            // older layouts must be migrated first, the tombstone keeps the current one
+            let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+            list_config.discriminator = ListConfig::TOMBSTONE_DISCRIMINATOR;
+            list_config.authority = *self.authority.key();
+            list_config.audit_log = 0;
+            list_config.metadata = 0;
+            if wallets_count == 0 {
+                list_config.deposit_vault = 0;
+            }
+            return Ok(());
+        }
 
-        Ok(())
+        close_account(self.list_config, self.authority)
     }
 }
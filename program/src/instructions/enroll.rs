@@ -0,0 +1,154 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::Signer,
+    seeds,
+    sysvars::{clock::Clock, rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events, load_account_mut, load_mut_unchecked,
+    utils::{check_deposit_vault, record_audit, resolve_bump},
+    ABLError, AuditOperation, Discriminator, ListConfig, Transmutable, Versioned, WalletEntry,
+};
+
+/// Lets a wallet add itself to an open enrollment list, paying the rent of its
+/// entry and the deposit set on the list into the deposit vault.
+///
+/// Enrollments are not counted against the per epoch limit, which wallets
+/// could otherwise use up to keep the authority and guardian from acting.
+pub struct Enroll<'a> {
+    pub wallet: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+    pub deposit_vault: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    /// Audit log of the list, required when the list keeps one.
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Enroll<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [wallet, list_config, wallet_entry, deposit_vault, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !wallet.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !wallet_entry.is_writable() || !deposit_vault.is_writable()
+        {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            wallet,
+            list_config,
+            wallet_entry,
+            deposit_vault,
+            system_program,
+            audit_log: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> Enroll<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1E;
+
+    pub fn process(&self) -> ProgramResult {
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        if !list_config.is_open_enrollment() {
+            return Err(ABLError::EnrollmentClosed.into());
+        }
+
+        check_deposit_vault(self.deposit_vault, self.list_config.key())?;
+
+        let wallet_entry_bump = resolve_bump(
+            &[
+                WalletEntry::SEED_PREFIX,
+                self.list_config.key(),
+                self.wallet.key(),
+            ],
+            self.wallet_entry.key(),
+        )?;
+
+        if self.wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::EntryAlreadyExists.into());
+        }
+
+        let clock = Clock::get()?;
+
+        // enrolled wallets wait for the list delay as any wallet added to it
+        let active_from = list_config.get_added_wallet_active_from(clock.unix_timestamp)?;
+
+        let bump_seed = [wallet_entry_bump];
+        let seeds = seeds!(
+            WalletEntry::SEED_PREFIX,
+            self.list_config.key(),
+            self.wallet.key(),
+            &bump_seed
+        );
+        let signer = Signer::from(&seeds);
+
+        pinocchio_system::instructions::CreateAccount {
+            from: self.wallet,
+            to: self.wallet_entry,
+            lamports: Rent::get()?.minimum_balance(WalletEntry::LEN),
+            space: WalletEntry::LEN as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+
+        let deposit = list_config.get_enrollment_deposit();
+        if deposit > 0 {
+            pinocchio_system::instructions::Transfer {
+                from: self.wallet,
+                to: self.deposit_vault,
+                lamports: deposit,
+            }
+            .invoke()?;
+        }
+
+        let mut data = self.wallet_entry.try_borrow_mut_data()?;
+        let wallet_entry = load_mut_unchecked::<WalletEntry>(&mut data)?;
+        wallet_entry.discriminator = WalletEntry::DISCRIMINATOR;
+        wallet_entry.version = WalletEntry::VERSION;
+        wallet_entry.wallet_address = *self.wallet.key();
+        wallet_entry.list_config = *self.list_config.key();
+        wallet_entry.active_from = active_from.to_le_bytes();
+        wallet_entry.bump = wallet_entry_bump;
+        wallet_entry.enrolled = 1;
+        wallet_entry.deposit = deposit.to_le_bytes();
+
+        list_config.increment_wallets_count()?;
+
+        record_audit(
+            &list_config,
+            self.list_config.key(),
+            self.audit_log,
+            AuditOperation::WalletAdded,
+            self.wallet.key(),
+            self.wallet.key(),
+            clock.slot,
+        )?;
+
+        events::wallet_added(self.list_config.key(), self.wallet.key(), self.wallet.key());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events, load_account_mut, load_account_versioned,
+    utils::{check_deposit_vault, close_account, parse_flag, record_audit, transfer_lamports},
+    ABLError, AuditOperation, ListConfig, WalletEntry,
+};
+
+/// Removes a wallet that enrolled itself, refunding it the rent of its entry.
+/// The authority either refunds the deposit of the wallet or keeps it.
+pub struct EvictWallet<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    /// Evicted wallet, receiving the rent of its entry and a refunded deposit.
+    pub wallet: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+    pub deposit_vault: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+    /// Audit log of the list, passed after the mint config when the list keeps one.
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for EvictWallet<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, wallet, wallet_entry, deposit_vault, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        if !authority.is_writable()
+            || !list_config.is_writable()
+            || !wallet.is_writable()
+            || !wallet_entry.is_writable()
+            || !deposit_vault.is_writable()
+        {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            wallet,
+            wallet_entry,
+            deposit_vault,
+            mint_config: remaining_accounts.first(),
+            audit_log: remaining_accounts.get(1),
+        })
+    }
+}
+
+impl<'a> EvictWallet<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1F;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [refund_deposit] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+        let refund_deposit = parse_flag(*refund_deposit)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        // evicting from an allow list is restrictive, so the guardian can evict too
+        let by_guardian =
+            list_config.check_change_authority(self.authority, self.mint_config, true)?;
+
+        let clock = Clock::get()?;
+        if !by_guardian {
+            list_config.record_changes(1, clock.epoch)?;
+        }
+
+        let deposit = {
+            let entry = load_account_versioned::<WalletEntry>(self.wallet_entry)?;
+            if entry.list_config.ne(self.list_config.key())
+                || entry.wallet_address.ne(self.wallet.key())
+                || !entry.is_enrolled()
+            {
+                return Err(ABLError::InvalidAccountData.into());
+            }
+            entry.get_deposit()
+        };
+
+        if deposit > 0 {
+            check_deposit_vault(self.deposit_vault, self.list_config.key())?;
+
+            // only the authority may keep deposits, the guardian always refunds them
+            let destination = if refund_deposit == 0 && !by_guardian {
+                self.authority
+            } else {
+                self.wallet
+            };
+            transfer_lamports(self.deposit_vault, destination, deposit)?;
+        }
+
+        close_account(self.wallet_entry, self.wallet)?;
+
+        list_config.decrement_wallets_count()?;
+
+        record_audit(
+            &list_config,
+            self.list_config.key(),
+            self.audit_log,
+            AuditOperation::WalletRemoved,
+            self.wallet.key(),
+            self.authority.key(),
+            clock.slot,
+        )?;
+
+        events::wallet_removed(
+            self.list_config.key(),
+            self.wallet.key(),
+            self.authority.key(),
+        );
+
+        if by_guardian {
+            pinocchio_log::log!(
+                "Guardian evicted wallet {} from list {}",
+                self.wallet.key(),
+                self.list_config.key()
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use pinocchio::{account_info::AccountInfo, cpi::set_return_data, pubkey::Pubkey, ProgramResult};
+use spl_discriminator::SplDiscriminate;
+
+use crate::{ABLError, CanThawPermissionless};
+
+/// Seed of the transfer hook extra metas PDA, as defined by the transfer hook interface.
+pub const EXECUTE_EXTRA_ACCOUNT_METAS_SEED: &[u8] = b"extra-account-metas";
+
+/// SPL transfer hook interface `Execute` instruction.
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("spl-transfer-hook-interface:execute")]
+pub struct ExecuteInstruction;
+
+///
+/// SECURITY ASSUMPTIONS OVER TRANSFER HOOK
+///
+/// 1- its called by the token-2022 program during a transfer
+/// 2- if some other program is calling it, we don't care as we don't write state here
+/// 3- remaining accounts are triples of list, source wallet block and destination wallet block,
+///    followed by the source and destination identities for lists with identities
+/// 4- source and destination owners are screened with the same semantics as CanThawPermissionless
+/// 5- on denial, return data is set to [list_index, DenialReason] and a reason specific error is returned
+
+pub struct Execute<'a> {
+    pub source: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub destination: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub extra_metas: &'a AccountInfo,
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+impl<'a> Execute<'a> {
+    pub const DISCRIMINATOR: &'static [u8] = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE;
+
+    pub fn process(&self) -> ProgramResult {
+        let source_owner = Self::get_owner(self.source)?;
+        let destination_owner = Self::get_owner(self.destination)?;
+
+        // remaining accounts should be triples of list, source and destination ab_wallet,
+        // followed by the source and destination identities for lists with identities
+        let mut remaining_accounts = self.remaining_accounts.iter();
+        let mut list_index: u8 = 0;
+        while let Some(list) = remaining_accounts.next() {
+            let source_ab_wallet = remaining_accounts
+                .next()
+                .ok_or(ABLError::NotEnoughAccounts)?;
+            let destination_ab_wallet = remaining_accounts
+                .next()
+                .ok_or(ABLError::NotEnoughAccounts)?;
+            let (source_identity, destination_identity) =
+                if CanThawPermissionless::has_identities(list) {
+                    (
+                        Some(
+                            remaining_accounts
+                                .next()
+                                .ok_or(ABLError::NotEnoughAccounts)?,
+                        ),
+                        Some(
+                            remaining_accounts
+                                .next()
+                                .ok_or(ABLError::NotEnoughAccounts)?,
+                        ),
+                    )
+                } else {
+                    (None, None)
+                };
+
+            let result = CanThawPermissionless::validate_thaw_list(
+                list,
+                &source_owner,
+                Some(source_ab_wallet),
+                source_identity,
+            )
+            .map_err(|reason| ("source", reason))
+            .and_then(|_| {
+                CanThawPermissionless::validate_thaw_list(
+                    list,
+                    &destination_owner,
+                    Some(destination_ab_wallet),
+                    destination_identity,
+                )
+                .map_err(|reason| ("destination", reason))
+            });
+
+            if let Err((side, reason)) = result {
+                pinocchio_log::log!(
+                    "Failed to pass {} validation for list {} ({}), reason {}",
+                    side,
+                    list.key(),
+                    list_index,
+                    reason as u8
+                );
+                set_return_data(&[list_index, reason as u8]);
+                return Err(ABLError::from(reason).into());
+            }
+
+            list_index += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Owner of a token account, stored right after the mint.
+    fn get_owner(token_account: &AccountInfo) -> Result<Pubkey, ABLError> {
+        let data = token_account
+            .try_borrow_data()
+            .map_err(|_| ABLError::InvalidAccountData)?;
+        data.get(32..64)
+            .and_then(|owner| owner.try_into().ok())
+            .ok_or(ABLError::InvalidAccountData)
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Execute<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        /*
+        TX HOOK GETS CALLED WITH:
+         1- source token account
+         2- mint
+         3- destination token account
+         4- source authority
+         5- extra account metas
+         6..- (optional) list, source wallet block, destination wallet block
+         */
+
+        let [source, mint, destination, authority, extra_metas, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        Ok(Self {
+            source,
+            mint,
+            destination,
+            authority,
+            extra_metas,
+            remaining_accounts,
+        })
+    }
+}
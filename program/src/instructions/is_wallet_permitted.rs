@@ -0,0 +1,170 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    cpi::set_return_data,
+    pubkey::{find_program_address, Pubkey},
+    ProgramResult,
+};
+
+use crate::{
+    load_account_versioned, load_bucket, utils::check_program_address, ABLError, Bucket,
+    CanThawPermissionless, ListConfig, Mode, Storage, WalletEntry,
+};
+
+/// Read-only query for other programs: would `wallet` pass `list_config`?
+///
+/// The verdict is reported through return data as [permitted, DenialReason]
+/// (reason is 0 when permitted) instead of failing the instruction.
+/// Callers must pass the wallet entry PDA (or the bucket PDA for bucket
+/// storage lists) for block lists, as its absence is what lets a wallet through.
+/// For lists with identities, the identity the wallet entry is linked to must
+/// be passed as well.
+pub struct IsWalletPermitted<'a> {
+    pub list_config: &'a AccountInfo,
+    pub wallet: &'a AccountInfo,
+    pub wallet_entry: Option<&'a AccountInfo>,
+    pub identity: Option<&'a AccountInfo>,
+}
+
+impl<'a> IsWalletPermitted<'a> {
+    pub const DISCRIMINATOR: u8 = 0x06;
+
+    pub fn process(&self) -> ProgramResult {
+        // an invalid list is reported through the verdict by validate_thaw_list
+        if let Ok(list_config) = load_account_versioned::<ListConfig>(self.list_config) {
+            // attribute lists read the wallet entries of their source list
+            let entries_list = match list_config.get_mode() {
+                Ok(Mode::Attributes) => &list_config.attributes_source,
+                _ => self.list_config.key(),
+            };
+
+            // block and risk score lists let wallets without an entry through
+            let absence_permits =
+                matches!(list_config.get_mode(), Ok(Mode::Block | Mode::RiskScore));
+
+            match self.wallet_entry {
+                Some(wallet_entry) => self.check_wallet_entry(
+                    list_config.get_storage(),
+                    entries_list,
+                    wallet_entry,
+                    absence_permits,
+                )?,
+                // without an entry a block or risk score list cannot prove the wallet is absent
+                None if absence_permits => {
+                    return Err(ABLError::NotEnoughAccounts.into());
+                }
+                None => {}
+            }
+        }
+
+        match CanThawPermissionless::validate_thaw_list(
+            self.list_config,
+            self.wallet.key(),
+            self.wallet_entry,
+            self.identity,
+        ) {
+            Ok(()) => set_return_data(&[1, 0]),
+            Err(reason) => set_return_data(&[0, reason as u8]),
+        }
+
+        Ok(())
+    }
+
+    /// Ensures the supplied wallet entry (or bucket) belongs to `entries_list`
+    /// and the wallet, so it can't be swapped for an unrelated account.
+    ///
+    /// Existing accounts are checked through their contents, and wallet entries
+    /// through their stored bump as well. Only an absent account has its address
+    /// derived, when its absence lets the wallet through: any other account can
+    /// only lead to a denial.
+    fn check_wallet_entry(
+        &self,
+        storage: Storage,
+        entries_list: &Pubkey,
+        wallet_entry: &AccountInfo,
+        absence_permits: bool,
+    ) -> ProgramResult {
+        let prefix_seed = [self.wallet.key()[0]];
+
+        if let Storage::Buckets = storage {
+            if wallet_entry.is_owned_by(&crate::ID) {
+                let data = wallet_entry.try_borrow_data()?;
+                if let Ok((bucket, _)) = load_bucket(&data) {
+                    if bucket.list_config.ne(self.list_config.key())
+                        || bucket.prefix != prefix_seed[0]
+                    {
+                        return Err(ABLError::InvalidAccountData.into());
+                    }
+                    return Ok(());
+                }
+            }
+
+            return Self::check_absent(
+                &[Bucket::SEED_PREFIX, self.list_config.key(), &prefix_seed],
+                wallet_entry,
+                absence_permits,
+            );
+        }
+
+        let seeds: [&[u8]; 3] = [WalletEntry::SEED_PREFIX, entries_list, self.wallet.key()];
+
+        if let Ok(entry) = load_account_versioned::<WalletEntry>(wallet_entry) {
+            if entry.list_config.ne(entries_list) || entry.wallet_address.ne(self.wallet.key()) {
+                return Err(ABLError::InvalidAccountData.into());
+            }
+
+            // entries of the original layout have their bump derived when read
+            check_program_address(&seeds, entry.bump, wallet_entry.key())?;
+            return Ok(());
+        }
+
+        Self::check_absent(&seeds, wallet_entry, absence_permits)
+    }
+
+    /// Ensures an account holding no wallet entry (or bucket) is at the address
+    /// derived from `seeds` when its absence lets the wallet through, as only
+    /// that address can hold one.
+    fn check_absent(
+        seeds: &[&[u8]],
+        wallet_entry: &AccountInfo,
+        absence_permits: bool,
+    ) -> ProgramResult {
+        if !absence_permits {
+            return Ok(());
+        }
+
+        let (expected, _) = find_program_address(seeds, &crate::ID);
+        if expected.ne(wallet_entry.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for IsWalletPermitted<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [list_config, wallet, wallet_entry, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        // optional accounts are passed as the program id when omitted
+        let wallet_entry = if wallet_entry.key().eq(&crate::ID) {
+            None
+        } else {
+            Some(wallet_entry)
+        };
+        // identity is only needed for lists with identities
+        let identity = remaining_accounts
+            .first()
+            .filter(|identity| identity.key().ne(&crate::ID));
+
+        Ok(Self {
+            list_config,
+            wallet,
+            wallet_entry,
+            identity,
+        })
+    }
+}
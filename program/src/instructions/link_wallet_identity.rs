@@ -0,0 +1,101 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account, load_account_mut, ABLError, Identity, ListConfig, WalletEntry};
+
+/// Links a wallet entry to an identity, or unlinks it when no identity is passed.
+///
+/// Counts as a change against the per epoch limit. As moving a wallet off a
+/// blocked identity lets it through, relinking on a list with a delay sets the
+/// entry aside until the delay elapsed, the same as a newly added wallet.
+pub struct LinkWalletIdentity<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+    pub identity: Option<&'a AccountInfo>,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for LinkWalletIdentity<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, wallet_entry, identity, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) || !wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !wallet_entry.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // optional accounts are passed as the program id when omitted
+        let identity = if identity.key().eq(&crate::ID) {
+            None
+        } else {
+            Some(identity)
+        };
+
+        Ok(Self {
+            authority,
+            list_config,
+            wallet_entry,
+            identity,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> LinkWalletIdentity<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0E;
+
+    pub fn process(&self) -> ProgramResult {
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        if !list_config.has_identities() {
+            return Err(ABLError::InvalidConfigAccount.into());
+        }
+
+        let clock = Clock::get()?;
+        list_config.record_changes(1, clock.epoch)?;
+
+        let id = match self.identity {
+            Some(identity) => {
+                if !identity.is_owned_by(&crate::ID) {
+                    return Err(ABLError::InvalidAccountData.into());
+                }
+                let identity = load_account::<Identity>(identity)?;
+                if identity.list_config.ne(self.list_config.key()) {
+                    return Err(ABLError::InvalidAccountData.into());
+                }
+                identity.id
+            }
+            None => Pubkey::default(),
+        };
+
+        let mut wallet_entry = load_account_mut::<WalletEntry>(self.wallet_entry)?;
+
+        if wallet_entry.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        if wallet_entry.identity.ne(&id) {
+            wallet_entry.active_from = list_config
+                .get_added_wallet_active_from(clock.unix_timestamp)?
+                .to_le_bytes();
+        }
+        wallet_entry.identity = id;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use bytemuck::Pod;
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{
+    load_account_versioned, utils::resize_with_rent, ABLError, Discriminator, ListConfig,
+    Versioned, VersionedRef, WalletEntry,
+};
+
+/// Migrates a list config or wallet entry of the original layout to the
+/// current one, growing it and filling in the fields it lacked. Anyone can pay
+/// for a migration since it leaves the meaning of the account unchanged.
+pub struct MigrateAccount<'a> {
+    pub payer: &'a AccountInfo,
+    pub account: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for MigrateAccount<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [payer, account, system_program] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !payer.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !account.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        if !payer.is_writable() || !account.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            payer,
+            account,
+            system_program,
+        })
+    }
+}
+
+impl<'a> MigrateAccount<'a> {
+    pub const DISCRIMINATOR: u8 = 0x17;
+
+    pub fn process(&self) -> ProgramResult {
+        let discriminator = *self
+            .account
+            .try_borrow_data()?
+            .first()
+            .ok_or(ABLError::InvalidAccountData)?;
+
+        match discriminator {
+            ListConfig::DISCRIMINATOR => self.migrate::<ListConfig>(),
+            WalletEntry::DISCRIMINATOR => self.migrate::<WalletEntry>(),
+            _ => Err(ABLError::InvalidAccountData.into()),
+        }
+    }
+
+    fn migrate<T: Discriminator + Versioned + Pod>(&self) -> ProgramResult {
+        // validates the account holds a known version of `T`, upgrading it
+        let upgraded = match load_account_versioned::<T>(self.account)? {
+            VersionedRef::Current(_) => return Ok(()),
+            VersionedRef::Older(upgraded) => upgraded,
+        };
+
+        resize_with_rent(self.account, self.payer, T::LEN)?;
+        self.account
+            .try_borrow_mut_data()?
+            .copy_from_slice(bytemuck::bytes_of(&upgraded));
+
+        Ok(())
+    }
+}
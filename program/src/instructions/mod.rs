@@ -1,13 +1,67 @@
+pub mod add_bucket_wallets;
 pub mod add_wallet;
 pub mod can_thaw_permissionless;
+pub mod create_audit_log;
+pub mod create_identity;
 pub mod create_list;
+pub mod create_mint_list;
 pub mod delete_list;
+pub mod enroll;
+pub mod evict_wallet;
+pub mod execute;
+pub mod is_wallet_permitted;
+pub mod link_wallet_identity;
+pub mod migrate_account;
+pub mod prune_list_registry;
+pub mod reclaim_wallet_entry;
+pub mod remove_bucket_wallets;
 pub mod remove_wallet;
+pub mod rotate_wallet;
+pub mod set_activation_window;
+pub mod set_attribute_policy;
+pub mod set_guardian;
+pub mod set_identity_status;
+pub mod set_list_limits;
+pub mod set_list_paused;
+pub mod set_open_enrollment;
+pub mod set_risk_threshold;
+pub mod set_self_rotation;
+pub mod set_self_thaw_only;
+pub mod set_wallet_attributes;
 pub mod setup_extra_metas;
+pub mod setup_transfer_hook_extra_metas;
+pub mod update_list_metadata;
 
+pub use add_bucket_wallets::*;
 pub use add_wallet::*;
 pub use can_thaw_permissionless::*;
+pub use create_audit_log::*;
+pub use create_identity::*;
 pub use create_list::*;
+pub use create_mint_list::*;
 pub use delete_list::*;
+pub use enroll::*;
+pub use evict_wallet::*;
+pub use execute::*;
+pub use is_wallet_permitted::*;
+pub use link_wallet_identity::*;
+pub use migrate_account::*;
+pub use prune_list_registry::*;
+pub use reclaim_wallet_entry::*;
+pub use remove_bucket_wallets::*;
 pub use remove_wallet::*;
+pub use rotate_wallet::*;
+pub use set_activation_window::*;
+pub use set_attribute_policy::*;
+pub use set_guardian::*;
+pub use set_identity_status::*;
+pub use set_list_limits::*;
+pub use set_list_paused::*;
+pub use set_open_enrollment::*;
+pub use set_risk_threshold::*;
+pub use set_self_rotation::*;
+pub use set_self_thaw_only::*;
+pub use set_wallet_attributes::*;
 pub use setup_extra_metas::*;
+pub use setup_transfer_hook_extra_metas::*;
+pub use update_list_metadata::*;
@@ -0,0 +1,76 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
+
+use crate::{load_account_versioned, utils::unregister_list, ABLError, ListConfig};
+
+/// Removes a list from the registry of the lists of an authority no longer
+/// controlling it: the list was deleted, or the freeze authority of the mint of
+/// a mint bound list moved to another key. Deleting a list only clears the
+/// registry of the authority deleting it, so this is how the registry of a
+/// previous freeze authority gets rid of it, refunding the rent of the record.
+pub struct PruneListRegistry<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_registry: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    /// Token-acl mint config of a mint bound list still in use, proving its
+    /// freeze authority moved.
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PruneListRegistry<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_registry, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !list_registry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidListRegistry);
+        }
+
+        if !authority.is_writable() || !list_registry.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_registry,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> PruneListRegistry<'a> {
+    pub const DISCRIMINATOR: u8 = 0x22;
+
+    pub fn process(&self) -> ProgramResult {
+        if self.is_controlled()? {
+            return Err(ABLError::ListStillControlled.into());
+        }
+
+        unregister_list(self.list_registry, self.authority, self.list_config.key())
+    }
+
+    /// Whether the list still exists under the authority of the registry.
+    fn is_controlled(&self) -> Result<bool, ProgramError> {
+        // a deleted list leaves either nothing or a tombstone behind
+        if !self.list_config.is_owned_by(&crate::ID) {
+            return Ok(false);
+        }
+
+        let discriminator = self.list_config.try_borrow_data()?.first().copied();
+        if discriminator == Some(ListConfig::TOMBSTONE_DISCRIMINATOR) {
+            return Ok(false);
+        }
+
+        let list_config = load_account_versioned::<ListConfig>(self.list_config)?;
+        Ok(list_config
+            .get_authority(self.mint_config)?
+            .eq(self.authority.key()))
+    }
+}
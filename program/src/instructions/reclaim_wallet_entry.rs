@@ -0,0 +1,166 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
+
+use crate::{
+    events, load_account, load_account_versioned, load_bucket, load_mut_unchecked,
+    utils::{check_deposit_vault, close_account, transfer_lamports},
+    ABLError, Identity, ListConfig, WalletEntry,
+};
+
+/// Closes a wallet entry, bucket or identity orphaned by a deleted list,
+/// refunding the authority that deleted it. Anyone can reclaim, and reclaiming the last
+/// wallets of the list closes its tombstone as well, along with its deposit
+/// vault.
+///
+/// Enrolled wallets paid for their entry, so they get its rent back instead,
+/// along with their deposit.
+pub struct ReclaimWalletEntry<'a> {
+    pub list_config: &'a AccountInfo,
+    /// Wallet entry, bucket or identity of the list.
+    pub wallet_entry: &'a AccountInfo,
+    /// Authority recorded in the tombstone, receiving the rent.
+    pub authority: &'a AccountInfo,
+    /// Wallet of an enrolled entry.
+    pub wallet: Option<&'a AccountInfo>,
+    /// Deposit vault of the list, required for enrolled entries holding a
+    /// deposit and for the last wallets of lists with a vault.
+    pub deposit_vault: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReclaimWalletEntry<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [list_config, wallet_entry, authority, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        if !list_config.is_writable() || !wallet_entry.is_writable() || !authority.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            list_config,
+            wallet_entry,
+            authority,
+            wallet: remaining_accounts.first(),
+            deposit_vault: remaining_accounts.get(1),
+        })
+    }
+}
+
+impl<'a> ReclaimWalletEntry<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1A;
+
+    pub fn process(&self) -> ProgramResult {
+        let (wallets_count, identities_count, has_deposit_vault, enrollment) = {
+            let mut data = self.list_config.try_borrow_mut_data()?;
+            let tombstone = load_mut_unchecked::<ListConfig>(&mut data)?;
+            if !tombstone.is_tombstone() {
+                return Err(ABLError::ListNotDeleted.into());
+            }
+
+            if tombstone.authority.ne(self.authority.key()) {
+                return Err(ABLError::InvalidAuthority.into());
+            }
+
+            let enrollment = if self.is_identity()? {
+                tombstone.decrement_identities_count()?;
+                None
+            } else {
+                let (reclaimed, enrollment) = self.reclaimed_wallets()?;
+                tombstone.sub_wallets_count(reclaimed)?;
+                enrollment
+            };
+            (
+                tombstone.get_wallets_count(),
+                tombstone.get_identities_count(),
+                tombstone.has_deposit_vault(),
+                enrollment,
+            )
+        };
+
+        let destination = match enrollment {
+            Some((wallet, deposit)) => {
+                let wallet = self
+                    .wallet
+                    .filter(|account| account.key().eq(&wallet))
+                    .ok_or(ABLError::InvalidAccountData)?;
+
+                if deposit > 0 {
+                    let deposit_vault = self.deposit_vault.ok_or(ABLError::InvalidDepositVault)?;
+                    check_deposit_vault(deposit_vault, self.list_config.key())?;
+                    transfer_lamports(deposit_vault, wallet, deposit)?;
+                }
+
+                wallet
+            }
+            None => self.authority,
+        };
+
+        close_account(self.wallet_entry, destination)?;
+
+        if wallets_count == 0 && identities_count == 0 {
+            if has_deposit_vault {
+                let deposit_vault = self.deposit_vault.ok_or(ABLError::InvalidDepositVault)?;
+                check_deposit_vault(deposit_vault, self.list_config.key())?;
+                close_account(deposit_vault, self.authority)?;
+            }
+
+            close_account(self.list_config, self.authority)?;
+        }
+
+        Ok(())
+    }
+
+    /// Whether the reclaimed account is an identity, which must belong to the list.
+    fn is_identity(&self) -> Result<bool, ProgramError> {
+        match load_account::<Identity>(self.wallet_entry) {
+            Ok(identity) if identity.list_config.ne(self.list_config.key()) => {
+                Err(ABLError::InvalidAccountData.into())
+            }
+            Ok(_) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    /// Number of wallets held by the reclaimed account, which must belong to
+    /// the list, along with the wallet and deposit of an enrolled entry. Each
+    /// wallet is reported removed on behalf of the authority, as anyone reclaims.
+    fn reclaimed_wallets(&self) -> Result<(u64, Option<(Pubkey, u64)>), ProgramError> {
+        if let Ok(entry) = load_account_versioned::<WalletEntry>(self.wallet_entry) {
+            if entry.list_config.ne(self.list_config.key()) {
+                return Err(ABLError::InvalidAccountData.into());
+            }
+            events::wallet_removed(
+                self.list_config.key(),
+                &entry.wallet_address,
+                self.authority.key(),
+            );
+            let enrollment = entry
+                .is_enrolled()
+                .then(|| (entry.wallet_address, entry.get_deposit()));
+            return Ok((1, enrollment));
+        }
+
+        let data = self.wallet_entry.try_borrow_data()?;
+        let (bucket, wallets) = load_bucket(&data)?;
+        if bucket.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+        for wallet in wallets {
+            events::wallet_removed(self.list_config.key(), wallet, self.authority.key());
+        }
+
+        Ok((wallets.len() as u64, None))
+    }
+}
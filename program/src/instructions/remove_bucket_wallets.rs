@@ -0,0 +1,125 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events, load_account_mut, load_bucket_mut_unchecked, parse_bucket_wallets,
+    utils::{close_account, resize_with_rent},
+    ABLError, Bucket, Discriminator, ListConfig, Mode, Storage,
+};
+
+/// Removes a batch of wallets from the bucket of a bucket storage list,
+/// closing the bucket once it is empty.
+pub struct RemoveBucketWallets<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub bucket: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+    /// List authority, refunded the freed rent when the guardian removes the wallets.
+    pub list_authority: Option<&'a AccountInfo>,
+}
+
+impl<'a> RemoveBucketWallets<'a> {
+    pub const DISCRIMINATOR: u8 = 0x09;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let wallets = parse_bucket_wallets(remaining_data)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        // the guardian can only remove wallets from allow lists
+        let by_guardian = list_config.check_change_authority(
+            self.authority,
+            self.mint_config,
+            matches!(list_config.get_mode()?, Mode::Allow | Mode::AllowAllEoas),
+        )?;
+
+        if !matches!(list_config.get_storage(), Storage::Buckets) {
+            return Err(ABLError::InvalidStorage.into());
+        }
+
+        if !by_guardian {
+            list_config.record_changes(wallets.len() as u64, Clock::get()?.epoch)?;
+        }
+
+        let count = {
+            let mut data = self.bucket.try_borrow_mut_data()?;
+            let (bucket, entries) = load_bucket_mut_unchecked(&mut data)?;
+            if !bucket.is_initialized() || bucket.list_config.ne(self.list_config.key()) {
+                return Err(ABLError::InvalidAccountData.into());
+            }
+
+            let mut count = entries.len();
+            for wallet in wallets {
+                match entries[..count].binary_search(wallet) {
+                    Ok(position) => {
+                        entries.copy_within(position + 1..count, position);
+                        count -= 1;
+                    }
+                    Err(_) => return Err(ABLError::WalletNotListed.into()),
+                }
+            }
+            count
+        };
+
+        let destination = list_config.get_refund_destination(
+            self.authority,
+            by_guardian,
+            self.mint_config,
+            self.list_authority,
+        )?;
+        if count == 0 {
+            close_account(self.bucket, destination)?;
+        } else {
+            resize_with_rent(self.bucket, destination, Bucket::size(count))?;
+        }
+
+        list_config.sub_wallets_count(wallets.len() as u64)?;
+
+        for wallet in wallets {
+            events::wallet_removed(self.list_config.key(), wallet, self.authority.key());
+        }
+
+        if by_guardian {
+            pinocchio_log::log!(
+                "Guardian removed {} wallets from list {}",
+                wallets.len(),
+                self.list_config.key()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RemoveBucketWallets<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, bucket, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !bucket.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        if !list_config.is_writable() || !bucket.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            bucket,
+            mint_config: remaining_accounts.first(),
+            list_authority: remaining_accounts.get(1),
+        })
+    }
+}
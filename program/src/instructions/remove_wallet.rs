@@ -1,37 +1,83 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult};
-
-use crate::{load, load_mut_unchecked, ABLError, ListConfig, WalletEntry};
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events, load_account_mut, load_account_versioned,
+    utils::{close_account, record_audit},
+    ABLError, AuditOperation, ListConfig, Mode, WalletEntry,
+};
 
 pub struct RemoveWallet<'a> {
     pub authority: &'a AccountInfo,
     pub list_config: &'a AccountInfo,
     pub wallet_entry: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+    /// Audit log of the list, passed after the mint config when the list keeps one.
+    pub audit_log: Option<&'a AccountInfo>,
+    /// List authority, refunded the entry rent when the guardian removes the wallet.
+    pub list_authority: Option<&'a AccountInfo>,
 }
 
 impl<'a> RemoveWallet<'a> {
     pub const DISCRIMINATOR: u8 = 0x03;
 
     pub fn process(&self) -> ProgramResult {
-        let list_config = unsafe {
-            load_mut_unchecked::<ListConfig>(self.list_config.borrow_mut_data_unchecked())?
-        };
-
-        if !self.authority.is_signer() || list_config.authority.ne(self.authority.key()) {
-            return Err(ABLError::InvalidAuthority.into());
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        // the guardian can only remove wallets from allow lists
+        let by_guardian = list_config.check_change_authority(
+            self.authority,
+            self.mint_config,
+            matches!(list_config.get_mode()?, Mode::Allow | Mode::AllowAllEoas),
+        )?;
+
+        let clock = Clock::get()?;
+        if !by_guardian {
+            list_config.record_changes(1, clock.epoch)?;
         }
 
-        let destination_lamports = self.authority.lamports();
+        // read before the entry is closed
+        let wallet = {
+            let entry = load_account_versioned::<WalletEntry>(self.wallet_entry)?;
+            // enrolled wallets paid for their entry, evicting them refunds it
+            if entry.is_enrolled() {
+                return Err(ABLError::EnrolledWallet.into());
+            }
+            entry.wallet_address
+        };
 
-        unsafe {
-            *self.authority.borrow_mut_lamports_unchecked() = destination_lamports
-                .checked_add(self.wallet_entry.lamports())
-                .ok_or(ProgramError::ArithmeticOverflow)?;
-            self.wallet_entry.close_unchecked();
-        }
+        let destination = list_config.get_refund_destination(
+            self.authority,
+            by_guardian,
+            self.mint_config,
+            self.list_authority,
+        )?;
+        close_account(self.wallet_entry, destination)?;
 
         list_config.decrement_wallets_count()?;
 
-        self.wallet_entry.resize(0)?;
+        record_audit(
+            &list_config,
+            self.list_config.key(),
+            self.audit_log,
+            AuditOperation::WalletRemoved,
+            &wallet,
+            self.authority.key(),
+            clock.slot,
+        )?;
+
+        events::wallet_removed(self.list_config.key(), &wallet, self.authority.key());
+
+        if by_guardian {
+            pinocchio_log::log!(
+                "Guardian removed wallet {} from list {}",
+                &wallet,
+                self.list_config.key()
+            );
+        }
 
         Ok(())
     }
@@ -41,7 +87,7 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RemoveWallet<'a> {
     type Error = ABLError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
-        let [authority, list_config, wallet_entry] = accounts else {
+        let [authority, list_config, wallet_entry, remaining_accounts @ ..] = accounts else {
             return Err(ABLError::NotEnoughAccounts);
         };
 
@@ -53,14 +99,20 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RemoveWallet<'a> {
             return Err(ABLError::AccountNotWritable);
         }
 
-        if unsafe { load::<WalletEntry>(wallet_entry.borrow_data_unchecked()).is_err() } {
-            return Err(ABLError::InvalidAccountData);
+        // the entry must belong to the list, otherwise removing it would
+        // close another list's entry and skew this list's count
+        match load_account_versioned::<WalletEntry>(wallet_entry) {
+            Ok(entry) if entry.list_config.eq(list_config.key()) => {}
+            _ => return Err(ABLError::InvalidAccountData),
         }
 
         Ok(Self {
             authority,
             list_config,
             wallet_entry,
+            mint_config: remaining_accounts.first(),
+            audit_log: remaining_accounts.get(1),
+            list_authority: remaining_accounts.get(2),
         })
     }
 }
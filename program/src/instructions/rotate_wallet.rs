@@ -0,0 +1,174 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    seeds,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    events, load_account_mut, load_account_versioned, load_mut_unchecked,
+    utils::{close_account, create_program_account, record_audit, resolve_bump},
+    ABLError, AuditOperation, ListConfig, Storage, Transmutable, WalletEntry,
+};
+
+/// Moves the entry of a wallet to a new address, e.g. after its keys leaked,
+/// closing the entry of the old one. Both wallets sign, along with the list
+/// authority unless the list lets its wallets rotate on their own.
+///
+/// Only rotations the authority co-signs count against the per epoch limit,
+/// which wallets rotating on their own could otherwise use up to keep the
+/// authority and guardian from acting.
+pub struct RotateWallet<'a> {
+    pub old_wallet: &'a AccountInfo,
+    /// Tops up the rent of the new entry when the old one falls short.
+    pub new_wallet: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub old_entry: &'a AccountInfo,
+    pub new_entry: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    /// List authority, required when the list doesn't allow self rotation.
+    pub authority: Option<&'a AccountInfo>,
+    pub mint_config: Option<&'a AccountInfo>,
+    /// Audit log of the list, passed after the mint config when the list keeps one.
+    pub audit_log: Option<&'a AccountInfo>,
+}
+
+impl<'a> RotateWallet<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1C;
+
+    pub fn process(&self) -> ProgramResult {
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        if !matches!(list_config.get_storage(), Storage::Entries) {
+            return Err(ABLError::InvalidStorage.into());
+        }
+
+        let clock = Clock::get()?;
+
+        if list_config.rotation_needs_authority() {
+            let authority = self.authority.ok_or(ABLError::InvalidAuthority)?;
+            list_config.check_authority(authority, self.mint_config)?;
+
+            list_config.record_changes(1, clock.epoch)?;
+        }
+
+        let entry = *load_account_versioned::<WalletEntry>(self.old_entry)?;
+        if entry.list_config.ne(self.list_config.key())
+            || entry.wallet_address.ne(self.old_wallet.key())
+        {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        let new_entry_bump = resolve_bump(
+            &[
+                WalletEntry::SEED_PREFIX,
+                self.list_config.key(),
+                self.new_wallet.key(),
+            ],
+            self.new_entry.key(),
+        )?;
+
+        if self.new_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::EntryAlreadyExists.into());
+        }
+
+        // the rent of the old entry funds the new one
+        close_account(self.old_entry, self.new_entry)?;
+
+        let bump_seed = [new_entry_bump];
+        let seeds = seeds!(
+            WalletEntry::SEED_PREFIX,
+            self.list_config.key(),
+            self.new_wallet.key(),
+            &bump_seed
+        );
+        create_program_account(self.new_entry, self.new_wallet, WalletEntry::LEN, &seeds)?;
+
+        let mut data = self.new_entry.try_borrow_mut_data()?;
+        let new_entry = load_mut_unchecked::<WalletEntry>(&mut data)?;
+        *new_entry = entry;
+        new_entry.wallet_address = *self.new_wallet.key();
+        new_entry.bump = new_entry_bump;
+        // the new wallet is added to the list, waiting for its delay as any other
+        new_entry.active_from = list_config
+            .get_added_wallet_active_from(clock.unix_timestamp)?
+            .to_le_bytes();
+
+        for (operation, wallet) in [
+            (AuditOperation::WalletRemoved, self.old_wallet.key()),
+            (AuditOperation::WalletAdded, self.new_wallet.key()),
+        ] {
+            record_audit(
+                &list_config,
+                self.list_config.key(),
+                self.audit_log,
+                operation,
+                wallet,
+                self.old_wallet.key(),
+                clock.slot,
+            )?;
+        }
+
+        events::wallet_removed(
+            self.list_config.key(),
+            self.old_wallet.key(),
+            self.old_wallet.key(),
+        );
+        events::wallet_added(
+            self.list_config.key(),
+            self.new_wallet.key(),
+            self.old_wallet.key(),
+        );
+
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RotateWallet<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [old_wallet, new_wallet, list_config, old_entry, new_entry, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !old_wallet.is_signer() || !new_wallet.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !old_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidAccountData);
+        }
+
+        if !list_config.is_writable()
+            || !old_entry.is_writable()
+            || !new_entry.is_writable()
+            || !new_wallet.is_writable()
+        {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            old_wallet,
+            new_wallet,
+            list_config,
+            old_entry,
+            new_entry,
+            system_program,
+            authority: remaining_accounts.first(),
+            mint_config: remaining_accounts.get(1),
+            audit_log: remaining_accounts.get(2),
+        })
+    }
+}
@@ -0,0 +1,70 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, utils::parse_flag, ABLError, ListConfig};
+
+/// Schedules when a list is effective, e.g. launch allow lists that start at
+/// a given time or temporary block lists that lapse. Outside the window the
+/// list is either skipped or denies every wallet. A zero bound is open ended.
+/// Counts as a change against the per epoch limit.
+pub struct SetActivationWindow<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetActivationWindow<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetActivationWindow<'a> {
+    pub const DISCRIMINATOR: u8 = 0x14;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        if remaining_data.len() != 17 {
+            return Err(ABLError::InvalidData.into());
+        }
+        let active_from = i64::from_le_bytes(remaining_data[..8].try_into().unwrap());
+        let active_until = i64::from_le_bytes(remaining_data[8..16].try_into().unwrap());
+        let deny_when_inactive = parse_flag(remaining_data[16])?;
+
+        if active_until != 0 && active_until <= active_from {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        list_config.record_changes(1, Clock::get()?.epoch)?;
+
+        list_config.active_from = active_from.to_le_bytes();
+        list_config.active_until = active_until.to_le_bytes();
+        list_config.deny_when_inactive = deny_when_inactive;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::Pubkey,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, load_account_versioned, ABLError, ListConfig, Mode, Storage};
+
+/// Sets the required and forbidden attributes of an attribute list, along
+/// with the list whose wallet entries are checked against them.
+///
+/// Counts as a change against the per epoch limit. Narrowing the policy on the
+/// same source applies immediately, any other change only blocks the wallets it
+/// would let through until `allow_delay` elapsed: it is staged, then applied by
+/// setting the same policy again. Applying a policy drops the pending one.
+///
+/// Sources are allowing lists, so changes to the attributes of their entries
+/// are held to the delay as well. Changing the source list requires setting up
+/// the extra metas again.
+pub struct SetAttributePolicy<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub attributes_source: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetAttributePolicy<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, attributes_source, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) || !attributes_source.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            attributes_source,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetAttributePolicy<'a> {
+    pub const DISCRIMINATOR: u8 = 0x10;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        if remaining_data.len() != 16 {
+            return Err(ABLError::InvalidData.into());
+        }
+        let (required, forbidden) = remaining_data.split_at(8);
+        let required = u64::from_le_bytes(required.try_into().unwrap());
+        let forbidden = u64::from_le_bytes(forbidden.try_into().unwrap());
+        let source = self.attributes_source.key();
+
+        // the source may be the list itself
+        if source.ne(self.list_config.key()) {
+            let source = load_account_versioned::<ListConfig>(self.attributes_source)?;
+            if !matches!(source.get_storage(), Storage::Entries) {
+                return Err(ABLError::InvalidStorage.into());
+            }
+            if !source.is_allowing() {
+                return Err(ABLError::InvalidData.into());
+            }
+        }
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        if !matches!(list_config.get_mode()?, Mode::Attributes) {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        let clock = Clock::get()?;
+        list_config.record_changes(1, clock.epoch)?;
+
+        // more required or forbidden attributes on the same source only block more wallets
+        let current_required = list_config.get_required_attributes();
+        let current_forbidden = list_config.get_forbidden_attributes();
+        let restrictive = source.eq(&list_config.attributes_source)
+            && required & current_required == current_required
+            && forbidden & current_forbidden == current_forbidden;
+
+        if !restrictive {
+            let applies_from = list_config.get_added_wallet_active_from(clock.unix_timestamp)?;
+            if applies_from != 0 && !list_config.is_policy_pending(required, forbidden, source) {
+                list_config.pending_required_attributes = required.to_le_bytes();
+                list_config.pending_forbidden_attributes = forbidden.to_le_bytes();
+                list_config.pending_attributes_source = *source;
+                list_config.pending_policy_from = applies_from.to_le_bytes();
+                return Ok(());
+            }
+
+            if applies_from != 0 && clock.unix_timestamp < list_config.get_pending_policy_from() {
+                return Err(ABLError::ChangeTimelocked.into());
+            }
+        }
+
+        list_config.required_attributes = required.to_le_bytes();
+        list_config.forbidden_attributes = forbidden.to_le_bytes();
+        list_config.attributes_source = *source;
+
+        list_config.pending_required_attributes = [0; 8];
+        list_config.pending_forbidden_attributes = [0; 8];
+        list_config.pending_attributes_source = Pubkey::default();
+        list_config.pending_policy_from = [0; 8];
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use pinocchio::{account_info::AccountInfo, pubkey::Pubkey, ProgramResult};
+
+use crate::{load_account_mut, ABLError, ListConfig};
+
+/// Names the guardian of a list, a key that can act faster than the authority
+/// but only restrictively: blocking wallets on block lists and removing wallets
+/// from allow lists. An all zeros guardian removes it.
+pub struct SetGuardian<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetGuardian<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetGuardian<'a> {
+    pub const DISCRIMINATOR: u8 = 0x12;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let guardian: &Pubkey = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        list_config.guardian = *guardian;
+
+        Ok(())
+    }
+}
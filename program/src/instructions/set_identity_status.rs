@@ -0,0 +1,78 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, ABLError, Identity, IdentityStatus, ListConfig};
+
+/// Allows or blocks an identity, applying to all of its linked wallets at the next check.
+///
+/// Counts as a change against the per epoch limit. Blocking applies
+/// immediately, allowing a blocked identity only once `allow_delay` elapsed.
+pub struct SetIdentityStatus<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub identity: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetIdentityStatus<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, identity, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) || !identity.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !identity.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            identity,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetIdentityStatus<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0D;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [status] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+        let status = IdentityStatus::try_from(*status)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        let clock = Clock::get()?;
+        list_config.record_changes(1, clock.epoch)?;
+
+        let mut identity = load_account_mut::<Identity>(self.identity)?;
+
+        if identity.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        if matches!(status, IdentityStatus::Allowed)
+            && !matches!(identity.get_status()?, IdentityStatus::Allowed)
+        {
+            identity.active_from = list_config
+                .get_added_wallet_active_from(clock.unix_timestamp)?
+                .to_le_bytes();
+        }
+        identity.status = status as u8;
+
+        Ok(())
+    }
+}
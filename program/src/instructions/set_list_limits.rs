@@ -0,0 +1,102 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, ABLError, ListConfig, Storage};
+
+/// Sets the per epoch change limit and the allow delay of a list, containing
+/// what a leaked authority key can do before it is noticed.
+///
+/// Tightening, a lower non zero change limit and a longer delay, applies
+/// immediately. Any other change is staged, then applied by setting the same
+/// limits again once `LIMITS_RELAX_DELAY` elapsed, so a leaked authority key
+/// can't lift them unnoticed. Applying limits drops the pending ones. The delay
+/// relies on wallet entries and is not available to bucket storage lists.
+pub struct SetListLimits<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetListLimits<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetListLimits<'a> {
+    pub const DISCRIMINATOR: u8 = 0x11;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        if remaining_data.len() != 16 {
+            return Err(ABLError::InvalidData.into());
+        }
+        let (max_changes_per_epoch, allow_delay) = remaining_data.split_at(8);
+        let max_changes_per_epoch = u64::from_le_bytes(max_changes_per_epoch.try_into().unwrap());
+        let allow_delay = u64::from_le_bytes(allow_delay.try_into().unwrap());
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        if i64::try_from(allow_delay).is_err() {
+            return Err(ABLError::LimitsCannotBeLoosened.into());
+        }
+
+        if allow_delay > 0 && matches!(list_config.get_storage(), Storage::Buckets) {
+            return Err(ABLError::InvalidStorage.into());
+        }
+
+        let current_max_changes = list_config.get_max_changes_per_epoch();
+        let tightening = (current_max_changes == 0
+            || (max_changes_per_epoch != 0 && max_changes_per_epoch <= current_max_changes))
+            && allow_delay >= list_config.get_allow_delay();
+
+        if !tightening {
+            let now = Clock::get()?.unix_timestamp;
+            if !list_config.are_limits_pending(max_changes_per_epoch, allow_delay) {
+                let applies_from = now
+                    .checked_add(ListConfig::LIMITS_RELAX_DELAY)
+                    .ok_or(ProgramError::ArithmeticOverflow)?;
+                list_config.pending_max_changes_per_epoch = max_changes_per_epoch.to_le_bytes();
+                list_config.pending_allow_delay = allow_delay.to_le_bytes();
+                list_config.pending_limits_from = applies_from.to_le_bytes();
+                return Ok(());
+            }
+
+            if now < list_config.get_pending_limits_from() {
+                return Err(ABLError::ChangeTimelocked.into());
+            }
+        }
+
+        list_config.max_changes_per_epoch = max_changes_per_epoch.to_le_bytes();
+        list_config.allow_delay = allow_delay.to_le_bytes();
+
+        list_config.pending_max_changes_per_epoch = [0; 8];
+        list_config.pending_allow_delay = [0; 8];
+        list_config.pending_limits_from = [0; 8];
+
+        Ok(())
+    }
+}
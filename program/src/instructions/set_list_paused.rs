@@ -0,0 +1,69 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, utils::parse_flag, ABLError, ListConfig};
+
+/// Suspends or resumes a list without deleting it. While paused the list
+/// either passes every wallet (fail open) or denies them all (fail closed).
+/// Counts as a change against the per epoch limit when made by the authority,
+/// the guardian may only make the list deny every wallet or end a fail open
+/// pause.
+pub struct SetListPaused<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetListPaused<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetListPaused<'a> {
+    pub const DISCRIMINATOR: u8 = 0x13;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [paused, fail_open] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+        let (paused, fail_open) = (parse_flag(*paused)?, parse_flag(*fail_open)?);
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        let restrictive = (paused != 0 && fail_open == 0)
+            || (list_config.is_paused() && list_config.is_fail_open());
+        let by_guardian =
+            list_config.check_change_authority(self.authority, self.mint_config, restrictive)?;
+
+        if !by_guardian {
+            list_config.record_changes(1, Clock::get()?.epoch)?;
+        }
+
+        list_config.paused = paused;
+        list_config.fail_open = fail_open;
+
+        Ok(())
+    }
+}
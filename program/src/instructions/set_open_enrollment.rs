@@ -0,0 +1,124 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    pubkey::find_program_address,
+    seeds,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load_account_mut, load_mut_unchecked,
+    utils::{check_deposit_vault, create_program_account, parse_flag},
+    ABLError, DepositVault, Discriminator, ListConfig, Mode, Storage, Transmutable,
+};
+
+/// Opens or closes self enrollment on an allow list, setting the deposit
+/// enrolling wallets pay. The deposit vault of the list is created the first
+/// time enrollment is opened.
+///
+/// Counts as a change against the per epoch limit. Enrolled wallets take effect
+/// after `allow_delay`, the same as added ones.
+pub struct SetOpenEnrollment<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub deposit_vault: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetOpenEnrollment<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, deposit_vault, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !deposit_vault.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            deposit_vault,
+            system_program,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetOpenEnrollment<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1D;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [open_enrollment, deposit @ ..] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+        let open_enrollment = parse_flag(*open_enrollment)?;
+        let deposit = <[u8; 8]>::try_from(deposit).map_err(|_| ABLError::InvalidData)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        list_config.record_changes(1, Clock::get()?.epoch)?;
+
+        // enrolled wallets are checked as any other wallet of an allow list
+        if !matches!(list_config.get_mode()?, Mode::Allow)
+            || !matches!(list_config.get_storage(), Storage::Entries)
+        {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        if self.deposit_vault.is_owned_by(&crate::ID) {
+            check_deposit_vault(self.deposit_vault, self.list_config.key())?;
+        } else {
+            self.create_deposit_vault()?;
+        }
+        list_config.deposit_vault = 1;
+
+        list_config.open_enrollment = open_enrollment;
+        list_config.enrollment_deposit = deposit;
+
+        Ok(())
+    }
+
+    fn create_deposit_vault(&self) -> ProgramResult {
+        let (_, deposit_vault_bump) = find_program_address(
+            &[DepositVault::SEED_PREFIX, self.list_config.key()],
+            &crate::ID,
+        );
+
+        let bump_seed = [deposit_vault_bump];
+        let seeds = seeds!(
+            DepositVault::SEED_PREFIX,
+            self.list_config.key(),
+            &bump_seed
+        );
+        create_program_account(
+            self.deposit_vault,
+            self.authority,
+            DepositVault::LEN,
+            &seeds,
+        )?;
+
+        let mut data = self.deposit_vault.try_borrow_mut_data()?;
+        let deposit_vault = load_mut_unchecked::<DepositVault>(&mut data)?;
+        deposit_vault.discriminator = DepositVault::DISCRIMINATOR;
+        deposit_vault.list_config = *self.list_config.key();
+
+        Ok(())
+    }
+}
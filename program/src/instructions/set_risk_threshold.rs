@@ -0,0 +1,70 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, ABLError, ListConfig, Mode};
+
+/// Updates the score threshold of a risk score list, tightening or relaxing
+/// the policy for every wallet entry at once. Counts as a change against the
+/// per epoch limit when made by the authority, the guardian may only lower the
+/// threshold.
+pub struct SetRiskThreshold<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetRiskThreshold<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetRiskThreshold<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0B;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [risk_threshold] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        if !matches!(list_config.get_mode()?, Mode::RiskScore) {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        // a lower threshold blocks more wallets
+        let restrictive = *risk_threshold <= list_config.risk_threshold;
+        let by_guardian =
+            list_config.check_change_authority(self.authority, self.mint_config, restrictive)?;
+
+        if !by_guardian {
+            list_config.record_changes(1, Clock::get()?.epoch)?;
+        }
+
+        list_config.risk_threshold = *risk_threshold;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, utils::parse_flag, ABLError, ListConfig};
+
+/// Lets the wallets of an allowing list rotate their entry to a new address on
+/// their own, or requires the authority to co-sign rotations again.
+///
+/// Counts as a change against the per epoch limit. Rotated entries take effect
+/// after `allow_delay`, the same as added ones.
+pub struct SetSelfRotation<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetSelfRotation<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetSelfRotation<'a> {
+    pub const DISCRIMINATOR: u8 = 0x1B;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [self_rotation] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+        let self_rotation = parse_flag(*self_rotation)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        list_config.record_changes(1, Clock::get()?.epoch)?;
+
+        if !list_config.is_allowing() {
+            return Err(ABLError::InvalidData.into());
+        }
+
+        list_config.self_rotation = self_rotation;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use pinocchio::{account_info::AccountInfo, ProgramResult};
+
+use crate::{load_account_mut, utils::parse_flag, ABLError, ListConfig};
+
+/// Requires permissionless thaws gated by the list to be initiated and signed
+/// by the owner of the token account, or lets any caller thaw again.
+pub struct SetSelfThawOnly<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetSelfThawOnly<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetSelfThawOnly<'a> {
+    pub const DISCRIMINATOR: u8 = 0x20;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let [self_thaw_only] = remaining_data else {
+            return Err(ABLError::InvalidData.into());
+        };
+        let self_thaw_only = parse_flag(*self_thaw_only)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        list_config.self_thaw_only = self_thaw_only;
+
+        Ok(())
+    }
+}
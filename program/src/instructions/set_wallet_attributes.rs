@@ -0,0 +1,77 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    sysvars::{clock::Clock, Sysvar},
+    ProgramResult,
+};
+
+use crate::{load_account_mut, ABLError, ListConfig, WalletEntry};
+
+/// Sets the attribute bitmask of a wallet entry. Counts as a change against the
+/// per epoch limit.
+///
+/// As attributes may let the wallet through attribute lists, changing them on
+/// an allowing list with a delay sets the entry aside until the delay elapsed,
+/// the same as a newly added wallet.
+pub struct SetWalletAttributes<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub wallet_entry: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetWalletAttributes<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, wallet_entry, remaining_accounts @ ..] = accounts else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) || !wallet_entry.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !wallet_entry.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            wallet_entry,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> SetWalletAttributes<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0F;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        let attributes: [u8; 8] = remaining_data
+            .try_into()
+            .map_err(|_| ABLError::InvalidData)?;
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        let clock = Clock::get()?;
+        list_config.record_changes(1, clock.epoch)?;
+
+        let mut wallet_entry = load_account_mut::<WalletEntry>(self.wallet_entry)?;
+
+        if wallet_entry.list_config.ne(self.list_config.key()) {
+            return Err(ABLError::InvalidAccountData.into());
+        }
+
+        if wallet_entry.attributes != attributes {
+            wallet_entry.active_from = list_config
+                .get_added_wallet_active_from(clock.unix_timestamp)?
+                .to_le_bytes();
+        }
+        wallet_entry.attributes = attributes;
+
+        Ok(())
+    }
+}
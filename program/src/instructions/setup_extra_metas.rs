@@ -1,12 +1,20 @@
 use pinocchio::{
-    account_info::AccountInfo, instruction::Signer, pubkey::{find_program_address, Pubkey}, seeds, syscalls::sol_memset_, sysvars::{rent::Rent, Sysvar}, ProgramResult
-};
-use spl_tlv_account_resolution::{
-    account::ExtraAccountMeta, seeds::Seed, solana_pubkey::Pubkey as SolanaPubkey,
-    state::ExtraAccountMetaList,
+    account_info::AccountInfo,
+    instruction::Signer,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
 };
 
-use crate::{load, ABLError, ListConfig, WalletEntry};
+use crate::{
+    events,
+    extra_metas::{self, ExtraAccountMeta, Seed},
+    load_account_versioned,
+    utils::{get_freeze_authority, resize_with_rent, resolve_bump},
+    ABLError, Bucket, Identity, ListConfig, Mode, Storage, WalletEntry,
+};
 
 pub struct SetupExtraMetas<'a> {
     pub authority: &'a AccountInfo,
@@ -15,7 +23,6 @@ pub struct SetupExtraMetas<'a> {
     pub extra_metas: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
     pub remaining_accounts: &'a [AccountInfo],
-    pub extra_metas_bump: u8,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for SetupExtraMetas<'a> {
@@ -32,17 +39,6 @@ impl<'a> TryFrom<&'a [AccountInfo]> for SetupExtraMetas<'a> {
             return Err(ABLError::InvalidAuthority);
         }
 
-        // derive extra_metas account
-        let (extra_metas_address, extra_metas_bump) = find_program_address(
-            &[token_acl_interface::THAW_EXTRA_ACCOUNT_METAS_SEED, mint.key()],
-            &crate::ID,
-        );
-        // need to check because we cannot rely on system program create instruction
-        // as the account may already be initialized
-        if extra_metas_address.ne(extra_metas.key()) {
-            return Err(ABLError::InvalidExtraMetasAccount);
-        }
-
         // check if system program is valid
         if system_program.key().ne(&pinocchio_system::ID) {
             return Err(ABLError::InvalidSystemProgram);
@@ -55,7 +51,6 @@ impl<'a> TryFrom<&'a [AccountInfo]> for SetupExtraMetas<'a> {
             extra_metas,
             system_program,
             remaining_accounts,
-            extra_metas_bump,
         })
     }
 }
@@ -64,132 +59,209 @@ impl<'a> SetupExtraMetas<'a> {
     pub const DISCRIMINATOR: u8 = 0x04;
 
     pub fn process(&self) -> ProgramResult {
-        let mint_config_data = self.token_acl_mint_config.try_borrow_data()?;
-        let mint_config = token_acl::state::load_mint_config(&mint_config_data)
-        .map_err(|_| ABLError::InvalidTokenAclMintConfig)?;
-    
-        // only the selected freeze authority should be able to set the extra metas
-        if mint_config.mint.as_array() == self.mint.key()
-        && mint_config.freeze_authority.as_array() != self.authority.key()
-        {
-            return Err(ABLError::InvalidAuthority.into());
-        }
-        
-        if self.remaining_accounts.len() > 5 {
-            return Err(ABLError::InvalidData.into());
-        }
-        
-        let mut lists = [Option::<&Pubkey>::None; 5];
-        let mut i = 0;
-        for account in self.remaining_accounts {
-            if !account.is_owned_by(&crate::ID) {
-                return Err(ABLError::InvalidConfigAccount.into());
-            }
-            let _ = unsafe { load::<ListConfig>(&account.try_borrow_data()?)? };
-            lists[i] = Some(account.key());
-            i += 1;
-        }
-        
-        let lists_slice = &lists[..i];
-        
-        let data_len = get_extra_metas_size(lists_slice);
-        let min_lamports = Rent::get()?.minimum_balance(data_len);
-        
-        if self.extra_metas.is_owned_by(&crate::ID) {
-            let current_lamports = self.extra_metas.lamports();
-            let auth_lamports = self.authority.lamports();
-            
-            // just resize and set everything to 0
-            self.extra_metas.resize(data_len)?;
-            unsafe {
-                sol_memset_(
-                    self.extra_metas.borrow_mut_data_unchecked().as_mut_ptr(),
-                    0,
-                    data_len as u64,
-                );
-            }
-            
-            if current_lamports < min_lamports {
-                // transfer to extra
-                let diff = min_lamports - current_lamports;
-                pinocchio_system::instructions::Transfer {
-                    from: self.authority,
-                    to: self.extra_metas,
-                    lamports: diff,
-                }
-                .invoke()?;
-            } else if current_lamports > min_lamports {
-                // transfer from extra
-                let diff = current_lamports - min_lamports;
-                unsafe {
-                    *self.extra_metas.borrow_mut_lamports_unchecked() = min_lamports;
-                    *self.authority.borrow_mut_lamports_unchecked() =
-                    auth_lamports.checked_add(diff).unwrap();
-                }
-            }
-        } else {
-            // create new account
-            let bump_seed = [self.extra_metas_bump];
-            let seeds = seeds!(
+        // need to check because we cannot rely on system program create instruction
+        // as the account may already be initialized
+        let extra_metas_bump = resolve_bump(
+            &[
                 token_acl_interface::THAW_EXTRA_ACCOUNT_METAS_SEED,
                 self.mint.key(),
-                &bump_seed
-            );
-            let signer = Signer::from(&seeds);
-            
-            pinocchio_system::instructions::CreateAccount {
-                from: self.authority,
-                to: self.extra_metas,
-                lamports: min_lamports,
-                space: data_len as u64,
-                owner: &crate::ID,
-            }
-            .invoke_signed(&[signer])?;
+            ],
+            self.extra_metas.key(),
+        )
+        .map_err(|_| ABLError::InvalidExtraMetasAccount)?;
+
+        // only the selected freeze authority should be able to set the extra metas
+        let freeze_authority = get_freeze_authority(self.token_acl_mint_config, self.mint.key())?;
+        if freeze_authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
         }
 
+        let (lists, i) = load_lists(self.remaining_accounts)?;
+        let (metas, len) = get_extra_metas(&lists[..i]);
+
+        let data_len = extra_metas::size_of(len);
+
+        let bump_seed = [extra_metas_bump];
+        let seeds = seeds!(
+            token_acl_interface::THAW_EXTRA_ACCOUNT_METAS_SEED,
+            self.mint.key(),
+            &bump_seed
+        );
+        prepare_extra_metas_account(
+            self.authority,
+            self.extra_metas,
+            Signer::from(&seeds),
+            data_len,
+        )?;
+
         let mut extra_metas_data = self.extra_metas.try_borrow_mut_data()?;
-        let (metas, len) = get_extra_metas(lists_slice);
 
-        ExtraAccountMetaList::init::<token_acl_interface::instruction::CanThawPermissionlessInstruction>(&mut extra_metas_data, &metas[..len]).unwrap();
+        extra_metas::init::<token_acl_interface::instruction::CanThawPermissionlessInstruction>(
+            &mut extra_metas_data,
+            &metas[..len],
+        )?;
+
+        events::extra_metas_setup(self.mint.key(), false, &lists[..i]);
+
         Ok(())
     }
 }
 
-fn get_extra_metas(lists: &[Option<&Pubkey>]) -> ([ExtraAccountMeta; 10], usize) {
-    let mut metas = [ExtraAccountMeta::default(); 10];
+/// A list to be resolved through extra metas.
+#[derive(Clone, Copy)]
+pub(crate) struct ExtraMetasList<'a> {
+    pub key: &'a Pubkey,
+    pub storage: Storage,
+    pub identities: bool,
+    /// Whether wallet entries are resolved from the attributes source of the
+    /// list rather than the list itself.
+    pub entries_from_source: bool,
+}
+
+/// Loads the lists passed as remaining accounts (up to 5).
+pub(crate) fn load_lists(
+    remaining_accounts: &[AccountInfo],
+) -> Result<([Option<ExtraMetasList>; 5], usize), ProgramError> {
+    if remaining_accounts.len() > 5 {
+        return Err(ABLError::InvalidData.into());
+    }
+
+    let mut lists = [Option::<ExtraMetasList>::None; 5];
+    let mut i = 0;
+    for account in remaining_accounts {
+        if !account.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount.into());
+        }
+        let list_config = load_account_versioned::<ListConfig>(account)?;
+        lists[i] = Some(ExtraMetasList {
+            key: account.key(),
+            storage: list_config.get_storage(),
+            identities: list_config.has_identities(),
+            entries_from_source: matches!(list_config.get_mode()?, Mode::Attributes),
+        });
+        i += 1;
+    }
+
+    Ok((lists, i))
+}
+
+/// Creates the extra metas account, or resizes and zeroes it if it already
+/// exists, funding it to be rent exempt for `data_len` bytes.
+pub(crate) fn prepare_extra_metas_account(
+    authority: &AccountInfo,
+    extra_metas: &AccountInfo,
+    signer: Signer,
+    data_len: usize,
+) -> ProgramResult {
+    if extra_metas.is_owned_by(&crate::ID) {
+        // just resize and set everything to 0
+        resize_with_rent(extra_metas, authority, data_len)?;
+        extra_metas.try_borrow_mut_data()?.fill(0);
+    } else {
+        // create new account
+        pinocchio_system::instructions::CreateAccount {
+            from: authority,
+            to: extra_metas,
+            lamports: Rent::get()?.minimum_balance(data_len),
+            space: data_len as u64,
+            owner: &crate::ID,
+        }
+        .invoke_signed(&[signer])?;
+    }
+
+    Ok(())
+}
+
+fn get_extra_metas(lists: &[Option<ExtraMetasList>]) -> ([ExtraAccountMeta; 15], usize) {
+    let mut metas = [ExtraAccountMeta::default(); 15];
 
     let mut index: usize = 0;
     for list in lists {
-        metas[index] = ExtraAccountMeta::new_with_pubkey(
-            &SolanaPubkey::new_from_array(*list.unwrap()),
+        let list = list.unwrap();
+        let list_index = index as u8 + 5;
+        metas[index] = ExtraAccountMeta::new_with_pubkey(list.key, false, false);
+        metas[index + 1] = get_wallet_meta(&list, list_index, 1);
+        index += 2;
+
+        if list.identities {
+            metas[index] = get_identity_meta(list_index, list_index + 1);
+            index += 1;
+        }
+    }
+
+    (metas, index)
+}
+
+/// Extra meta resolving the wallet entry (or bucket) of the owner of the token
+/// account at `token_account_index` for the list at `list_index`.
+pub(crate) fn get_wallet_meta(
+    list: &ExtraMetasList,
+    list_index: u8,
+    token_account_index: u8,
+) -> ExtraAccountMeta {
+    // entries from another list are resolved from the list it points to, read
+    // from its data as the address doesn't fit the seeds along with the others
+    let list_seed = if list.entries_from_source {
+        Seed::AccountData {
+            account_index: list_index,
+            data_index: ListConfig::ATTRIBUTES_SOURCE_OFFSET as u8,
+            length: 32,
+        }
+    } else {
+        Seed::AccountKey { index: list_index }
+    };
+
+    match list.storage {
+        Storage::Entries => ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal(WalletEntry::SEED_PREFIX),
+                list_seed,
+                Seed::AccountData {
+                    account_index: token_account_index,
+                    data_index: 32, // ta owner
+                    length: 32,
+                },
+            ],
             false,
             false,
-        )
-        .unwrap();
-        metas[index + 1] = ExtraAccountMeta::new_with_seeds(
+        ),
+        // buckets are sharded by the first byte of the owner
+        Storage::Buckets => ExtraAccountMeta::new_with_seeds(
             &[
-                Seed::Literal {
-                    bytes: WalletEntry::SEED_PREFIX.to_vec(),
-                },
-                Seed::AccountKey {
-                    index: index as u8 + 5,
-                },
+                Seed::Literal(Bucket::SEED_PREFIX),
+                Seed::AccountKey { index: list_index },
                 Seed::AccountData {
-                    account_index: 1, // token account
-                    data_index: 32,   // ta owner
-                    length: 32,
+                    account_index: token_account_index,
+                    data_index: 32, // ta owner
+                    length: 1,
                 },
             ],
             false,
             false,
-        )
-        .unwrap();
-        index += 2;
+        ),
     }
-
-    (metas, index)
+    .unwrap()
 }
 
-fn get_extra_metas_size(lists: &[Option<&Pubkey>]) -> usize {
-    ExtraAccountMetaList::size_of(2 * lists.len()).unwrap()
+/// Extra meta resolving the identity the wallet entry at `wallet_entry_index`
+/// is linked to, for the list at `list_index`.
+///
+/// The seed is read from the wallet entry, so wallets without one can't resolve
+/// it: their thaw fails building its accounts instead of returning
+/// `NotOnAllowList`, IsWalletPermitted reporting the reason for them.
+pub(crate) fn get_identity_meta(list_index: u8, wallet_entry_index: u8) -> ExtraAccountMeta {
+    ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal(Identity::SEED_PREFIX),
+            Seed::AccountKey { index: list_index },
+            Seed::AccountData {
+                account_index: wallet_entry_index,
+                data_index: WalletEntry::IDENTITY_OFFSET as u8,
+                length: 32,
+            },
+        ],
+        false,
+        false,
+    )
+    .unwrap()
 }
@@ -0,0 +1,121 @@
+use pinocchio::{account_info::AccountInfo, instruction::Signer, seeds, ProgramResult};
+
+use crate::{
+    events,
+    extra_metas::{self, ExtraAccountMeta},
+    get_identity_meta, get_wallet_meta, load_lists, prepare_extra_metas_account,
+    utils::{get_transfer_hook, resolve_bump},
+    ABLError, ExecuteInstruction, ExtraMetasList, EXECUTE_EXTRA_ACCOUNT_METAS_SEED,
+};
+
+/// Sets up the extra metas resolved by token-2022 when invoking the transfer hook `Execute`.
+///
+/// Each list adds the list itself and the wallet blocks of the source and
+/// destination owners, followed by their identities for lists with identities.
+pub struct SetupTransferHookExtraMetas<'a> {
+    pub authority: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub extra_metas: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub remaining_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetupTransferHookExtraMetas<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, mint, extra_metas, system_program, remaining_accounts @ ..] = accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !authority.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            mint,
+            extra_metas,
+            system_program,
+            remaining_accounts,
+        })
+    }
+}
+
+impl<'a> SetupTransferHookExtraMetas<'a> {
+    pub const DISCRIMINATOR: u8 = 0x0A;
+
+    pub fn process(&self) -> ProgramResult {
+        // need to check because we cannot rely on system program create instruction
+        // as the account may already be initialized
+        let extra_metas_bump = resolve_bump(
+            &[EXECUTE_EXTRA_ACCOUNT_METAS_SEED, self.mint.key()],
+            self.extra_metas.key(),
+        )
+        .map_err(|_| ABLError::InvalidExtraMetasAccount)?;
+
+        // only the mint's transfer hook authority should be able to set the extra metas
+        let (hook_authority, hook_program_id) = get_transfer_hook(self.mint)?;
+        if hook_program_id.ne(&crate::ID) {
+            return Err(ABLError::InvalidMint.into());
+        }
+        if hook_authority.ne(self.authority.key()) {
+            return Err(ABLError::InvalidAuthority.into());
+        }
+
+        let (lists, i) = load_lists(self.remaining_accounts)?;
+        let (metas, len) = get_extra_metas(&lists[..i]);
+
+        let data_len = extra_metas::size_of(len);
+
+        let bump_seed = [extra_metas_bump];
+        let seeds = seeds!(
+            EXECUTE_EXTRA_ACCOUNT_METAS_SEED,
+            self.mint.key(),
+            &bump_seed
+        );
+        prepare_extra_metas_account(
+            self.authority,
+            self.extra_metas,
+            Signer::from(&seeds),
+            data_len,
+        )?;
+
+        let mut extra_metas_data = self.extra_metas.try_borrow_mut_data()?;
+
+        extra_metas::init::<ExecuteInstruction>(&mut extra_metas_data, &metas[..len])?;
+
+        events::extra_metas_setup(self.mint.key(), true, &lists[..i]);
+
+        Ok(())
+    }
+}
+
+fn get_extra_metas(lists: &[Option<ExtraMetasList>]) -> ([ExtraAccountMeta; 25], usize) {
+    let mut metas = [ExtraAccountMeta::default(); 25];
+
+    let mut index: usize = 0;
+    for list in lists {
+        let list = list.unwrap();
+        let list_index = index as u8 + 5;
+        metas[index] = ExtraAccountMeta::new_with_pubkey(list.key, false, false);
+        // source token account is the first account, destination the third
+        metas[index + 1] = get_wallet_meta(&list, list_index, 0);
+        metas[index + 2] = get_wallet_meta(&list, list_index, 2);
+        index += 3;
+
+        if list.identities {
+            metas[index] = get_identity_meta(list_index, list_index + 1);
+            metas[index + 1] = get_identity_meta(list_index, list_index + 2);
+            index += 2;
+        }
+    }
+
+    (metas, index)
+}
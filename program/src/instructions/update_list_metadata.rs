@@ -0,0 +1,108 @@
+use pinocchio::{account_info::AccountInfo, pubkey::find_program_address, seeds, ProgramResult};
+
+use crate::{
+    load_account_mut, load_mut_unchecked, utils::create_program_account, ABLError, Discriminator,
+    ListConfig, ListMetadata, Transmutable,
+};
+
+/// Sets the name, policy URI and policy hash of a list, creating its metadata
+/// account the first time. The metadata is closed along with the list.
+pub struct UpdateListMetadata<'a> {
+    pub authority: &'a AccountInfo,
+    pub list_config: &'a AccountInfo,
+    pub list_metadata: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub mint_config: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateListMetadata<'a> {
+    type Error = ABLError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, list_config, list_metadata, system_program, remaining_accounts @ ..] =
+            accounts
+        else {
+            return Err(ABLError::NotEnoughAccounts);
+        };
+
+        if !list_config.is_owned_by(&crate::ID) {
+            return Err(ABLError::InvalidConfigAccount);
+        }
+
+        if !list_config.is_writable() || !list_metadata.is_writable() {
+            return Err(ABLError::AccountNotWritable);
+        }
+
+        // check if system program is valid
+        if system_program.key().ne(&pinocchio_system::ID) {
+            return Err(ABLError::InvalidSystemProgram);
+        }
+
+        Ok(Self {
+            authority,
+            list_config,
+            list_metadata,
+            system_program,
+            mint_config: remaining_accounts.first(),
+        })
+    }
+}
+
+impl<'a> UpdateListMetadata<'a> {
+    pub const DISCRIMINATOR: u8 = 0x21;
+
+    pub fn process(&self, remaining_data: &[u8]) -> ProgramResult {
+        // name, uri and policy hash
+        if remaining_data.len() != 32 + 128 + 32 {
+            return Err(ABLError::InvalidData.into());
+        }
+        let (name, rest) = remaining_data.split_at(32);
+        let (uri, policy_hash) = rest.split_at(128);
+
+        let mut list_config = load_account_mut::<ListConfig>(self.list_config)?;
+
+        list_config.check_authority(self.authority, self.mint_config)?;
+
+        if list_config.has_metadata() {
+            let mut list_metadata = load_account_mut::<ListMetadata>(self.list_metadata)
+                .map_err(|_| ABLError::InvalidListMetadata)?;
+            if list_metadata.list_config.ne(self.list_config.key()) {
+                return Err(ABLError::InvalidListMetadata.into());
+            }
+            list_metadata.name.copy_from_slice(name);
+            list_metadata.uri.copy_from_slice(uri);
+            list_metadata.policy_hash.copy_from_slice(policy_hash);
+            return Ok(());
+        }
+
+        let (_, list_metadata_bump) = find_program_address(
+            &[ListMetadata::SEED_PREFIX, self.list_config.key()],
+            &crate::ID,
+        );
+
+        let bump_seed = [list_metadata_bump];
+        let seeds = seeds!(
+            ListMetadata::SEED_PREFIX,
+            self.list_config.key(),
+            &bump_seed
+        );
+        create_program_account(
+            self.list_metadata,
+            self.authority,
+            ListMetadata::LEN,
+            &seeds,
+        )?;
+
+        let mut data = self.list_metadata.try_borrow_mut_data()?;
+        let list_metadata = load_mut_unchecked::<ListMetadata>(&mut data)?;
+        list_metadata.discriminator = ListMetadata::DISCRIMINATOR;
+        list_metadata.list_config = *self.list_config.key();
+        list_metadata.name.copy_from_slice(name);
+        list_metadata.uri.copy_from_slice(uri);
+        list_metadata.policy_hash.copy_from_slice(policy_hash);
+
+        list_config.metadata = 1;
+
+        Ok(())
+    }
+}
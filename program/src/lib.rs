@@ -1,22 +1,23 @@
 #![no_std]
 
 use pinocchio::{
-    account_info::AccountInfo, default_allocator, program_entrypoint, program_error::ProgramError, pubkey::Pubkey, ProgramResult
+    account_info::AccountInfo, no_allocator, program_entrypoint, program_error::ProgramError, pubkey::Pubkey, ProgramResult
 };
 use pinocchio_pubkey::declare_id;
 
 program_entrypoint!(process_instruction);
 
-// need allocator due to dependency on spl_tlv_account_resolution
-//no_allocator!();
-default_allocator!();
+no_allocator!();
 
 pub mod instructions;
 pub use instructions::*;
 pub mod error;
 pub use error::*;
+pub mod events;
+pub mod extra_metas;
 pub mod state;
 pub use state::*;
+pub mod utils;
 
 declare_id!("ABL37q2e55mQ87KTRe6yF89TJoeysHKipwVwSRRPbTNY");
 
@@ -26,6 +27,11 @@ fn process_instruction(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
+    // transfer hook interface uses an 8 byte discriminator
+    if instruction_data.starts_with(Execute::DISCRIMINATOR) {
+        return Execute::try_from(accounts)?.process();
+    }
+
     let [disc, remaining_data @ ..] = instruction_data else {
         return Err(ABLError::InvalidInstruction.into());
     };
@@ -36,9 +42,67 @@ fn process_instruction(
         }
         CreateList::DISCRIMINATOR => CreateList::try_from(accounts)?.process(remaining_data),
         DeleteList::DISCRIMINATOR => DeleteList::try_from(accounts)?.process(),
-        AddWallet::DISCRIMINATOR => AddWallet::try_from(accounts)?.process(),
+        DeleteList::FORCE_DISCRIMINATOR => DeleteList::try_from(accounts)?.process_force(),
+        AddWallet::DISCRIMINATOR => AddWallet::try_from(accounts)?.process(remaining_data),
+        AddWallet::IDEMPOTENT_DISCRIMINATOR => {
+            AddWallet::try_from(accounts)?.process_idempotent(remaining_data)
+        }
         RemoveWallet::DISCRIMINATOR => RemoveWallet::try_from(accounts)?.process(),
         SetupExtraMetas::DISCRIMINATOR => SetupExtraMetas::try_from(accounts)?.process(),
+        IsWalletPermitted::DISCRIMINATOR => IsWalletPermitted::try_from(accounts)?.process(),
+        AddBucketWallets::DISCRIMINATOR => {
+            AddBucketWallets::try_from(accounts)?.process(remaining_data)
+        }
+        RemoveBucketWallets::DISCRIMINATOR => {
+            RemoveBucketWallets::try_from(accounts)?.process(remaining_data)
+        }
+        SetupTransferHookExtraMetas::DISCRIMINATOR => {
+            SetupTransferHookExtraMetas::try_from(accounts)?.process()
+        }
+        SetRiskThreshold::DISCRIMINATOR => {
+            SetRiskThreshold::try_from(accounts)?.process(remaining_data)
+        }
+        CreateIdentity::DISCRIMINATOR => {
+            CreateIdentity::try_from(accounts)?.process(remaining_data)
+        }
+        SetIdentityStatus::DISCRIMINATOR => {
+            SetIdentityStatus::try_from(accounts)?.process(remaining_data)
+        }
+        LinkWalletIdentity::DISCRIMINATOR => LinkWalletIdentity::try_from(accounts)?.process(),
+        SetWalletAttributes::DISCRIMINATOR => {
+            SetWalletAttributes::try_from(accounts)?.process(remaining_data)
+        }
+        SetAttributePolicy::DISCRIMINATOR => {
+            SetAttributePolicy::try_from(accounts)?.process(remaining_data)
+        }
+        SetListLimits::DISCRIMINATOR => SetListLimits::try_from(accounts)?.process(remaining_data),
+        SetGuardian::DISCRIMINATOR => SetGuardian::try_from(accounts)?.process(remaining_data),
+        SetListPaused::DISCRIMINATOR => SetListPaused::try_from(accounts)?.process(remaining_data),
+        SetActivationWindow::DISCRIMINATOR => {
+            SetActivationWindow::try_from(accounts)?.process(remaining_data)
+        }
+        CreateMintList::DISCRIMINATOR => {
+            CreateMintList::try_from(accounts)?.process(remaining_data)
+        }
+        CreateAuditLog::DISCRIMINATOR => CreateAuditLog::try_from(accounts)?.process(),
+        MigrateAccount::DISCRIMINATOR => MigrateAccount::try_from(accounts)?.process(),
+        ReclaimWalletEntry::DISCRIMINATOR => ReclaimWalletEntry::try_from(accounts)?.process(),
+        SetSelfRotation::DISCRIMINATOR => {
+            SetSelfRotation::try_from(accounts)?.process(remaining_data)
+        }
+        RotateWallet::DISCRIMINATOR => RotateWallet::try_from(accounts)?.process(),
+        SetOpenEnrollment::DISCRIMINATOR => {
+            SetOpenEnrollment::try_from(accounts)?.process(remaining_data)
+        }
+        Enroll::DISCRIMINATOR => Enroll::try_from(accounts)?.process(),
+        EvictWallet::DISCRIMINATOR => EvictWallet::try_from(accounts)?.process(remaining_data),
+        SetSelfThawOnly::DISCRIMINATOR => {
+            SetSelfThawOnly::try_from(accounts)?.process(remaining_data)
+        }
+        UpdateListMetadata::DISCRIMINATOR => {
+            UpdateListMetadata::try_from(accounts)?.process(remaining_data)
+        }
+        PruneListRegistry::DISCRIMINATOR => PruneListRegistry::try_from(accounts)?.process(),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
@@ -0,0 +1,82 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::Pubkey;
+
+use super::{Discriminator, Transmutable};
+
+/// Ring buffer of the most recent wallet changes of a list, kept on chain so
+/// its history can be checked with a single account fetch.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct AuditLog {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    /// Records appended since the log was created, the next record goes at
+    /// `total_records % CAPACITY`.
+    pub total_records: [u8; 8],
+    pub records: [AuditRecord; AuditLog::CAPACITY],
+}
+
+impl AuditLog {
+    pub const SEED_PREFIX: &'static [u8] = b"audit_log";
+
+    /// Number of records kept before the oldest ones are overwritten.
+    pub const CAPACITY: usize = 64;
+
+    pub fn get_total_records(&self) -> u64 {
+        u64::from_le_bytes(self.total_records)
+    }
+
+    /// Appends a record, overwriting the oldest one when the log is full.
+    pub fn append(
+        &mut self,
+        operation: AuditOperation,
+        wallet: &Pubkey,
+        signer: &Pubkey,
+        slot: u64,
+    ) {
+        let total_records = self.get_total_records();
+
+        let record = &mut self.records[(total_records % Self::CAPACITY as u64) as usize];
+        record.operation = operation as u8;
+        record.wallet = *wallet;
+        record.signer = *signer;
+        record.slot = slot.to_le_bytes();
+
+        self.total_records = total_records.wrapping_add(1).to_le_bytes();
+    }
+}
+
+impl Transmutable for AuditLog {
+    const LEN: usize = 1 + 32 + 8 + AuditRecord::LEN * AuditLog::CAPACITY;
+}
+
+impl Discriminator for AuditLog {
+    const DISCRIMINATOR: u8 = 0x05;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
+
+/// A wallet change recorded in an audit log.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct AuditRecord {
+    pub operation: u8,
+    pub wallet: Pubkey,
+    /// Key that signed the change, the authority, the guardian or the old
+    /// wallet of a rotation.
+    pub signer: Pubkey,
+    pub slot: [u8; 8],
+}
+
+impl Transmutable for AuditRecord {
+    const LEN: usize = 1 + 32 + 32 + 8;
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum AuditOperation {
+    WalletAdded = 1,
+    WalletRemoved,
+}
@@ -0,0 +1,72 @@
+use bytemuck::{Pod, Zeroable};
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::ABLError;
+
+use super::{Discriminator, Transmutable};
+
+/// Header of a bucket account, followed by the sorted wallets of the bucket.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Bucket {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    pub prefix: u8,
+}
+
+impl Bucket {
+    pub const SEED_PREFIX: &'static [u8] = b"bucket";
+
+    /// Account size for a bucket holding `count` wallets.
+    pub fn size(count: usize) -> usize {
+        Self::LEN + count * core::mem::size_of::<Pubkey>()
+    }
+}
+
+impl Transmutable for Bucket {
+    const LEN: usize = 1 + 32 + 1;
+}
+
+impl Discriminator for Bucket {
+    const DISCRIMINATOR: u8 = 0x03;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
+
+/// Return the header and sorted wallets of an initialized bucket.
+#[inline(always)]
+pub fn load_bucket(bytes: &[u8]) -> Result<(&Bucket, &[Pubkey]), ABLError> {
+    if bytes.len() < Bucket::LEN || (bytes.len() - Bucket::LEN) % 32 != 0 {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    let (header, wallets) = bytes.split_at(Bucket::LEN);
+    let bucket: &Bucket =
+        bytemuck::try_from_bytes(header).map_err(|_| ABLError::InvalidAccountData)?;
+    if !bucket.is_initialized() {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    Ok((bucket, bytemuck::cast_slice(wallets)))
+}
+
+/// Return the mutable header and wallets of a bucket.
+///
+/// This function does not check if the data is initialized.
+#[inline(always)]
+pub fn load_bucket_mut_unchecked(
+    bytes: &mut [u8],
+) -> Result<(&mut Bucket, &mut [Pubkey]), ABLError> {
+    if bytes.len() < Bucket::LEN || (bytes.len() - Bucket::LEN) % 32 != 0 {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    let (header, wallets) = bytes.split_at_mut(Bucket::LEN);
+    Ok((
+        bytemuck::try_from_bytes_mut(header).map_err(|_| ABLError::InvalidAccountData)?,
+        bytemuck::cast_slice_mut(wallets),
+    ))
+}
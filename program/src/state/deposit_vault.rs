@@ -0,0 +1,30 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::Pubkey;
+
+use super::{Discriminator, Transmutable};
+
+/// Holds the deposits paid by the wallets enrolled in an open enrollment list,
+/// every lamport above its rent being owed to them or kept by the authority
+/// on eviction.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct DepositVault {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+}
+
+impl DepositVault {
+    pub const SEED_PREFIX: &'static [u8] = b"deposit_vault";
+}
+
+impl Transmutable for DepositVault {
+    const LEN: usize = 1 + 32;
+}
+
+impl Discriminator for DepositVault {
+    const DISCRIMINATOR: u8 = 0x07;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
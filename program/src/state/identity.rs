@@ -0,0 +1,68 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::Pubkey;
+
+use super::{Discriminator, Transmutable};
+use crate::ABLError;
+
+/// A person or entity controlling the wallet entries linked to it.
+///
+/// Flipping the status of an identity applies to all of its linked wallets
+/// at the next check, without touching their entries.
+///
+/// Identities are limited to allow lists. Extra metas resolve the identity
+/// from the id stored in the wallet entry, so a wallet without an entry can't
+/// resolve one: on a block list, where most wallets have no entry, every
+/// unlisted wallet would fail to thaw.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct Identity {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    pub id: Pubkey,
+    pub status: u8,
+    /// Unix timestamp an allowed status takes effect at, 0 when effective immediately.
+    pub active_from: [u8; 8],
+}
+
+impl Identity {
+    pub const SEED_PREFIX: &'static [u8] = b"identity";
+
+    pub fn get_status(&self) -> Result<IdentityStatus, ABLError> {
+        IdentityStatus::try_from(self.status)
+    }
+
+    pub fn get_active_from(&self) -> i64 {
+        i64::from_le_bytes(self.active_from)
+    }
+}
+
+impl Transmutable for Identity {
+    const LEN: usize = 1 + 32 + 32 + 1 + 8;
+}
+
+impl Discriminator for Identity {
+    const DISCRIMINATOR: u8 = 0x04;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
+
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum IdentityStatus {
+    Allowed,
+    Blocked,
+}
+
+impl TryFrom<u8> for IdentityStatus {
+    type Error = ABLError;
+
+    fn try_from(status: u8) -> Result<Self, Self::Error> {
+        match status {
+            0 => Ok(IdentityStatus::Allowed),
+            1 => Ok(IdentityStatus::Blocked),
+            _ => Err(ABLError::InvalidData),
+        }
+    }
+}
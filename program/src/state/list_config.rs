@@ -1,31 +1,364 @@
-use pinocchio::{program_error::ProgramError, pubkey::Pubkey, ProgramResult};
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey, ProgramResult,
+};
 
-use super::{Discriminator, Transmutable};
+use super::{Discriminator, Transmutable, Versioned};
+use crate::{utils::get_freeze_authority, ABLError};
 
 #[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
 pub struct ListConfig {
     pub discriminator: u8,
+    /// Authority of the list, unused by mint bound lists.
     pub authority: Pubkey,
     pub seed: Pubkey,
     pub mode: u8,
     pub wallets_count: [u8; 8],
+    /// Layout version, following the fields of the original layout.
+    pub version: u8,
+    pub storage: u8,
+    /// Wallets scoring at or above this are blocked (risk score lists only).
+    pub risk_threshold: u8,
+    /// Whether wallet entries may be linked to identities (allow lists only).
+    pub identities: u8,
+    /// Attributes a wallet entry must all have (attribute lists only).
+    pub required_attributes: [u8; 8],
+    /// Attributes a wallet entry must have none of (attribute lists only).
+    pub forbidden_attributes: [u8; 8],
+    /// List holding the wallet entries checked against the attributes (attribute lists only).
+    pub attributes_source: Pubkey,
+    /// Maximum wallets added or removed, and policy changes, per epoch, 0 when unlimited.
+    pub max_changes_per_epoch: [u8; 8],
+    /// Epoch the changes counter belongs to.
+    pub changes_epoch: [u8; 8],
+    /// Wallets added or removed, and policy changes, during `changes_epoch`.
+    pub changes_in_epoch: [u8; 8],
+    /// Seconds before wallets added to allowing lists take effect.
+    pub allow_delay: [u8; 8],
+    /// Key allowed to make restrictive changes only, all zeros when unset.
+    pub guardian: Pubkey,
+    /// Whether the list is suspended.
+    pub paused: u8,
+    /// Whether a paused list lets every wallet through instead of denying them all.
+    pub fail_open: u8,
+    /// Unix timestamp the list becomes effective at, 0 when effective immediately.
+    pub active_from: [u8; 8],
+    /// Unix timestamp the list lapses at, 0 when it never lapses.
+    pub active_until: [u8; 8],
+    /// Whether an inactive list denies every wallet instead of being skipped.
+    pub deny_when_inactive: u8,
+    /// Mint whose token-acl freeze authority controls the list, all zeros for authority lists.
+    pub mint: Pubkey,
+    /// Whether wallet changes must be recorded in the audit log of the list.
+    pub audit_log: u8,
+    /// Whether wallets may rotate their entry to a new address without the
+    /// authority co-signing (allowing lists only).
+    pub self_rotation: u8,
+    /// Whether any wallet may enroll itself, paying its entry rent and
+    /// `enrollment_deposit` (allow lists only).
+    pub open_enrollment: u8,
+    /// Lamports an enrolling wallet deposits in the deposit vault of the list.
+    pub enrollment_deposit: [u8; 8],
+    /// Whether permissionless thaws must be initiated and signed by the owner
+    /// of the token account, rather than any caller.
+    pub self_thaw_only: u8,
+    /// Whether the list has a metadata account, closed along with the list.
+    pub metadata: u8,
+    /// Whether the list has a deposit vault, closed along with the list once
+    /// the deposits it holds are refunded.
+    pub deposit_vault: u8,
+    /// Required attributes of a policy change held to `allow_delay`.
+    pub pending_required_attributes: [u8; 8],
+    /// Forbidden attributes of a policy change held to `allow_delay`.
+    pub pending_forbidden_attributes: [u8; 8],
+    /// Attributes source of a policy change held to `allow_delay`.
+    pub pending_attributes_source: Pubkey,
+    /// Unix timestamp the pending policy change may be applied from, 0 when
+    /// none is pending.
+    pub pending_policy_from: [u8; 8],
+    /// Change limit of a loosening of the limits held to `LIMITS_RELAX_DELAY`.
+    pub pending_max_changes_per_epoch: [u8; 8],
+    /// Allow delay of a loosening of the limits held to `LIMITS_RELAX_DELAY`.
+    pub pending_allow_delay: [u8; 8],
+    /// Unix timestamp the pending limits may be applied from, 0 when none are
+    /// pending.
+    pub pending_limits_from: [u8; 8],
+    /// Identities created for the list, kept by the tombstone of a deleted list
+    /// until they are reclaimed.
+    pub identities_count: [u8; 8],
 }
 
 impl ListConfig {
     pub const SEED_PREFIX: &'static [u8] = b"list_config";
+    pub const MINT_SEED_PREFIX: &'static [u8] = b"mint_list_config";
 
-    pub fn get_mode(&self) -> Mode {
-        match self.mode {
-            0 => Mode::Allow,
-            1 => Mode::AllowAllEoas,
-            _ => Mode::Block,
-        }
+    /// Discriminator of a force deleted list kept until its wallets are
+    /// reclaimed, `authority` then being the one refunded.
+    pub const TOMBSTONE_DISCRIMINATOR: u8 = 0x06;
+
+    /// Seconds a loosening of the limits waits before it may be applied,
+    /// leaving time to notice a leaked authority key and cancel it.
+    pub const LIMITS_RELAX_DELAY: i64 = 7 * 24 * 60 * 60;
+
+    /// Offset of the attributes source, used to resolve its wallet entries through extra metas.
+    pub const ATTRIBUTES_SOURCE_OFFSET: usize = 1 + 32 + 32 + 1 + 8 + 1 + 1 + 1 + 1 + 8 + 8;
+
+    pub fn get_mode(&self) -> Result<Mode, ABLError> {
+        Mode::try_from(self.mode)
     }
 
     pub fn set_mode(&mut self, mode: Mode) {
         self.mode = mode as u8;
     }
 
+    pub fn get_storage(&self) -> Storage {
+        match self.storage {
+            1 => Storage::Buckets,
+            _ => Storage::Entries,
+        }
+    }
+
+    pub fn has_identities(&self) -> bool {
+        self.identities != 0
+    }
+
+    pub fn get_required_attributes(&self) -> u64 {
+        u64::from_le_bytes(self.required_attributes)
+    }
+
+    pub fn get_forbidden_attributes(&self) -> u64 {
+        u64::from_le_bytes(self.forbidden_attributes)
+    }
+
+    /// Whether `attributes` has all the required and none of the forbidden attributes.
+    pub fn matches_attributes(&self, attributes: u64) -> bool {
+        let required = self.get_required_attributes();
+        attributes & required == required && attributes & self.get_forbidden_attributes() == 0
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused != 0
+    }
+
+    pub fn is_fail_open(&self) -> bool {
+        self.fail_open != 0
+    }
+
+    pub fn get_active_from(&self) -> i64 {
+        i64::from_le_bytes(self.active_from)
+    }
+
+    pub fn get_active_until(&self) -> i64 {
+        i64::from_le_bytes(self.active_until)
+    }
+
+    pub fn has_activation_window(&self) -> bool {
+        self.get_active_from() != 0 || self.get_active_until() != 0
+    }
+
+    /// Whether `timestamp` falls within the activation window of the list.
+    pub fn is_active_at(&self, timestamp: i64) -> bool {
+        let active_until = self.get_active_until();
+        timestamp >= self.get_active_from() && (active_until == 0 || timestamp < active_until)
+    }
+
+    pub fn denies_when_inactive(&self) -> bool {
+        self.deny_when_inactive != 0
+    }
+
+    pub fn get_max_changes_per_epoch(&self) -> u64 {
+        u64::from_le_bytes(self.max_changes_per_epoch)
+    }
+
+    pub fn get_allow_delay(&self) -> u64 {
+        u64::from_le_bytes(self.allow_delay)
+    }
+
+    /// Whether adding a wallet lets it through, making the addition subject to `allow_delay`.
+    pub fn is_allowing(&self) -> bool {
+        matches!(
+            self.get_mode(),
+            Ok(Mode::Allow | Mode::AllowAllEoas | Mode::Attributes)
+        )
+    }
+
+    /// Unix timestamp a wallet added at `now` takes effect at, after the list
+    /// delay on allowing lists and immediately, 0, otherwise.
+    pub fn get_added_wallet_active_from(&self, now: i64) -> Result<i64, ProgramError> {
+        let allow_delay = self.get_allow_delay();
+        if !self.is_allowing() || allow_delay == 0 {
+            return Ok(0);
+        }
+
+        i64::try_from(allow_delay)
+            .ok()
+            .and_then(|delay| now.checked_add(delay))
+            .ok_or(ProgramError::ArithmeticOverflow)
+    }
+
+    pub fn get_pending_limits_from(&self) -> i64 {
+        i64::from_le_bytes(self.pending_limits_from)
+    }
+
+    /// Whether the given limits are the ones pending.
+    pub fn are_limits_pending(&self, max_changes_per_epoch: u64, allow_delay: u64) -> bool {
+        self.get_pending_limits_from() != 0
+            && u64::from_le_bytes(self.pending_max_changes_per_epoch) == max_changes_per_epoch
+            && u64::from_le_bytes(self.pending_allow_delay) == allow_delay
+    }
+
+    pub fn get_pending_policy_from(&self) -> i64 {
+        i64::from_le_bytes(self.pending_policy_from)
+    }
+
+    /// Whether the given policy is the one pending.
+    pub fn is_policy_pending(&self, required: u64, forbidden: u64, source: &Pubkey) -> bool {
+        self.get_pending_policy_from() != 0
+            && u64::from_le_bytes(self.pending_required_attributes) == required
+            && u64::from_le_bytes(self.pending_forbidden_attributes) == forbidden
+            && self.pending_attributes_source.eq(source)
+    }
+
+    /// Counts `amount` wallet or policy changes against the per epoch limit,
+    /// resetting the counter when `epoch` moved on.
+    pub fn record_changes(&mut self, amount: u64, epoch: u64) -> ProgramResult {
+        let max_changes = self.get_max_changes_per_epoch();
+        if max_changes == 0 {
+            return Ok(());
+        }
+
+        let changes = if u64::from_le_bytes(self.changes_epoch) == epoch {
+            u64::from_le_bytes(self.changes_in_epoch)
+        } else {
+            0
+        }
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if changes > max_changes {
+            return Err(ABLError::RateLimitExceeded.into());
+        }
+
+        self.changes_epoch = epoch.to_le_bytes();
+        self.changes_in_epoch = changes.to_le_bytes();
+        Ok(())
+    }
+
+    pub fn has_audit_log(&self) -> bool {
+        self.audit_log != 0
+    }
+
+    pub fn has_metadata(&self) -> bool {
+        self.metadata != 0
+    }
+
+    pub fn has_deposit_vault(&self) -> bool {
+        self.deposit_vault != 0
+    }
+
+    pub fn is_tombstone(&self) -> bool {
+        self.discriminator == Self::TOMBSTONE_DISCRIMINATOR
+    }
+
+    /// Whether rotating a wallet needs the authority to co-sign, which lists
+    /// not letting their wallets through always do, as a rotation would move a
+    /// block off the wallet.
+    pub fn rotation_needs_authority(&self) -> bool {
+        self.self_rotation == 0 || !self.is_allowing()
+    }
+
+    pub fn is_open_enrollment(&self) -> bool {
+        self.open_enrollment != 0
+    }
+
+    pub fn get_enrollment_deposit(&self) -> u64 {
+        u64::from_le_bytes(self.enrollment_deposit)
+    }
+
+    pub fn is_self_thaw_only(&self) -> bool {
+        self.self_thaw_only != 0
+    }
+
+    pub fn is_mint_bound(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+
+    /// Key controlling the list. Mint bound lists are controlled by the current
+    /// freeze authority of their token-acl `mint_config`.
+    pub fn get_authority(&self, mint_config: Option<&AccountInfo>) -> Result<Pubkey, ABLError> {
+        if self.is_mint_bound() {
+            let mint_config = mint_config.ok_or(ABLError::InvalidTokenAclMintConfig)?;
+            get_freeze_authority(mint_config, &self.mint)
+        } else {
+            Ok(self.authority)
+        }
+    }
+
+    /// Checks `signer` controls the list.
+    pub fn check_authority(
+        &self,
+        signer: &AccountInfo,
+        mint_config: Option<&AccountInfo>,
+    ) -> Result<(), ABLError> {
+        if !signer.is_signer() {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        if self.get_authority(mint_config)?.ne(signer.key()) {
+            return Err(ABLError::InvalidAuthority);
+        }
+
+        Ok(())
+    }
+
+    /// Account refunded the rent freed by a wallet change: the signer, unless the
+    /// guardian made the change, the list authority `list_authority` then being
+    /// refunded as the one that paid for the wallets.
+    pub fn get_refund_destination<'a>(
+        &self,
+        signer: &'a AccountInfo,
+        by_guardian: bool,
+        mint_config: Option<&AccountInfo>,
+        list_authority: Option<&'a AccountInfo>,
+    ) -> Result<&'a AccountInfo, ABLError> {
+        if !by_guardian {
+            return Ok(signer);
+        }
+
+        match list_authority {
+            Some(list_authority) if self.get_authority(mint_config)?.eq(list_authority.key()) => {
+                Ok(list_authority)
+            }
+            _ => Err(ABLError::InvalidAuthority),
+        }
+    }
+
+    /// Checks `signer` may change the wallets of the list. The guardian is only
+    /// accepted for `restrictive` changes, returns whether it made the change.
+    ///
+    /// Changes made by the guardian are not counted against the per epoch
+    /// limit, so a leaked authority key can't use it up to keep the guardian
+    /// from blocking wallets.
+    pub fn check_change_authority(
+        &self,
+        signer: &AccountInfo,
+        mint_config: Option<&AccountInfo>,
+        restrictive: bool,
+    ) -> Result<bool, ABLError> {
+        if restrictive
+            && signer.is_signer()
+            && self.guardian != Pubkey::default()
+            && self.guardian.eq(signer.key())
+        {
+            return Ok(true);
+        }
+
+        self.check_authority(signer, mint_config)?;
+
+        Ok(false)
+    }
+
     pub fn get_wallets_count(&self) -> u64 {
         u64::from_le_bytes(self.wallets_count)
     }
@@ -47,23 +380,105 @@ impl ListConfig {
             .to_le_bytes();
         Ok(())
     }
+
+    pub fn get_identities_count(&self) -> u64 {
+        u64::from_le_bytes(self.identities_count)
+    }
+
+    pub fn increment_identities_count(&mut self) -> ProgramResult {
+        self.identities_count = self
+            .get_identities_count()
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .to_le_bytes();
+        Ok(())
+    }
+
+    pub fn decrement_identities_count(&mut self) -> ProgramResult {
+        self.identities_count = self
+            .get_identities_count()
+            .checked_sub(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .to_le_bytes();
+        Ok(())
+    }
+
+    pub fn add_wallets_count(&mut self, amount: u64) -> ProgramResult {
+        self.wallets_count = self
+            .get_wallets_count()
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .to_le_bytes();
+        Ok(())
+    }
+
+    pub fn sub_wallets_count(&mut self, amount: u64) -> ProgramResult {
+        self.wallets_count = self
+            .get_wallets_count()
+            .checked_sub(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?
+            .to_le_bytes();
+        Ok(())
+    }
 }
 
 impl Transmutable for ListConfig {
-    const LEN: usize = 1 + 32 + 32 + 8 + 1;
+    const LEN: usize = (1 + 32 + 32 + 1 + 8 + 1 + 1 + 1 + 1 + 8 + 8 + 32)
+        + (8 + 8 + 8 + 8 + 32 + 1 + 1 + 8 + 8 + 1 + 32 + 1 + 1 + 1 + 8 + 1 + 1 + 1)
+        + (8 + 8 + 32 + 8 + 8 + 8 + 8 + 8);
+}
+
+impl Versioned for ListConfig {
+    /// The original layout ends with `wallets_count`.
+    const V1_LEN: usize = 1 + 32 + 32 + 1 + 8;
+
+    const VERSION: u8 = 2;
+
+    fn upgrade(&mut self, _from_version: u8) {
+        // every field the original layout lacked is all zeros when unset
+        self.version = Self::VERSION;
+    }
 }
 
 impl Discriminator for ListConfig {
     const DISCRIMINATOR: u8 = 0x01;
 
     fn is_initialized(&self) -> bool {
-        self.discriminator == Self::DISCRIMINATOR
+        self.discriminator == Self::DISCRIMINATOR && self.version == Self::VERSION
     }
 }
 
 #[repr(u8)]
+#[derive(Clone, Copy)]
 pub enum Mode {
     Allow,
     AllowAllEoas,
     Block,
+    RiskScore,
+    Attributes,
+}
+
+impl TryFrom<u8> for Mode {
+    type Error = ABLError;
+
+    fn try_from(mode: u8) -> Result<Self, Self::Error> {
+        match mode {
+            0 => Ok(Mode::Allow),
+            1 => Ok(Mode::AllowAllEoas),
+            2 => Ok(Mode::Block),
+            3 => Ok(Mode::RiskScore),
+            4 => Ok(Mode::Attributes),
+            _ => Err(ABLError::InvalidData),
+        }
+    }
+}
+
+/// How the wallets of a list are stored.
+/// entries: one `WalletEntry` account per wallet
+/// buckets: sorted wallets packed into `Bucket` accounts, sharded by the first byte of the wallet
+#[repr(u8)]
+#[derive(Clone, Copy)]
+pub enum Storage {
+    Entries,
+    Buckets,
 }
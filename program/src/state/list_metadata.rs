@@ -0,0 +1,35 @@
+use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::Pubkey;
+
+use super::{Discriminator, Transmutable};
+
+/// Human readable description of a list, so wallets can name the list that
+/// denied a thaw and point to the policy it enforces.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct ListMetadata {
+    pub discriminator: u8,
+    pub list_config: Pubkey,
+    /// Name of the list, utf-8 padded with zeroes.
+    pub name: [u8; 32],
+    /// URI of the policy document of the list, utf-8 padded with zeroes.
+    pub uri: [u8; 128],
+    /// Hash of the policy document, letting readers check it was not changed.
+    pub policy_hash: [u8; 32],
+}
+
+impl ListMetadata {
+    pub const SEED_PREFIX: &'static [u8] = b"list_metadata";
+}
+
+impl Transmutable for ListMetadata {
+    const LEN: usize = 1 + 32 + 32 + 128 + 32;
+}
+
+impl Discriminator for ListMetadata {
+    const DISCRIMINATOR: u8 = 0x09;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
@@ -0,0 +1,90 @@
+use bytemuck::{Pod, Zeroable};
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::ABLError;
+
+use super::{Discriminator, Transmutable};
+
+/// Header of the registry of the lists created by an authority, followed by
+/// one record per list so they can be found without scanning the program.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct ListRegistry {
+    pub discriminator: u8,
+    pub authority: Pubkey,
+}
+
+impl ListRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"list_registry";
+
+    /// Account size for a registry holding `count` lists.
+    pub fn size(count: usize) -> usize {
+        Self::LEN + count * RegisteredList::LEN
+    }
+}
+
+impl Transmutable for ListRegistry {
+    const LEN: usize = 1 + 32;
+}
+
+impl Discriminator for ListRegistry {
+    const DISCRIMINATOR: u8 = 0x08;
+
+    fn is_initialized(&self) -> bool {
+        self.discriminator == Self::DISCRIMINATOR
+    }
+}
+
+/// A list recorded in a registry.
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+pub struct RegisteredList {
+    pub list_config: Pubkey,
+    pub mode: u8,
+    /// Label set on creation, utf-8 padded with zeroes.
+    pub label: [u8; 32],
+}
+
+impl Transmutable for RegisteredList {
+    const LEN: usize = 32 + 1 + 32;
+}
+
+/// Return the header and lists of an initialized registry.
+#[inline(always)]
+pub fn load_list_registry(bytes: &[u8]) -> Result<(&ListRegistry, &[RegisteredList]), ABLError> {
+    if bytes.len() < ListRegistry::LEN
+        || (bytes.len() - ListRegistry::LEN) % RegisteredList::LEN != 0
+    {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    let (header, lists) = bytes.split_at(ListRegistry::LEN);
+    let registry: &ListRegistry =
+        bytemuck::try_from_bytes(header).map_err(|_| ABLError::InvalidAccountData)?;
+    if !registry.is_initialized() {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    Ok((registry, bytemuck::cast_slice(lists)))
+}
+
+/// Return the mutable header and lists of a registry.
+///
+/// This function does not check if the data is initialized.
+#[inline(always)]
+pub fn load_list_registry_mut_unchecked(
+    bytes: &mut [u8],
+) -> Result<(&mut ListRegistry, &mut [RegisteredList]), ABLError> {
+    if bytes.len() < ListRegistry::LEN
+        || (bytes.len() - ListRegistry::LEN) % RegisteredList::LEN != 0
+    {
+        return Err(ABLError::InvalidAccountData);
+    }
+
+    let (header, lists) = bytes.split_at_mut(ListRegistry::LEN);
+    Ok((
+        bytemuck::try_from_bytes_mut(header).map_err(|_| ABLError::InvalidAccountData)?,
+        bytemuck::cast_slice_mut(lists),
+    ))
+}
@@ -1,14 +1,62 @@
+pub mod audit_log;
+pub mod bucket;
+pub mod deposit_vault;
+pub mod identity;
 pub mod list_config;
+pub mod list_metadata;
+pub mod list_registry;
 pub mod wallet_entry;
+pub use audit_log::*;
+pub use bucket::*;
+pub use deposit_vault::*;
+pub use identity::*;
 pub use list_config::*;
+pub use list_metadata::*;
+pub use list_registry::*;
 pub use wallet_entry::*;
 
+use core::ops::Deref;
+
+use bytemuck::{Pod, Zeroable};
+use pinocchio::{
+    account_info::{AccountInfo, Ref, RefMut},
+    program_error::ProgramError,
+};
+
 use crate::ABLError;
 
 pub trait Transmutable {
     const LEN: usize;
 }
 
+/// Account whose original layout predates versioning. Later layouts keep the
+/// original fields first, followed by a version byte telling how the rest of
+/// the account reads, while an original account is told apart by its length.
+/// It reads as the current layout zero extended and upgraded, and can be
+/// migrated to it by reallocating it.
+pub trait Versioned: Transmutable {
+    /// Length of the original layout, version 1, which the version byte follows.
+    const V1_LEN: usize;
+
+    /// Version of the current layout.
+    const VERSION: u8;
+
+    /// Fills in the fields an account of `from_version` lacked, once zero
+    /// extended to the current layout, along with its version byte.
+    fn upgrade(&mut self, from_version: u8);
+
+    /// Layout version of the account data, `None` when unknown.
+    fn version_of(data: &[u8]) -> Option<u8> {
+        if data.len() == Self::V1_LEN {
+            Some(1)
+        } else if data.len() == Self::LEN && data[Self::V1_LEN] == Self::VERSION {
+            Some(Self::VERSION)
+        } else {
+            None
+        }
+    }
+}
+
 pub trait Discriminator {
     const DISCRIMINATOR: u8;
 
@@ -16,12 +64,8 @@ pub trait Discriminator {
 }
 
 /// Return a reference for an initialized `T` from the given bytes.
-///
-/// # Safety
-///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
 #[inline(always)]
-pub unsafe fn load<T: Discriminator + Transmutable>(bytes: &[u8]) -> Result<&T, ABLError> {
+pub fn load<T: Discriminator + Transmutable + Pod>(bytes: &[u8]) -> Result<&T, ABLError> {
     load_unchecked(bytes).and_then(|t: &T| {
         // checks if the data is initialized
         if t.is_initialized() {
@@ -32,13 +76,9 @@ pub unsafe fn load<T: Discriminator + Transmutable>(bytes: &[u8]) -> Result<&T,
     })
 }
 
-/// Return a reference for an initialized `T` from the given bytes.
-///
-/// # Safety
-///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
+/// Return a mutable reference for an initialized `T` from the given bytes.
 #[inline(always)]
-pub unsafe fn load_mut<T: Discriminator + Transmutable>(
+pub fn load_mut<T: Discriminator + Transmutable + Pod>(
     bytes: &mut [u8],
 ) -> Result<&mut T, ABLError> {
     load_mut_unchecked(bytes).and_then(|t: &mut T| {
@@ -54,29 +94,93 @@ pub unsafe fn load_mut<T: Discriminator + Transmutable>(
 /// Return a `T` reference from the given bytes.
 ///
 /// This function does not check if the data is initialized.
-///
-/// # Safety
-///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
 #[inline(always)]
-pub unsafe fn load_unchecked<T: Transmutable>(bytes: &[u8]) -> Result<&T, ABLError> {
+pub fn load_unchecked<T: Transmutable + Pod>(bytes: &[u8]) -> Result<&T, ABLError> {
     if bytes.len() != T::LEN {
         return Err(ABLError::InvalidAccountData);
     }
-    Ok(&*(bytes.as_ptr() as *const T))
+    bytemuck::try_from_bytes(bytes).map_err(|_| ABLError::InvalidAccountData)
 }
 
 /// Return a mutable `T` reference from the given bytes.
 ///
 /// This function does not check if the data is initialized.
-///
-/// # Safety
-///
-/// The caller must ensure that `bytes` contains a valid representation of `T`.
 #[inline(always)]
-pub unsafe fn load_mut_unchecked<T: Transmutable>(bytes: &mut [u8]) -> Result<&mut T, ABLError> {
+pub fn load_mut_unchecked<T: Transmutable + Pod>(bytes: &mut [u8]) -> Result<&mut T, ABLError> {
     if bytes.len() != T::LEN {
         return Err(ABLError::InvalidAccountData);
     }
-    Ok(&mut *(bytes.as_mut_ptr() as *mut T))
+    bytemuck::try_from_bytes_mut(bytes).map_err(|_| ABLError::InvalidAccountData)
+}
+
+/// Borrow the initialized `T` held by `account`, which must be owned by the program.
+#[inline(always)]
+pub fn load_account<T: Discriminator + Transmutable + Pod>(
+    account: &AccountInfo,
+) -> Result<Ref<'_, T>, ProgramError> {
+    if !account.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    Ref::filter_map(account.try_borrow_data()?, |data| load(data).ok())
+        .map_err(|_| ABLError::InvalidAccountData.into())
+}
+
+/// Mutably borrow the initialized `T` held by `account`, which must be owned
+/// by the program.
+#[inline(always)]
+pub fn load_account_mut<T: Discriminator + Transmutable + Pod>(
+    account: &AccountInfo,
+) -> Result<RefMut<'_, T>, ProgramError> {
+    if !account.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+    RefMut::filter_map(account.try_borrow_mut_data()?, |data| load_mut(data).ok())
+        .map_err(|_| ABLError::InvalidAccountData.into())
+}
+
+/// A `T` read from an account of any of its layout versions, borrowed when it
+/// has the current layout and upgraded into a copy otherwise.
+pub enum VersionedRef<'a, T> {
+    Current(Ref<'a, T>),
+    Older(T),
+}
+
+impl<T> Deref for VersionedRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        match self {
+            Self::Current(t) => t,
+            Self::Older(t) => t,
+        }
+    }
+}
+
+/// Borrow the initialized `T` held by `account`, which must be owned by the
+/// program and may hold any of its layout versions.
+#[inline(always)]
+pub fn load_account_versioned<T: Discriminator + Versioned + Pod>(
+    account: &AccountInfo,
+) -> Result<VersionedRef<'_, T>, ProgramError> {
+    if !account.is_owned_by(&crate::ID) {
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let data = account.try_borrow_data()?;
+    let version = T::version_of(&data).ok_or(ABLError::InvalidAccountData)?;
+    if version == T::VERSION {
+        return Ref::filter_map(data, |data| load(data).ok())
+            .map(VersionedRef::Current)
+            .map_err(|_| ABLError::InvalidAccountData.into());
+    }
+
+    let mut t = T::zeroed();
+    bytemuck::bytes_of_mut(&mut t)[..data.len()].copy_from_slice(&data);
+    t.upgrade(version);
+
+    if t.is_initialized() {
+        Ok(VersionedRef::Older(t))
+    } else {
+        Err(ABLError::InvalidAccountData.into())
+    }
 }
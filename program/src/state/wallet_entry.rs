@@ -1,26 +1,85 @@
-use pinocchio::pubkey::Pubkey;
+use bytemuck::{Pod, Zeroable};
+use pinocchio::pubkey::{find_program_address, Pubkey};
 
-use super::{Discriminator, Transmutable};
+use super::{Discriminator, Transmutable, Versioned};
 
 #[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
 pub struct WalletEntry {
     pub discriminator: u8,
     pub wallet_address: Pubkey,
     pub list_config: Pubkey,
+    /// Layout version, following the fields of the original layout.
+    pub version: u8,
+    /// Risk score of the wallet (risk score lists only).
+    pub score: u8,
+    /// Id of the identity the wallet is linked to, all zeros when unlinked.
+    pub identity: Pubkey,
+    /// Attribute bitmask of the wallet, checked by attribute lists.
+    pub attributes: [u8; 8],
+    /// Unix timestamp the entry takes effect at, 0 when effective immediately.
+    pub active_from: [u8; 8],
+    /// Canonical bump of the entry address.
+    pub bump: u8,
+    /// Whether the wallet enrolled itself, paying the entry rent and `deposit`,
+    /// so it can only be evicted, refunding it.
+    pub enrolled: u8,
+    /// Lamports the wallet deposited in the deposit vault of the list when enrolling.
+    pub deposit: [u8; 8],
 }
 
 impl WalletEntry {
     pub const SEED_PREFIX: &'static [u8] = b"wallet_entry";
+
+    /// Offset of the identity id, used to resolve the identity through extra metas.
+    pub const IDENTITY_OFFSET: usize = 1 + 32 + 32 + 1 + 1;
+
+    pub fn get_attributes(&self) -> u64 {
+        u64::from_le_bytes(self.attributes)
+    }
+
+    pub fn get_active_from(&self) -> i64 {
+        i64::from_le_bytes(self.active_from)
+    }
+
+    pub fn is_linked(&self) -> bool {
+        self.identity != Pubkey::default()
+    }
+
+    pub fn is_enrolled(&self) -> bool {
+        self.enrolled != 0
+    }
+
+    pub fn get_deposit(&self) -> u64 {
+        u64::from_le_bytes(self.deposit)
+    }
 }
 
 impl Transmutable for WalletEntry {
-    const LEN: usize = 1 + 32 + 32;
+    const LEN: usize = 1 + 32 + 32 + 1 + 1 + 32 + 8 + 8 + 1 + 1 + 8;
+}
+
+impl Versioned for WalletEntry {
+    /// The original layout ends with `list_config`.
+    const V1_LEN: usize = 1 + 32 + 32;
+
+    const VERSION: u8 = 2;
+
+    fn upgrade(&mut self, _from_version: u8) {
+        // the bump is the only field the original layout lacked not all zeros when unset
+        self.bump = find_program_address(
+            &[Self::SEED_PREFIX, &self.list_config, &self.wallet_address],
+            &crate::ID,
+        )
+        .1;
+        self.version = Self::VERSION;
+    }
 }
 
 impl Discriminator for WalletEntry {
     const DISCRIMINATOR: u8 = 0x02;
 
     fn is_initialized(&self) -> bool {
-        self.discriminator == Self::DISCRIMINATOR
+        self.discriminator == Self::DISCRIMINATOR && self.version == Self::VERSION
     }
 }
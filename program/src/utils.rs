@@ -0,0 +1,349 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{create_program_address, find_program_address, Pubkey},
+    seeds,
+    sysvars::{rent::Rent, Sysvar},
+    ProgramResult,
+};
+
+use crate::{
+    load_account, load_list_registry, load_list_registry_mut_unchecked, load_mut, ABLError,
+    AuditLog, AuditOperation, DepositVault, Discriminator, ListConfig, ListRegistry,
+    RegisteredList,
+};
+
+/// Resize a program owned account, topping up its rent exempt balance from
+/// `payer` when growing and refunding the excess to `payer` when shrinking.
+pub fn resize_with_rent(
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    new_len: usize,
+) -> ProgramResult {
+    let min_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account.lamports();
+
+    account.resize(new_len)?;
+
+    if current_lamports < min_lamports {
+        pinocchio_system::instructions::Transfer {
+            from: payer,
+            to: account,
+            lamports: min_lamports - current_lamports,
+        }
+        .invoke()?;
+    } else if current_lamports > min_lamports {
+        let payer_lamports = payer.lamports();
+        unsafe {
+            *account.borrow_mut_lamports_unchecked() = min_lamports;
+            *payer.borrow_mut_lamports_unchecked() = payer_lamports
+                .checked_add(current_lamports - min_lamports)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create a program owned account of `space` bytes at the program address of
+/// `seeds`, topping up its rent exempt balance from `payer`.
+///
+/// The address may already hold lamports, which `CreateAccount` would fail on,
+/// letting anyone block the creation of an account by funding its address.
+pub fn create_program_account(
+    account: &AccountInfo,
+    payer: &AccountInfo,
+    space: usize,
+    seeds: &[Seed],
+) -> ProgramResult {
+    let lamports = Rent::get()?.minimum_balance(space);
+    let funded = account.lamports();
+    if funded < lamports {
+        pinocchio_system::instructions::Transfer {
+            from: payer,
+            to: account,
+            lamports: lamports - funded,
+        }
+        .invoke()?;
+    }
+
+    pinocchio_system::instructions::Allocate {
+        account,
+        space: space as u64,
+    }
+    .invoke_signed(&[Signer::from(seeds)])?;
+
+    pinocchio_system::instructions::Assign {
+        account,
+        owner: &crate::ID,
+    }
+    .invoke_signed(&[Signer::from(seeds)])
+}
+
+/// Close a program owned account, sending its lamports to `destination`.
+pub fn close_account(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    let destination_lamports = destination.lamports();
+
+    unsafe {
+        *destination.borrow_mut_lamports_unchecked() = destination_lamports
+            .checked_add(account.lamports())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        account.close_unchecked();
+    }
+
+    account.resize(0)?;
+
+    Ok(())
+}
+
+/// Move `lamports` out of a program owned account to `destination`.
+pub fn transfer_lamports(
+    source: &AccountInfo,
+    destination: &AccountInfo,
+    lamports: u64,
+) -> ProgramResult {
+    let mut source_lamports = source.try_borrow_mut_lamports()?;
+    *source_lamports = source_lamports
+        .checked_sub(lamports)
+        .ok_or(ProgramError::InsufficientFunds)?;
+
+    let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+    *destination_lamports = destination_lamports
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Reads a boolean instruction argument, which must be 0 or 1.
+pub fn parse_flag(flag: u8) -> Result<u8, ABLError> {
+    match flag {
+        0 | 1 => Ok(flag),
+        _ => Err(ABLError::InvalidData),
+    }
+}
+
+/// Checks `deposit_vault` is the deposit vault of the list at `list_config_key`.
+pub fn check_deposit_vault(deposit_vault: &AccountInfo, list_config_key: &Pubkey) -> ProgramResult {
+    match load_account::<DepositVault>(deposit_vault) {
+        Ok(vault) if vault.list_config.eq(list_config_key) => Ok(()),
+        _ => Err(ABLError::InvalidDepositVault.into()),
+    }
+}
+
+/// Token-2022 program id.
+pub const TOKEN_2022_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Offset of the account type byte in a Token-2022 account with extensions.
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+/// Token-2022 account type of a mint.
+const ACCOUNT_TYPE_MINT: u8 = 1;
+/// Token-2022 extension type of the transfer hook mint extension.
+const TRANSFER_HOOK_EXTENSION_TYPE: u16 = 14;
+
+/// Read the `(authority, program_id)` of a Token-2022 mint's transfer hook extension.
+pub fn get_transfer_hook(mint: &AccountInfo) -> Result<(Pubkey, Pubkey), ProgramError> {
+    if !mint.is_owned_by(&TOKEN_2022_PROGRAM_ID) {
+        return Err(ABLError::InvalidMint.into());
+    }
+
+    let data = mint.try_borrow_data()?;
+    if data.get(ACCOUNT_TYPE_OFFSET) != Some(&ACCOUNT_TYPE_MINT) {
+        return Err(ABLError::InvalidMint.into());
+    }
+
+    // walk the extensions TLV entries: [type: u16, length: u16, value]
+    let mut offset = ACCOUNT_TYPE_OFFSET + 1;
+    while let Some(header) = data.get(offset..offset + 4) {
+        let extension_type = u16::from_le_bytes([header[0], header[1]]);
+        let length = u16::from_le_bytes([header[2], header[3]]) as usize;
+        let value = data
+            .get(offset + 4..offset + 4 + length)
+            .ok_or(ABLError::InvalidMint)?;
+
+        if extension_type == TRANSFER_HOOK_EXTENSION_TYPE {
+            if value.len() < 64 {
+                return Err(ABLError::InvalidMint.into());
+            }
+            let authority: Pubkey = value[..32].try_into().unwrap();
+            let program_id: Pubkey = value[32..64].try_into().unwrap();
+            return Ok((authority, program_id));
+        }
+
+        offset += 4 + length;
+    }
+
+    Err(ABLError::InvalidMint.into())
+}
+
+/// Reads the freeze authority `mint` is controlled by from its token-acl mint config.
+pub fn get_freeze_authority(mint_config: &AccountInfo, mint: &Pubkey) -> Result<Pubkey, ABLError> {
+    if !mint_config.is_owned_by(token_acl::ID.as_array()) {
+        return Err(ABLError::InvalidTokenAclMintConfig);
+    }
+
+    let mint_config_data = mint_config
+        .try_borrow_data()
+        .map_err(|_| ABLError::InvalidTokenAclMintConfig)?;
+    let mint_config = token_acl::state::load_mint_config(&mint_config_data)
+        .map_err(|_| ABLError::InvalidTokenAclMintConfig)?;
+
+    if mint_config.mint.as_array() != mint {
+        return Err(ABLError::InvalidTokenAclMintConfig);
+    }
+
+    Ok(*mint_config.freeze_authority.as_array())
+}
+
+/// Appends a wallet change to the audit log of `list_config` when it keeps one,
+/// `audit_log` being the account passed for it.
+pub fn record_audit(
+    list_config: &ListConfig,
+    list_config_key: &Pubkey,
+    audit_log: Option<&AccountInfo>,
+    operation: AuditOperation,
+    wallet: &Pubkey,
+    signer: &Pubkey,
+    slot: u64,
+) -> ProgramResult {
+    if !list_config.has_audit_log() {
+        return Ok(());
+    }
+
+    let audit_log = audit_log.ok_or(ABLError::InvalidAuditLog)?;
+    if !audit_log.is_owned_by(&crate::ID) || !audit_log.is_writable() {
+        return Err(ABLError::InvalidAuditLog.into());
+    }
+
+    let mut data = audit_log.try_borrow_mut_data()?;
+    let audit_log = load_mut::<AuditLog>(&mut data).map_err(|_| ABLError::InvalidAuditLog)?;
+    if audit_log.list_config.ne(list_config_key) {
+        return Err(ABLError::InvalidAuditLog.into());
+    }
+
+    audit_log.append(operation, wallet, signer, slot);
+
+    Ok(())
+}
+
+/// Records `list_config` in the registry of the lists of `authority`, creating
+/// the registry on first use and otherwise growing it by one record.
+pub fn register_list(
+    list_registry: &AccountInfo,
+    authority: &AccountInfo,
+    list_config: &Pubkey,
+    mode: u8,
+    label: [u8; 32],
+) -> ProgramResult {
+    let count = if list_registry.is_owned_by(&crate::ID) {
+        let count = {
+            let data = list_registry.try_borrow_data()?;
+            let (registry, lists) = load_list_registry(&data)?;
+            if registry.authority.ne(authority.key()) {
+                return Err(ABLError::InvalidListRegistry.into());
+            }
+            lists.len()
+        };
+        resize_with_rent(list_registry, authority, ListRegistry::size(count + 1))?;
+        count
+    } else {
+        let (registry_address, registry_bump) =
+            find_program_address(&[ListRegistry::SEED_PREFIX, authority.key()], &crate::ID);
+        if registry_address.ne(list_registry.key()) {
+            return Err(ABLError::InvalidListRegistry.into());
+        }
+
+        let bump_seed = [registry_bump];
+        let seeds = seeds!(ListRegistry::SEED_PREFIX, authority.key(), &bump_seed);
+        create_program_account(list_registry, authority, ListRegistry::size(1), &seeds)?;
+        0
+    };
+
+    let mut data = list_registry.try_borrow_mut_data()?;
+    let (registry, lists) = load_list_registry_mut_unchecked(&mut data)?;
+    registry.discriminator = ListRegistry::DISCRIMINATOR;
+    registry.authority = *authority.key();
+    lists[count] = RegisteredList {
+        list_config: *list_config,
+        mode,
+        label,
+    };
+
+    Ok(())
+}
+
+/// Removes `list_config` from the registry of the lists of `authority`,
+/// shrinking it and refunding the rent of its record. Lists missing from it,
+/// created before every list was registered or recorded under a previous
+/// freeze authority of their mint, leave it unchanged, as does a registry not
+/// created yet.
+pub fn unregister_list(
+    list_registry: &AccountInfo,
+    authority: &AccountInfo,
+    list_config: &Pubkey,
+) -> ProgramResult {
+    if !list_registry.is_owned_by(&crate::ID) {
+        let (registry_address, _) =
+            find_program_address(&[ListRegistry::SEED_PREFIX, authority.key()], &crate::ID);
+        if registry_address.ne(list_registry.key()) {
+            return Err(ABLError::InvalidListRegistry.into());
+        }
+        return Ok(());
+    }
+
+    let count = {
+        let mut data = list_registry.try_borrow_mut_data()?;
+        let (registry, lists) = load_list_registry_mut_unchecked(&mut data)?;
+        if !registry.is_initialized() || registry.authority.ne(authority.key()) {
+            return Err(ABLError::InvalidListRegistry.into());
+        }
+
+        let Some(position) = lists
+            .iter()
+            .position(|list| list.list_config.eq(list_config))
+        else {
+            return Ok(());
+        };
+
+        // records are unordered, the last one takes the place of the removed one
+        let last = lists.len() - 1;
+        lists[position] = lists[last];
+        last
+    };
+
+    resize_with_rent(list_registry, authority, ListRegistry::size(count))
+}
+
+/// Canonical bump of the program address `address` derived from `seeds`.
+///
+/// Every account has a single valid address, the one of the canonical bump:
+/// the existence of an entry is decided by its canonical address being owned
+/// by the program, and the extra metas only ever resolve canonical addresses.
+/// Proving a caller supplied bump is canonical takes the same search as
+/// finding it, so the search only happens when creating accounts, later checks
+/// relying on the bump stored in wallet entries.
+pub fn resolve_bump(seeds: &[&[u8]], address: &Pubkey) -> Result<u8, ABLError> {
+    let (expected, bump) = find_program_address(seeds, &crate::ID);
+
+    if expected.ne(address) {
+        return Err(ABLError::InvalidBump);
+    }
+
+    Ok(bump)
+}
+
+/// Checks `address` is the program address derived from `seeds` (up to 3) and
+/// the canonical `bump` stored in the account, without searching for the bump.
+pub fn check_program_address(seeds: &[&[u8]], bump: u8, address: &Pubkey) -> Result<(), ABLError> {
+    let bump_seed = [bump];
+    let mut seeds_with_bump: [&[u8]; 4] = [&[]; 4];
+    seeds_with_bump[..seeds.len()].copy_from_slice(seeds);
+    seeds_with_bump[seeds.len()] = &bump_seed;
+
+    match create_program_address(&seeds_with_bump[..=seeds.len()], &crate::ID) {
+        Ok(expected) if expected.eq(address) => Ok(()),
+        _ => Err(ABLError::InvalidAccountData),
+    }
+}
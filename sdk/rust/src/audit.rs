@@ -0,0 +1,21 @@
+use crate::{accounts::AuditLog, types::AuditRecord};
+
+/// Number of records an audit log keeps before overwriting the oldest ones.
+pub const AUDIT_LOG_CAPACITY: usize = 64;
+
+/// `AuditRecord::operation` of a wallet added to the list.
+pub const AUDIT_WALLET_ADDED: u8 = 1;
+/// `AuditRecord::operation` of a wallet removed from the list.
+pub const AUDIT_WALLET_REMOVED: u8 = 2;
+
+impl AuditLog {
+    /// Records still held by the ring buffer, oldest first.
+    pub fn recent_records(&self) -> Vec<&AuditRecord> {
+        let capacity = self.records.len() as u64;
+        let start = self.total_records.saturating_sub(capacity);
+
+        (start..self.total_records)
+            .map(|index| &self.records[(index % capacity) as usize])
+            .collect()
+    }
+}
@@ -0,0 +1,35 @@
+use solana_program::{
+    account_info::AccountInfo, program::get_return_data, program_error::ProgramError,
+};
+
+use crate::{
+    instructions::{IsWalletPermittedCpi, IsWalletPermittedCpiAccounts},
+    return_data::WalletVerdict,
+};
+
+/// Asks the allow/block list program whether `wallet` passes `list_config`.
+///
+/// `wallet_entry` is the wallet's entry PDA for the list and is required for
+/// block lists, whether or not the account exists. `identity` is the identity
+/// the wallet entry is linked to, required for lists with identities.
+pub fn is_wallet_permitted<'a>(
+    program: &AccountInfo<'a>,
+    list_config: &AccountInfo<'a>,
+    wallet: &AccountInfo<'a>,
+    wallet_entry: Option<&AccountInfo<'a>>,
+    identity: Option<&AccountInfo<'a>>,
+) -> Result<WalletVerdict, ProgramError> {
+    IsWalletPermittedCpi::new(
+        program,
+        IsWalletPermittedCpiAccounts {
+            list_config,
+            wallet,
+            wallet_entry,
+            identity,
+        },
+    )
+    .invoke()?;
+
+    let (program_id, data) = get_return_data().ok_or(ProgramError::InvalidAccountData)?;
+    WalletVerdict::from_return_data(&program_id, &data).ok_or(ProgramError::InvalidAccountData)
+}
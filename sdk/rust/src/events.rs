@@ -0,0 +1,148 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use solana_program::pubkey::Pubkey;
+
+use crate::programs::ABL_ID;
+
+/// Layout version of the events this decoder understands.
+pub const EVENT_VERSION: u8 = 1;
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+/// Structured event logged by the program on every list mutation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AblEvent {
+    ListCreated {
+        list: Pubkey,
+        authority: Pubkey,
+        mint: Pubkey,
+        mode: u8,
+        storage: u8,
+    },
+    ListDeleted {
+        list: Pubkey,
+    },
+    WalletAdded {
+        list: Pubkey,
+        wallet: Pubkey,
+        signer: Pubkey,
+    },
+    WalletRemoved {
+        list: Pubkey,
+        wallet: Pubkey,
+        signer: Pubkey,
+    },
+    ExtraMetasSetup {
+        mint: Pubkey,
+        transfer_hook: bool,
+        lists: Vec<Pubkey>,
+    },
+}
+
+impl AblEvent {
+    /// Decodes an event from a `Program data: ` log line, returning `None` for
+    /// other lines or unknown versions. Such lines don't name the program that
+    /// logged them, use `parse_events` to only decode the program's own lines.
+    pub fn from_log(log: &str) -> Option<Self> {
+        let segments = log.strip_prefix(PROGRAM_DATA_PREFIX)?;
+
+        let mut data = Vec::new();
+        for segment in segments.split_whitespace() {
+            data.extend(STANDARD.decode(segment).ok()?);
+        }
+
+        Self::decode(&data)
+    }
+
+    /// Decodes an event from its concatenated data segments.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let [EVENT_VERSION, kind, fields @ ..] = data else {
+            return None;
+        };
+
+        let pubkeys = |count: usize, data: &[u8]| -> Option<Vec<Pubkey>> {
+            if data.len() != count * 32 {
+                return None;
+            }
+            Some(
+                data.chunks_exact(32)
+                    .map(|key| Pubkey::new_from_array(key.try_into().unwrap()))
+                    .collect(),
+            )
+        };
+
+        match kind {
+            1 => {
+                let (keys, [mode, storage]) = fields.split_at_checked(96)? else {
+                    return None;
+                };
+                let keys = pubkeys(3, keys)?;
+                Some(Self::ListCreated {
+                    list: keys[0],
+                    authority: keys[1],
+                    mint: keys[2],
+                    mode: *mode,
+                    storage: *storage,
+                })
+            }
+            2 => Some(Self::ListDeleted {
+                list: pubkeys(1, fields)?[0],
+            }),
+            3 | 4 => {
+                let keys = pubkeys(3, fields)?;
+                let (list, wallet, signer) = (keys[0], keys[1], keys[2]);
+                Some(if *kind == 3 {
+                    Self::WalletAdded {
+                        list,
+                        wallet,
+                        signer,
+                    }
+                } else {
+                    Self::WalletRemoved {
+                        list,
+                        wallet,
+                        signer,
+                    }
+                })
+            }
+            5 => {
+                let (mint, [transfer_hook, lists @ ..]) = fields.split_at_checked(32)? else {
+                    return None;
+                };
+                Some(Self::ExtraMetasSetup {
+                    mint: pubkeys(1, mint)?[0],
+                    transfer_hook: *transfer_hook != 0,
+                    lists: pubkeys(lists.len() / 32, lists)?,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Decodes the events of the program found in the logs of a transaction,
+/// skipping data logged by any other program, including ones it invokes.
+pub fn parse_events<S: AsRef<str>>(logs: &[S]) -> Vec<AblEvent> {
+    let program_id = ABL_ID.to_string();
+    let mut invocations: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+
+    for log in logs {
+        let log = log.as_ref();
+        let mut words = log.split_whitespace();
+
+        match (words.next(), words.next(), words.next()) {
+            (Some("Program"), Some(program), Some("invoke")) => invocations.push(program),
+            (Some("Program"), Some(program), Some("success" | "failed:"))
+                if invocations.last() == Some(&program) =>
+            {
+                invocations.pop();
+            }
+            _ if invocations.last() == Some(&program_id.as_str()) => {
+                events.extend(AblEvent::from_log(log));
+            }
+            _ => {}
+        }
+    }
+
+    events
+}
@@ -25,10 +25,60 @@ pub struct ListConfig {
     pub seed: Pubkey,
     pub mode: u8,
     pub wallets_count: u64,
+    /// Layout version, following the fields of the original layout
+    pub version: u8,
+    pub storage: u8,
+    pub risk_threshold: u8,
+    pub identities: u8,
+    pub required_attributes: u64,
+    pub forbidden_attributes: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub attributes_source: Pubkey,
+    pub max_changes_per_epoch: u64,
+    pub changes_epoch: u64,
+    pub changes_in_epoch: u64,
+    pub allow_delay: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub guardian: Pubkey,
+    pub paused: bool,
+    pub fail_open: bool,
+    pub active_from: i64,
+    pub active_until: i64,
+    pub deny_when_inactive: bool,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub mint: Pubkey,
+    pub audit_log: bool,
+    pub self_rotation: bool,
+    pub open_enrollment: bool,
+    pub enrollment_deposit: u64,
+    pub self_thaw_only: bool,
+    pub metadata: bool,
+    pub deposit_vault: bool,
+    pub pending_required_attributes: u64,
+    pub pending_forbidden_attributes: u64,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub pending_attributes_source: Pubkey,
+    pub pending_policy_from: i64,
+    pub pending_max_changes_per_epoch: u64,
+    pub pending_allow_delay: u64,
+    pub pending_limits_from: i64,
+    pub identities_count: u64,
 }
 
 impl ListConfig {
-    pub const LEN: usize = 74;
+    pub const LEN: usize = 343;
 
     /// Prefix values used to generate a PDA for this account.
     ///
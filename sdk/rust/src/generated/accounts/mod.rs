@@ -5,8 +5,20 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#audit_log;
+pub(crate) mod r#bucket;
+pub(crate) mod r#deposit_vault;
+pub(crate) mod r#identity;
 pub(crate) mod r#list_config;
+pub(crate) mod r#list_metadata;
+pub(crate) mod r#list_registry;
 pub(crate) mod r#wallet_entry;
 
+pub use self::r#audit_log::*;
+pub use self::r#bucket::*;
+pub use self::r#deposit_vault::*;
+pub use self::r#identity::*;
 pub use self::r#list_config::*;
+pub use self::r#list_metadata::*;
+pub use self::r#list_registry::*;
 pub use self::r#wallet_entry::*;
@@ -23,10 +23,23 @@ pub struct WalletEntry {
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
     )]
     pub list_config: Pubkey,
+    /// Layout version, following the fields of the original layout
+    pub version: u8,
+    pub score: u8,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub identity: Pubkey,
+    pub attributes: u64,
+    pub active_from: i64,
+    pub bump: u8,
+    pub enrolled: bool,
+    pub deposit: u64,
 }
 
 impl WalletEntry {
-    pub const LEN: usize = 65;
+    pub const LEN: usize = 125;
 
     /// Prefix values used to generate a PDA for this account.
     ///
@@ -11,6 +11,7 @@ use borsh::BorshSerialize;
 /// Accounts.
 #[derive(Debug)]
 pub struct AddWallet {
+    /// List authority, or the guardian for restrictive changes
     pub authority: solana_program::pubkey::Pubkey,
 
     pub list_config: solana_program::pubkey::Pubkey,
@@ -20,19 +21,29 @@ pub struct AddWallet {
     pub wallet_entry: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<solana_program::pubkey::Pubkey>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl AddWallet {
-    pub fn instruction(&self) -> solana_program::instruction::Instruction {
-        self.instruction_with_remaining_accounts(&[])
+    pub fn instruction(
+        &self,
+        args: AddWalletInstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
     }
     #[allow(clippy::arithmetic_side_effects)]
     #[allow(clippy::vec_init_then_push)]
     pub fn instruction_with_remaining_accounts(
         &self,
+        args: AddWalletInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -53,8 +64,31 @@ impl AddWallet {
             self.system_program,
             false,
         ));
+        if let Some(mint_config) = self.mint_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint_config,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                audit_log, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
-        let data = borsh::to_vec(&AddWalletInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&AddWalletInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
 
         solana_program::instruction::Instruction {
             program_id: crate::ABL_ID,
@@ -82,6 +116,13 @@ impl Default for AddWalletInstructionData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AddWalletInstructionArgs {
+    pub score: u8,
+    pub attributes: u64,
+}
+
 /// Instruction builder for `AddWallet`.
 ///
 /// ### Accounts:
@@ -91,6 +132,8 @@ impl Default for AddWalletInstructionData {
 ///   2. `[]` wallet
 ///   3. `[writable]` wallet_entry
 ///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   5. `[optional]` mint_config
+///   6. `[writable, optional]` audit_log
 #[derive(Clone, Debug, Default)]
 pub struct AddWalletBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
@@ -98,6 +141,10 @@ pub struct AddWalletBuilder {
     wallet: Option<solana_program::pubkey::Pubkey>,
     wallet_entry: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    mint_config: Option<solana_program::pubkey::Pubkey>,
+    audit_log: Option<solana_program::pubkey::Pubkey>,
+    score: Option<u8>,
+    attributes: Option<u64>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -105,6 +152,7 @@ impl AddWalletBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+    /// List authority, or the guardian for restrictive changes
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -131,6 +179,35 @@ impl AddWalletBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Token-acl mint config, required for mint bound lists
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_config(
+        &mut self,
+        mint_config: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.mint_config = mint_config;
+        self
+    }
+    /// Audit log, required for lists keeping one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn audit_log(&mut self, audit_log: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.audit_log = audit_log;
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn score(&mut self, score: u8) -> &mut Self {
+        self.score = Some(score);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn attributes(&mut self, attributes: u64) -> &mut Self {
+        self.attributes = Some(attributes);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -159,14 +236,21 @@ impl AddWalletBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            mint_config: self.mint_config,
+            audit_log: self.audit_log,
+        };
+        let args = AddWalletInstructionArgs {
+            score: self.score.clone().unwrap_or(0),
+            attributes: self.attributes.clone().unwrap_or(0),
         };
 
-        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
     }
 }
 
 /// `add_wallet` CPI accounts.
 pub struct AddWalletCpiAccounts<'a, 'b> {
+    /// List authority, or the guardian for restrictive changes
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
@@ -176,6 +260,12 @@ pub struct AddWalletCpiAccounts<'a, 'b> {
     pub wallet_entry: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `add_wallet` CPI instruction.
@@ -183,6 +273,7 @@ pub struct AddWalletCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    /// List authority, or the guardian for restrictive changes
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
@@ -192,12 +283,21 @@ pub struct AddWalletCpi<'a, 'b> {
     pub wallet_entry: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: AddWalletInstructionArgs,
 }
 
 impl<'a, 'b> AddWalletCpi<'a, 'b> {
     pub fn new(
         program: &'b solana_program::account_info::AccountInfo<'a>,
         accounts: AddWalletCpiAccounts<'a, 'b>,
+        args: AddWalletInstructionArgs,
     ) -> Self {
         Self {
             __program: program,
@@ -206,6 +306,9 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
             wallet: accounts.wallet,
             wallet_entry: accounts.wallet_entry,
             system_program: accounts.system_program,
+            mint_config: accounts.mint_config,
+            audit_log: accounts.audit_log,
+            __args: args,
         }
     }
     #[inline(always)]
@@ -242,7 +345,7 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -263,6 +366,28 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        if let Some(mint_config) = self.mint_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint_config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *audit_log.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -270,20 +395,28 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
                 is_writable: remaining_account.2,
             })
         });
-        let data = borsh::to_vec(&AddWalletInstructionData::new()).unwrap();
+        let mut data = borsh::to_vec(&AddWalletInstructionData::new()).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
 
         let instruction = solana_program::instruction::Instruction {
             program_id: crate::ABL_ID,
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.list_config.clone());
         account_infos.push(self.wallet.clone());
         account_infos.push(self.wallet_entry.clone());
         account_infos.push(self.system_program.clone());
+        if let Some(mint_config) = self.mint_config {
+            account_infos.push(mint_config.clone());
+        }
+        if let Some(audit_log) = self.audit_log {
+            account_infos.push(audit_log.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -305,6 +438,8 @@ impl<'a, 'b> AddWalletCpi<'a, 'b> {
 ///   2. `[]` wallet
 ///   3. `[writable]` wallet_entry
 ///   4. `[]` system_program
+///   5. `[optional]` mint_config
+///   6. `[writable, optional]` audit_log
 #[derive(Clone, Debug)]
 pub struct AddWalletCpiBuilder<'a, 'b> {
     instruction: Box<AddWalletCpiBuilderInstruction<'a, 'b>>,
@@ -319,10 +454,15 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
             wallet: None,
             wallet_entry: None,
             system_program: None,
+            mint_config: None,
+            audit_log: None,
+            score: None,
+            attributes: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
+    /// List authority, or the guardian for restrictive changes
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -363,6 +503,38 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Token-acl mint config, required for mint bound lists
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_config(
+        &mut self,
+        mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_config = mint_config;
+        self
+    }
+    /// Audit log, required for lists keeping one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn audit_log(
+        &mut self,
+        audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.audit_log = audit_log;
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn score(&mut self, score: u8) -> &mut Self {
+        self.instruction.score = Some(score);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn attributes(&mut self, attributes: u64) -> &mut Self {
+        self.instruction.attributes = Some(attributes);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -404,6 +576,10 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
         &self,
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
+        let args = AddWalletInstructionArgs {
+            score: self.instruction.score.clone().unwrap_or(0),
+            attributes: self.instruction.attributes.clone().unwrap_or(0),
+        };
         let instruction = AddWalletCpi {
             __program: self.instruction.__program,
 
@@ -425,6 +601,11 @@ impl<'a, 'b> AddWalletCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            mint_config: self.instruction.mint_config,
+
+            audit_log: self.instruction.audit_log,
+            __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -441,6 +622,10 @@ struct AddWalletCpiBuilderInstruction<'a, 'b> {
     wallet: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     wallet_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    score: Option<u8>,
+    attributes: Option<u64>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
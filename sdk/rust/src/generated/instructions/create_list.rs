@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::Mode;
+use crate::generated::types::Storage;
 use borsh::BorshDeserialize;
 use borsh::BorshSerialize;
 use solana_program::pubkey::Pubkey;
@@ -18,6 +19,9 @@ pub struct CreateList {
     pub list_config: solana_program::pubkey::Pubkey,
 
     pub system_program: solana_program::pubkey::Pubkey,
+
+    /// Registry of the lists of the authority, recording the new list
+    pub list_registry: solana_program::pubkey::Pubkey,
 }
 
 impl CreateList {
@@ -34,7 +38,7 @@ impl CreateList {
         args: CreateListInstructionArgs,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -47,6 +51,10 @@ impl CreateList {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.list_registry,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let mut data = borsh::to_vec(&CreateListInstructionData::new()).unwrap();
         let mut args = borsh::to_vec(&args).unwrap();
@@ -83,6 +91,10 @@ impl Default for CreateListInstructionData {
 pub struct CreateListInstructionArgs {
     pub mode: Mode,
     pub seed: Pubkey,
+    pub storage: Storage,
+    pub risk_threshold: u8,
+    pub identities: bool,
+    pub label: [u8; 32],
 }
 
 /// Instruction builder for `CreateList`.
@@ -92,13 +104,19 @@ pub struct CreateListInstructionArgs {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` list_config
 ///   2. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   3. `[writable]` list_registry
 #[derive(Clone, Debug, Default)]
 pub struct CreateListBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     list_config: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    list_registry: Option<solana_program::pubkey::Pubkey>,
     mode: Option<Mode>,
     seed: Option<Pubkey>,
+    storage: Option<Storage>,
+    risk_threshold: Option<u8>,
+    identities: Option<bool>,
+    label: Option<[u8; 32]>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -122,6 +140,12 @@ impl CreateListBuilder {
         self.system_program = Some(system_program);
         self
     }
+    /// Registry of the lists of the authority, recording the new list
+    #[inline(always)]
+    pub fn list_registry(&mut self, list_registry: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.list_registry = Some(list_registry);
+        self
+    }
     #[inline(always)]
     pub fn mode(&mut self, mode: Mode) -> &mut Self {
         self.mode = Some(mode);
@@ -132,6 +156,31 @@ impl CreateListBuilder {
         self.seed = Some(seed);
         self
     }
+    /// `[optional argument, defaults to 'Storage::Entries']`
+    #[inline(always)]
+    pub fn storage(&mut self, storage: Storage) -> &mut Self {
+        self.storage = Some(storage);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn risk_threshold(&mut self, risk_threshold: u8) -> &mut Self {
+        self.risk_threshold = Some(risk_threshold);
+        self
+    }
+    /// `[optional argument, defaults to 'false']`
+    #[inline(always)]
+    pub fn identities(&mut self, identities: bool) -> &mut Self {
+        self.identities = Some(identities);
+        self
+    }
+    /// `[optional argument, defaults to '[0u8; 32]']`
+    /// Label of the list in the registry, utf-8 padded with zeroes
+    #[inline(always)]
+    pub fn label(&mut self, label: [u8; 32]) -> &mut Self {
+        self.label = Some(label);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -158,10 +207,15 @@ impl CreateListBuilder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            list_registry: self.list_registry.expect("list_registry is not set"),
         };
         let args = CreateListInstructionArgs {
             mode: self.mode.clone().expect("mode is not set"),
             seed: self.seed.clone().expect("seed is not set"),
+            storage: self.storage.clone().unwrap_or(Storage::Entries),
+            risk_threshold: self.risk_threshold.clone().unwrap_or(0),
+            identities: self.identities.clone().unwrap_or(false),
+            label: self.label.clone().unwrap_or([0u8; 32]),
         };
 
         accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -175,6 +229,9 @@ pub struct CreateListCpiAccounts<'a, 'b> {
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Registry of the lists of the authority, recording the new list
+    pub list_registry: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `create_list` CPI instruction.
@@ -187,6 +244,9 @@ pub struct CreateListCpi<'a, 'b> {
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Registry of the lists of the authority, recording the new list
+    pub list_registry: &'b solana_program::account_info::AccountInfo<'a>,
     /// The arguments for the instruction.
     pub __args: CreateListInstructionArgs,
 }
@@ -202,6 +262,7 @@ impl<'a, 'b> CreateListCpi<'a, 'b> {
             authority: accounts.authority,
             list_config: accounts.list_config,
             system_program: accounts.system_program,
+            list_registry: accounts.list_registry,
             __args: args,
         }
     }
@@ -239,7 +300,7 @@ impl<'a, 'b> CreateListCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -252,6 +313,10 @@ impl<'a, 'b> CreateListCpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.list_registry.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -268,11 +333,12 @@ impl<'a, 'b> CreateListCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(5 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.list_config.clone());
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.list_registry.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -292,6 +358,7 @@ impl<'a, 'b> CreateListCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` list_config
 ///   2. `[]` system_program
+///   3. `[writable]` list_registry
 #[derive(Clone, Debug)]
 pub struct CreateListCpiBuilder<'a, 'b> {
     instruction: Box<CreateListCpiBuilderInstruction<'a, 'b>>,
@@ -304,8 +371,13 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
             authority: None,
             list_config: None,
             system_program: None,
+            list_registry: None,
             mode: None,
             seed: None,
+            storage: None,
+            risk_threshold: None,
+            identities: None,
+            label: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -334,6 +406,15 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// Registry of the lists of the authority, recording the new list
+    #[inline(always)]
+    pub fn list_registry(
+        &mut self,
+        list_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.list_registry = Some(list_registry);
+        self
+    }
     #[inline(always)]
     pub fn mode(&mut self, mode: Mode) -> &mut Self {
         self.instruction.mode = Some(mode);
@@ -344,6 +425,31 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
         self.instruction.seed = Some(seed);
         self
     }
+    /// `[optional argument, defaults to 'Storage::Entries']`
+    #[inline(always)]
+    pub fn storage(&mut self, storage: Storage) -> &mut Self {
+        self.instruction.storage = Some(storage);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn risk_threshold(&mut self, risk_threshold: u8) -> &mut Self {
+        self.instruction.risk_threshold = Some(risk_threshold);
+        self
+    }
+    /// `[optional argument, defaults to 'false']`
+    #[inline(always)]
+    pub fn identities(&mut self, identities: bool) -> &mut Self {
+        self.instruction.identities = Some(identities);
+        self
+    }
+    /// `[optional argument, defaults to '[0u8; 32]']`
+    /// Label of the list in the registry, utf-8 padded with zeroes
+    #[inline(always)]
+    pub fn label(&mut self, label: [u8; 32]) -> &mut Self {
+        self.instruction.label = Some(label);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -388,6 +494,10 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
         let args = CreateListInstructionArgs {
             mode: self.instruction.mode.clone().expect("mode is not set"),
             seed: self.instruction.seed.clone().expect("seed is not set"),
+            storage: self.instruction.storage.clone().unwrap_or(Storage::Entries),
+            risk_threshold: self.instruction.risk_threshold.clone().unwrap_or(0),
+            identities: self.instruction.identities.clone().unwrap_or(false),
+            label: self.instruction.label.clone().unwrap_or([0u8; 32]),
         };
         let instruction = CreateListCpi {
             __program: self.instruction.__program,
@@ -403,6 +513,11 @@ impl<'a, 'b> CreateListCpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            list_registry: self
+                .instruction
+                .list_registry
+                .expect("list_registry is not set"),
             __args: args,
         };
         instruction.invoke_signed_with_remaining_accounts(
@@ -418,8 +533,13 @@ struct CreateListCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     list_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    list_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mode: Option<Mode>,
     seed: Option<Pubkey>,
+    storage: Option<Storage>,
+    risk_threshold: Option<u8>,
+    identities: Option<bool>,
+    label: Option<[u8; 32]>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
@@ -14,6 +14,21 @@ pub struct DeleteList {
     pub authority: solana_program::pubkey::Pubkey,
 
     pub list_config: solana_program::pubkey::Pubkey,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<solana_program::pubkey::Pubkey>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<solana_program::pubkey::Pubkey>,
+
+    /// Registry of the lists of the authority, the list is removed from it
+    pub list_registry: solana_program::pubkey::Pubkey,
+
+    /// List metadata, required for lists having one
+    pub list_metadata: Option<solana_program::pubkey::Pubkey>,
+
+    /// Deposit vault, required for lists having one
+    pub deposit_vault: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl DeleteList {
@@ -26,7 +41,7 @@ impl DeleteList {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -35,6 +50,53 @@ impl DeleteList {
             self.list_config,
             false,
         ));
+        if let Some(mint_config) = self.mint_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint_config,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                audit_log, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.list_registry,
+            false,
+        ));
+        if let Some(list_metadata) = self.list_metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                list_metadata,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_vault) = self.deposit_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                deposit_vault,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&DeleteListInstructionData::new()).unwrap();
 
@@ -70,10 +132,20 @@ impl Default for DeleteListInstructionData {
 ///
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` list_config
+///   2. `[optional]` mint_config
+///   3. `[writable, optional]` audit_log
+///   4. `[writable]` list_registry
+///   5. `[writable, optional]` list_metadata
+///   6. `[writable, optional]` deposit_vault
 #[derive(Clone, Debug, Default)]
 pub struct DeleteListBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     list_config: Option<solana_program::pubkey::Pubkey>,
+    mint_config: Option<solana_program::pubkey::Pubkey>,
+    audit_log: Option<solana_program::pubkey::Pubkey>,
+    list_registry: Option<solana_program::pubkey::Pubkey>,
+    list_metadata: Option<solana_program::pubkey::Pubkey>,
+    deposit_vault: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -91,6 +163,49 @@ impl DeleteListBuilder {
         self.list_config = Some(list_config);
         self
     }
+    /// Token-acl mint config, required for mint bound lists
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_config(
+        &mut self,
+        mint_config: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.mint_config = mint_config;
+        self
+    }
+    /// Audit log, required for lists keeping one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn audit_log(&mut self, audit_log: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.audit_log = audit_log;
+        self
+    }
+    /// Registry of the lists of the authority, the list is removed from it
+    #[inline(always)]
+    pub fn list_registry(&mut self, list_registry: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.list_registry = Some(list_registry);
+        self
+    }
+    /// List metadata, required for lists having one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn list_metadata(
+        &mut self,
+        list_metadata: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.list_metadata = list_metadata;
+        self
+    }
+    /// Deposit vault, required for lists having one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn deposit_vault(
+        &mut self,
+        deposit_vault: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.deposit_vault = deposit_vault;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -114,6 +229,11 @@ impl DeleteListBuilder {
         let accounts = DeleteList {
             authority: self.authority.expect("authority is not set"),
             list_config: self.list_config.expect("list_config is not set"),
+            mint_config: self.mint_config,
+            audit_log: self.audit_log,
+            list_registry: self.list_registry.expect("list_registry is not set"),
+            list_metadata: self.list_metadata,
+            deposit_vault: self.deposit_vault,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -125,6 +245,21 @@ pub struct DeleteListCpiAccounts<'a, 'b> {
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Registry of the lists of the authority, the list is removed from it
+    pub list_registry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// List metadata, required for lists having one
+    pub list_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Deposit vault, required for lists having one
+    pub deposit_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `delete_list` CPI instruction.
@@ -135,6 +270,21 @@ pub struct DeleteListCpi<'a, 'b> {
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Registry of the lists of the authority, the list is removed from it
+    pub list_registry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// List metadata, required for lists having one
+    pub list_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Deposit vault, required for lists having one
+    pub deposit_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> DeleteListCpi<'a, 'b> {
@@ -146,6 +296,11 @@ impl<'a, 'b> DeleteListCpi<'a, 'b> {
             __program: program,
             authority: accounts.authority,
             list_config: accounts.list_config,
+            mint_config: accounts.mint_config,
+            audit_log: accounts.audit_log,
+            list_registry: accounts.list_registry,
+            list_metadata: accounts.list_metadata,
+            deposit_vault: accounts.deposit_vault,
         }
     }
     #[inline(always)]
@@ -182,7 +337,7 @@ impl<'a, 'b> DeleteListCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -191,6 +346,54 @@ impl<'a, 'b> DeleteListCpi<'a, 'b> {
             *self.list_config.key,
             false,
         ));
+        if let Some(mint_config) = self.mint_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint_config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *audit_log.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.list_registry.key,
+            false,
+        ));
+        if let Some(list_metadata) = self.list_metadata {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *list_metadata.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(deposit_vault) = self.deposit_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *deposit_vault.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -205,10 +408,23 @@ impl<'a, 'b> DeleteListCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.list_config.clone());
+        if let Some(mint_config) = self.mint_config {
+            account_infos.push(mint_config.clone());
+        }
+        if let Some(audit_log) = self.audit_log {
+            account_infos.push(audit_log.clone());
+        }
+        account_infos.push(self.list_registry.clone());
+        if let Some(list_metadata) = self.list_metadata {
+            account_infos.push(list_metadata.clone());
+        }
+        if let Some(deposit_vault) = self.deposit_vault {
+            account_infos.push(deposit_vault.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -227,6 +443,11 @@ impl<'a, 'b> DeleteListCpi<'a, 'b> {
 ///
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` list_config
+///   2. `[optional]` mint_config
+///   3. `[writable, optional]` audit_log
+///   4. `[writable]` list_registry
+///   5. `[writable, optional]` list_metadata
+///   6. `[writable, optional]` deposit_vault
 #[derive(Clone, Debug)]
 pub struct DeleteListCpiBuilder<'a, 'b> {
     instruction: Box<DeleteListCpiBuilderInstruction<'a, 'b>>,
@@ -238,6 +459,11 @@ impl<'a, 'b> DeleteListCpiBuilder<'a, 'b> {
             __program: program,
             authority: None,
             list_config: None,
+            mint_config: None,
+            audit_log: None,
+            list_registry: None,
+            list_metadata: None,
+            deposit_vault: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -258,6 +484,55 @@ impl<'a, 'b> DeleteListCpiBuilder<'a, 'b> {
         self.instruction.list_config = Some(list_config);
         self
     }
+    /// Token-acl mint config, required for mint bound lists
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_config(
+        &mut self,
+        mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_config = mint_config;
+        self
+    }
+    /// Audit log, required for lists keeping one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn audit_log(
+        &mut self,
+        audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.audit_log = audit_log;
+        self
+    }
+    /// Registry of the lists of the authority, the list is removed from it
+    #[inline(always)]
+    pub fn list_registry(
+        &mut self,
+        list_registry: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.list_registry = Some(list_registry);
+        self
+    }
+    /// List metadata, required for lists having one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn list_metadata(
+        &mut self,
+        list_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.list_metadata = list_metadata;
+        self
+    }
+    /// Deposit vault, required for lists having one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn deposit_vault(
+        &mut self,
+        deposit_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.deposit_vault = deposit_vault;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -308,6 +583,19 @@ impl<'a, 'b> DeleteListCpiBuilder<'a, 'b> {
                 .instruction
                 .list_config
                 .expect("list_config is not set"),
+
+            mint_config: self.instruction.mint_config,
+
+            audit_log: self.instruction.audit_log,
+
+            list_registry: self
+                .instruction
+                .list_registry
+                .expect("list_registry is not set"),
+
+            list_metadata: self.instruction.list_metadata,
+
+            deposit_vault: self.instruction.deposit_vault,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -321,6 +609,11 @@ struct DeleteListCpiBuilderInstruction<'a, 'b> {
     __program: &'b solana_program::account_info::AccountInfo<'a>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     list_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    list_registry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    list_metadata: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    deposit_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
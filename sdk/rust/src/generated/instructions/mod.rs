@@ -5,14 +5,70 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#add_bucket_wallets;
 pub(crate) mod r#add_wallet;
+pub(crate) mod r#add_wallet_idempotent;
+pub(crate) mod r#create_audit_log;
+pub(crate) mod r#create_identity;
 pub(crate) mod r#create_list;
+pub(crate) mod r#create_mint_list;
 pub(crate) mod r#delete_list;
+pub(crate) mod r#enroll;
+pub(crate) mod r#evict_wallet;
+pub(crate) mod r#force_delete_list;
+pub(crate) mod r#is_wallet_permitted;
+pub(crate) mod r#link_wallet_identity;
+pub(crate) mod r#migrate_account;
+pub(crate) mod r#prune_list_registry;
+pub(crate) mod r#reclaim_wallet_entry;
+pub(crate) mod r#remove_bucket_wallets;
 pub(crate) mod r#remove_wallet;
+pub(crate) mod r#rotate_wallet;
+pub(crate) mod r#set_activation_window;
+pub(crate) mod r#set_attribute_policy;
+pub(crate) mod r#set_guardian;
+pub(crate) mod r#set_identity_status;
+pub(crate) mod r#set_list_limits;
+pub(crate) mod r#set_list_paused;
+pub(crate) mod r#set_open_enrollment;
+pub(crate) mod r#set_risk_threshold;
+pub(crate) mod r#set_self_rotation;
+pub(crate) mod r#set_self_thaw_only;
+pub(crate) mod r#set_wallet_attributes;
 pub(crate) mod r#setup_extra_metas;
+pub(crate) mod r#setup_transfer_hook_extra_metas;
+pub(crate) mod r#update_list_metadata;
 
+pub use self::r#add_bucket_wallets::*;
 pub use self::r#add_wallet::*;
+pub use self::r#add_wallet_idempotent::*;
+pub use self::r#create_audit_log::*;
+pub use self::r#create_identity::*;
 pub use self::r#create_list::*;
+pub use self::r#create_mint_list::*;
 pub use self::r#delete_list::*;
+pub use self::r#enroll::*;
+pub use self::r#evict_wallet::*;
+pub use self::r#force_delete_list::*;
+pub use self::r#is_wallet_permitted::*;
+pub use self::r#link_wallet_identity::*;
+pub use self::r#migrate_account::*;
+pub use self::r#prune_list_registry::*;
+pub use self::r#reclaim_wallet_entry::*;
+pub use self::r#remove_bucket_wallets::*;
 pub use self::r#remove_wallet::*;
+pub use self::r#rotate_wallet::*;
+pub use self::r#set_activation_window::*;
+pub use self::r#set_attribute_policy::*;
+pub use self::r#set_guardian::*;
+pub use self::r#set_identity_status::*;
+pub use self::r#set_list_limits::*;
+pub use self::r#set_list_paused::*;
+pub use self::r#set_open_enrollment::*;
+pub use self::r#set_risk_threshold::*;
+pub use self::r#set_self_rotation::*;
+pub use self::r#set_self_thaw_only::*;
+pub use self::r#set_wallet_attributes::*;
 pub use self::r#setup_extra_metas::*;
+pub use self::r#setup_transfer_hook_extra_metas::*;
+pub use self::r#update_list_metadata::*;
@@ -11,11 +11,21 @@ use borsh::BorshSerialize;
 /// Accounts.
 #[derive(Debug)]
 pub struct RemoveWallet {
+    /// List authority, or the guardian for restrictive changes
     pub authority: solana_program::pubkey::Pubkey,
 
     pub list_config: solana_program::pubkey::Pubkey,
 
     pub wallet_entry: solana_program::pubkey::Pubkey,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<solana_program::pubkey::Pubkey>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<solana_program::pubkey::Pubkey>,
+
+    /// List authority, refunded the entry rent when the guardian removes the wallet
+    pub list_authority: Option<solana_program::pubkey::Pubkey>,
 }
 
 impl RemoveWallet {
@@ -28,7 +38,7 @@ impl RemoveWallet {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.authority,
             true,
@@ -41,6 +51,38 @@ impl RemoveWallet {
             self.wallet_entry,
             false,
         ));
+        if let Some(mint_config) = self.mint_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                mint_config,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                audit_log, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(list_authority) = self.list_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                list_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&RemoveWalletInstructionData::new()).unwrap();
 
@@ -77,11 +119,17 @@ impl Default for RemoveWalletInstructionData {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` list_config
 ///   2. `[writable]` wallet_entry
+///   3. `[optional]` mint_config
+///   4. `[writable, optional]` audit_log
+///   5. `[writable, optional]` list_authority
 #[derive(Clone, Debug, Default)]
 pub struct RemoveWalletBuilder {
     authority: Option<solana_program::pubkey::Pubkey>,
     list_config: Option<solana_program::pubkey::Pubkey>,
     wallet_entry: Option<solana_program::pubkey::Pubkey>,
+    mint_config: Option<solana_program::pubkey::Pubkey>,
+    audit_log: Option<solana_program::pubkey::Pubkey>,
+    list_authority: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -89,6 +137,7 @@ impl RemoveWalletBuilder {
     pub fn new() -> Self {
         Self::default()
     }
+    /// List authority, or the guardian for restrictive changes
     #[inline(always)]
     pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
         self.authority = Some(authority);
@@ -104,6 +153,33 @@ impl RemoveWalletBuilder {
         self.wallet_entry = Some(wallet_entry);
         self
     }
+    /// Token-acl mint config, required for mint bound lists
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_config(
+        &mut self,
+        mint_config: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.mint_config = mint_config;
+        self
+    }
+    /// Audit log, required for lists keeping one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn audit_log(&mut self, audit_log: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.audit_log = audit_log;
+        self
+    }
+    /// List authority, refunded the entry rent when the guardian removes the wallet
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn list_authority(
+        &mut self,
+        list_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.list_authority = list_authority;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -128,6 +204,9 @@ impl RemoveWalletBuilder {
             authority: self.authority.expect("authority is not set"),
             list_config: self.list_config.expect("list_config is not set"),
             wallet_entry: self.wallet_entry.expect("wallet_entry is not set"),
+            mint_config: self.mint_config,
+            audit_log: self.audit_log,
+            list_authority: self.list_authority,
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -136,11 +215,21 @@ impl RemoveWalletBuilder {
 
 /// `remove_wallet` CPI accounts.
 pub struct RemoveWalletCpiAccounts<'a, 'b> {
+    /// List authority, or the guardian for restrictive changes
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub wallet_entry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// List authority, refunded the entry rent when the guardian removes the wallet
+    pub list_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 /// `remove_wallet` CPI instruction.
@@ -148,11 +237,21 @@ pub struct RemoveWalletCpi<'a, 'b> {
     /// The program to invoke.
     pub __program: &'b solana_program::account_info::AccountInfo<'a>,
 
+    /// List authority, or the guardian for restrictive changes
     pub authority: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub list_config: &'b solana_program::account_info::AccountInfo<'a>,
 
     pub wallet_entry: &'b solana_program::account_info::AccountInfo<'a>,
+
+    /// Token-acl mint config, required for mint bound lists
+    pub mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// Audit log, required for lists keeping one
+    pub audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+
+    /// List authority, refunded the entry rent when the guardian removes the wallet
+    pub list_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
 }
 
 impl<'a, 'b> RemoveWalletCpi<'a, 'b> {
@@ -165,6 +264,9 @@ impl<'a, 'b> RemoveWalletCpi<'a, 'b> {
             authority: accounts.authority,
             list_config: accounts.list_config,
             wallet_entry: accounts.wallet_entry,
+            mint_config: accounts.mint_config,
+            audit_log: accounts.audit_log,
+            list_authority: accounts.list_authority,
         }
     }
     #[inline(always)]
@@ -201,7 +303,7 @@ impl<'a, 'b> RemoveWalletCpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.authority.key,
             true,
@@ -214,6 +316,39 @@ impl<'a, 'b> RemoveWalletCpi<'a, 'b> {
             *self.wallet_entry.key,
             false,
         ));
+        if let Some(mint_config) = self.mint_config {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *mint_config.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(audit_log) = self.audit_log {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *audit_log.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
+        if let Some(list_authority) = self.list_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *list_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::ABL_ID,
+                false,
+            ));
+        }
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -228,11 +363,20 @@ impl<'a, 'b> RemoveWalletCpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(4 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.authority.clone());
         account_infos.push(self.list_config.clone());
         account_infos.push(self.wallet_entry.clone());
+        if let Some(mint_config) = self.mint_config {
+            account_infos.push(mint_config.clone());
+        }
+        if let Some(audit_log) = self.audit_log {
+            account_infos.push(audit_log.clone());
+        }
+        if let Some(list_authority) = self.list_authority {
+            account_infos.push(list_authority.clone());
+        }
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -252,6 +396,9 @@ impl<'a, 'b> RemoveWalletCpi<'a, 'b> {
 ///   0. `[writable, signer]` authority
 ///   1. `[writable]` list_config
 ///   2. `[writable]` wallet_entry
+///   3. `[optional]` mint_config
+///   4. `[writable, optional]` audit_log
+///   5. `[writable, optional]` list_authority
 #[derive(Clone, Debug)]
 pub struct RemoveWalletCpiBuilder<'a, 'b> {
     instruction: Box<RemoveWalletCpiBuilderInstruction<'a, 'b>>,
@@ -264,10 +411,14 @@ impl<'a, 'b> RemoveWalletCpiBuilder<'a, 'b> {
             authority: None,
             list_config: None,
             wallet_entry: None,
+            mint_config: None,
+            audit_log: None,
+            list_authority: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
     }
+    /// List authority, or the guardian for restrictive changes
     #[inline(always)]
     pub fn authority(
         &mut self,
@@ -292,6 +443,36 @@ impl<'a, 'b> RemoveWalletCpiBuilder<'a, 'b> {
         self.instruction.wallet_entry = Some(wallet_entry);
         self
     }
+    /// Token-acl mint config, required for mint bound lists
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn mint_config(
+        &mut self,
+        mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.mint_config = mint_config;
+        self
+    }
+    /// Audit log, required for lists keeping one
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn audit_log(
+        &mut self,
+        audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.audit_log = audit_log;
+        self
+    }
+    /// List authority, refunded the entry rent when the guardian removes the wallet
+    /// `[optional account]`
+    #[inline(always)]
+    pub fn list_authority(
+        &mut self,
+        list_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.list_authority = list_authority;
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -347,6 +528,12 @@ impl<'a, 'b> RemoveWalletCpiBuilder<'a, 'b> {
                 .instruction
                 .wallet_entry
                 .expect("wallet_entry is not set"),
+
+            mint_config: self.instruction.mint_config,
+
+            audit_log: self.instruction.audit_log,
+
+            list_authority: self.instruction.list_authority,
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -361,6 +548,9 @@ struct RemoveWalletCpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     list_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     wallet_entry: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    audit_log: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    list_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
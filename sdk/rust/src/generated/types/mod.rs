@@ -5,6 +5,14 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+pub(crate) mod r#audit_record;
+pub(crate) mod r#identity_status;
 pub(crate) mod r#mode;
+pub(crate) mod r#registered_list;
+pub(crate) mod r#storage;
 
+pub use self::r#audit_record::*;
+pub use self::r#identity_status::*;
 pub use self::r#mode::*;
+pub use self::r#registered_list::*;
+pub use self::r#storage::*;
@@ -26,4 +26,6 @@ pub enum Mode {
     Allow,
     AllowAllEoas,
     Block,
+    RiskScore,
+    Attributes,
 }
@@ -1,2 +1,7 @@
+pub mod audit;
+pub mod cpi;
+pub mod events;
 pub mod generated;
+pub mod pda;
+pub mod return_data;
 pub use generated::*;
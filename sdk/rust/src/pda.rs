@@ -0,0 +1,8 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::programs::ABL_ID;
+
+/// Address of the list bound to `mint`, controlled by the mint's token-acl freeze authority.
+pub fn find_mint_list_config_pda(mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"mint_list_config", mint.as_ref()], &ABL_ID)
+}
@@ -0,0 +1,108 @@
+use solana_program::pubkey::Pubkey;
+
+use crate::{errors::AblError, programs::ABL_ID};
+
+/// Reason a wallet failed a list check, as encoded by the program in the
+/// second byte of its return data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DenialReason {
+    InvalidListAccount = 1,
+    NotOnAllowList = 2,
+    NonEoaNotOnAllowList = 3,
+    OnBlockList = 4,
+    RiskScoreTooHigh = 5,
+    IdentityBlocked = 6,
+    AttributesMismatch = 7,
+    ListPaused = 8,
+    ListInactive = 9,
+    ThawNotByOwner = 10,
+}
+
+impl DenialReason {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Self::InvalidListAccount),
+            2 => Some(Self::NotOnAllowList),
+            3 => Some(Self::NonEoaNotOnAllowList),
+            4 => Some(Self::OnBlockList),
+            5 => Some(Self::RiskScoreTooHigh),
+            6 => Some(Self::IdentityBlocked),
+            7 => Some(Self::AttributesMismatch),
+            8 => Some(Self::ListPaused),
+            9 => Some(Self::ListInactive),
+            10 => Some(Self::ThawNotByOwner),
+            _ => None,
+        }
+    }
+}
+
+impl From<DenialReason> for AblError {
+    fn from(reason: DenialReason) -> Self {
+        match reason {
+            DenialReason::InvalidListAccount => AblError::InvalidListAccount,
+            DenialReason::NotOnAllowList => AblError::WalletNotAllowed,
+            DenialReason::NonEoaNotOnAllowList => AblError::NonEoaWalletNotAllowed,
+            DenialReason::OnBlockList => AblError::WalletBlocked,
+            DenialReason::RiskScoreTooHigh => AblError::WalletRiskTooHigh,
+            DenialReason::IdentityBlocked => AblError::IdentityBlocked,
+            DenialReason::AttributesMismatch => AblError::AttributesMismatch,
+            DenialReason::ListPaused => AblError::ListPaused,
+            DenialReason::ListInactive => AblError::ListInactive,
+            DenialReason::ThawNotByOwner => AblError::ThawNotByOwner,
+        }
+    }
+}
+
+/// Denial reported by `CanThawPermissionless` through return data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ThawDenial {
+    /// Index of the failing list in the extra metas, in setup order.
+    pub list_index: u8,
+    pub reason: DenialReason,
+}
+
+impl ThawDenial {
+    /// Decodes the return data of a failed thaw. Returns `None` if the data
+    /// was not set by the allow/block list program or is malformed.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &ABL_ID {
+            return None;
+        }
+
+        let [list_index, reason] = data else {
+            return None;
+        };
+
+        Some(Self {
+            list_index: *list_index,
+            reason: DenialReason::from_u8(*reason)?,
+        })
+    }
+}
+
+/// Verdict reported by `IsWalletPermitted` through return data.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WalletVerdict {
+    Permitted,
+    Denied(DenialReason),
+}
+
+impl WalletVerdict {
+    /// Decodes the return data of an `IsWalletPermitted` call. Returns `None`
+    /// if the data was not set by the allow/block list program or is malformed.
+    pub fn from_return_data(program_id: &Pubkey, data: &[u8]) -> Option<Self> {
+        if program_id != &ABL_ID {
+            return None;
+        }
+
+        match data {
+            [1, 0] => Some(Self::Permitted),
+            [0, reason] => Some(Self::Denied(DenialReason::from_u8(*reason)?)),
+            _ => None,
+        }
+    }
+
+    pub fn is_permitted(&self) -> bool {
+        matches!(self, Self::Permitted)
+    }
+}
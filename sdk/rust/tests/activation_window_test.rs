@@ -0,0 +1,125 @@
+pub mod program_test;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::SetActivationWindowBuilder;
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn set_activation_window(
+    context: &mut TestContext,
+    list: &Pubkey,
+    active_from: i64,
+    active_until: i64,
+    deny_when_inactive: bool,
+) -> TransactionResult {
+    let ix = SetActivationWindowBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .active_from(active_from)
+        .active_until(active_until)
+        .deny_when_inactive(deny_when_inactive)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn now(context: &TestContext) -> i64 {
+    context.vm.get_sysvar::<Clock>().unix_timestamp
+}
+
+fn set_now(context: &mut TestContext, unix_timestamp: i64) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp = unix_timestamp;
+    context.vm.set_sysvar(&clock);
+    context.vm.expire_blockhash();
+}
+
+#[tokio::test]
+async fn launch_allow_list_denies_before_start() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let launch = now(&context) + 3600;
+    let res = set_activation_window(&mut context, &list, launch, 0, true);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::ListInactive as u32)
+        )
+    );
+
+    set_now(&mut context, launch);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn temporary_block_list_lapses() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let end = now(&context) + 3600;
+    let res = set_activation_window(&mut context, &list, 0, end, false);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletBlocked as u32)
+        )
+    );
+
+    // lapsed lists are skipped
+    set_now(&mut context, end);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_set_empty_window() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+
+    let res = set_activation_window(&mut context, &list, 100, 100, false);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidData as u32)
+        )
+    );
+}
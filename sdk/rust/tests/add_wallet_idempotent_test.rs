@@ -0,0 +1,103 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{AddWalletBuilder, AddWalletIdempotentBuilder};
+use allow_block_list_client::types::Mode;
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+fn add_wallet_ix(context: &TestContext, list: &Pubkey, wallet: &Pubkey, score: u8) -> Instruction {
+    AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(WalletEntry::find_pda(list, wallet).0)
+        .score(score)
+        .instruction()
+}
+
+fn add_wallet_idempotent_ix(
+    context: &TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    score: u8,
+) -> Instruction {
+    AddWalletIdempotentBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(WalletEntry::find_pda(list, wallet).0)
+        .score(score)
+        .instruction()
+}
+
+fn wallets_count(context: &TestContext, list: &Pubkey) -> u64 {
+    let account = context.vm.get_account(list).unwrap();
+    ListConfig::from_bytes(&account.data).unwrap().wallets_count
+}
+
+#[tokio::test]
+async fn fails_to_add_listed_wallet() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list, &wallet);
+
+    let ix = add_wallet_ix(&context, &list, &wallet, 0);
+    let res = context.send(ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::EntryAlreadyExists as u32)
+        )
+    );
+
+    assert_eq!(wallets_count(&context, &list), 1);
+}
+
+#[tokio::test]
+async fn idempotent_add_creates_missing_entry() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+
+    let ix = add_wallet_idempotent_ix(&context, &list, &wallet, 0);
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    let entry = context
+        .vm
+        .get_account(&WalletEntry::find_pda(&list, &wallet).0)
+        .unwrap();
+    let entry = WalletEntry::from_bytes(&entry.data).unwrap();
+    assert_eq!(entry.wallet_address, wallet);
+    assert_eq!(entry.list_config, list);
+    assert_eq!(wallets_count(&context, &list), 1);
+}
+
+#[tokio::test]
+async fn idempotent_add_leaves_listed_wallet_unchanged() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_scored_wallet_to_list(&list, &wallet, 10);
+    let before = context.vm.get_account(&wallet_entry).unwrap();
+
+    // retried with different data, the existing entry wins
+    let ix = add_wallet_idempotent_ix(&context, &list, &wallet, 90);
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    let after = context.vm.get_account(&wallet_entry).unwrap();
+    assert_eq!(after.data, before.data);
+    assert_eq!(wallets_count(&context, &list), 1);
+}
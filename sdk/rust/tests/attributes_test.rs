@@ -0,0 +1,213 @@
+pub mod program_test;
+use allow_block_list_client::accounts::WalletEntry;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{
+    SetAttributePolicyBuilder, SetWalletAttributesBuilder,
+};
+use allow_block_list_client::types::Mode;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+const ACCREDITED: u64 = 1 << 0;
+const US_PERSON: u64 = 1 << 1;
+
+fn add_wallet_with_attributes(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    attributes: u64,
+) -> Pubkey {
+    let wallet_entry = context.add_wallet_to_list(list, wallet);
+
+    let ix = SetWalletAttributesBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet_entry(wallet_entry)
+        .attributes(attributes)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    wallet_entry
+}
+
+fn create_policy(
+    context: &mut TestContext,
+    source: &Pubkey,
+    required: u64,
+    forbidden: u64,
+) -> Pubkey {
+    let list = context.create_list(Mode::Attributes);
+
+    let ix = SetAttributePolicyBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .attributes_source(*source)
+        .required_attributes(required)
+        .forbidden_attributes(forbidden)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    list
+}
+
+#[tokio::test]
+async fn stores_wallet_attributes() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry =
+        add_wallet_with_attributes(&mut context, &list, &wallet, ACCREDITED | US_PERSON);
+
+    let entry_account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry_data = WalletEntry::from_bytes(&entry_account.data).unwrap();
+    assert_eq!(entry_data.attributes, ACCREDITED | US_PERSON);
+}
+
+#[tokio::test]
+async fn thaws_wallets_matching_policy_from_shared_entries() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let registry = context.create_list(Mode::Allow);
+    // accredited non us persons only
+    let policy = create_policy(&mut context, &registry, ACCREDITED, US_PERSON);
+    let _ = context.setup_extra_metas(&[policy]);
+
+    let accredited = Keypair::new();
+    let _ = add_wallet_with_attributes(&mut context, &registry, &accredited.pubkey(), ACCREDITED);
+    let ta = context.create_token_account(&accredited);
+    let res = context.thaw_permissionless(&accredited.pubkey(), &ta).await;
+    assert!(res.is_ok());
+
+    let us_person = Keypair::new();
+    let _ = add_wallet_with_attributes(
+        &mut context,
+        &registry,
+        &us_person.pubkey(),
+        ACCREDITED | US_PERSON,
+    );
+    let ta = context.create_token_account(&us_person);
+    let res = context.thaw_permissionless(&us_person.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::AttributesMismatch as u32)
+        )
+    );
+
+    let retail = Keypair::new();
+    let _ = add_wallet_with_attributes(&mut context, &registry, &retail.pubkey(), 0);
+    let ta = context.create_token_account(&retail);
+    let res = context.thaw_permissionless(&retail.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::AttributesMismatch as u32)
+        )
+    );
+
+    let unlisted = Keypair::new();
+    let ta = context.create_token_account(&unlisted);
+    let res = context.thaw_permissionless(&unlisted.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletNotAllowed as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_set_policy_on_non_attribute_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+
+    let ix = SetAttributePolicyBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .attributes_source(list)
+        .required_attributes(ACCREDITED)
+        .forbidden_attributes(0)
+        .instruction();
+    let res = context.send(ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidData as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn resolves_source_entries_through_extra_metas() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let registry = context.create_list(Mode::Allow);
+    let policy = create_policy(&mut context, &registry, ACCREDITED, 0);
+    let _ = context.setup_extra_metas(&[policy]);
+
+    let accredited = Keypair::new();
+    let _ = add_wallet_with_attributes(&mut context, &registry, &accredited.pubkey(), ACCREDITED);
+    let ta = context.create_token_account(&accredited);
+
+    let ix = context
+        .get_thaw_permissionless_ix(&context.auth.pubkey(), &accredited.pubkey(), &ta)
+        .await;
+    let (source_entry, _) = WalletEntry::find_pda(&registry, &accredited.pubkey());
+    let (own_entry, _) = WalletEntry::find_pda(&policy, &accredited.pubkey());
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == source_entry));
+    assert!(!ix.accounts.iter().any(|meta| meta.pubkey == own_entry));
+
+    let res = context.send(ix);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn thaws_from_own_entries_through_extra_metas() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Attributes);
+    let ix = SetAttributePolicyBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .attributes_source(list)
+        .required_attributes(ACCREDITED)
+        .forbidden_attributes(0)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+    let _ = context.setup_extra_metas(&[list]);
+
+    let accredited = Keypair::new();
+    let _ = add_wallet_with_attributes(&mut context, &list, &accredited.pubkey(), ACCREDITED);
+    let ta = context.create_token_account(&accredited);
+    let res = context.thaw_permissionless(&accredited.pubkey(), &ta).await;
+    assert!(res.is_ok());
+
+    let retail = Keypair::new();
+    let _ = add_wallet_with_attributes(&mut context, &list, &retail.pubkey(), 0);
+    let ta = context.create_token_account(&retail);
+    let res = context.thaw_permissionless(&retail.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::AttributesMismatch as u32)
+        )
+    );
+}
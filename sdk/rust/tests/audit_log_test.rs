@@ -0,0 +1,236 @@
+pub mod program_test;
+use allow_block_list_client::{
+    accounts::{AuditLog, ListConfig, WalletEntry},
+    audit::{AUDIT_LOG_CAPACITY, AUDIT_WALLET_ADDED, AUDIT_WALLET_REMOVED},
+    types::{Mode, Storage},
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::program_test::TestContext;
+
+fn create_audit_log(context: &mut TestContext, list: &Pubkey) -> Pubkey {
+    let (audit_log, _) = AuditLog::find_pda(list);
+
+    let ix = allow_block_list_client::instructions::CreateAuditLogBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .audit_log(audit_log)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    audit_log
+}
+
+fn add_wallet(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    audit_log: Option<Pubkey>,
+) -> bool {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+
+    let ix = allow_block_list_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .audit_log(audit_log)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx).is_ok()
+}
+
+fn remove_wallet(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    audit_log: Option<Pubkey>,
+) -> bool {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+
+    let ix = allow_block_list_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet_entry(wallet_entry)
+        .audit_log(audit_log)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx).is_ok()
+}
+
+fn get_audit_log(context: &TestContext, audit_log: &Pubkey) -> AuditLog {
+    let account = context.vm.get_account(audit_log).unwrap();
+    AuditLog::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn records_wallet_changes() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let audit_log = create_audit_log(&mut context, &list);
+
+    let config = ListConfig::from_bytes(&context.vm.get_account(&list).unwrap().data).unwrap();
+    assert!(config.audit_log);
+
+    let wallet = Pubkey::new_unique();
+    assert!(add_wallet(&mut context, &list, &wallet, Some(audit_log)));
+    assert!(remove_wallet(&mut context, &list, &wallet, Some(audit_log)));
+
+    let log = get_audit_log(&context, &audit_log);
+    assert_eq!(log.list_config, list);
+    assert_eq!(log.total_records, 2);
+
+    let records = log.recent_records();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].operation, AUDIT_WALLET_ADDED);
+    assert_eq!(records[0].wallet, wallet);
+    assert_eq!(records[0].signer, context.auth.pubkey());
+    assert_eq!(records[1].operation, AUDIT_WALLET_REMOVED);
+    assert_eq!(records[1].wallet, wallet);
+    assert!(records[1].slot >= records[0].slot);
+}
+
+#[tokio::test]
+async fn creates_audit_log_at_funded_address() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let (audit_log, _) = AuditLog::find_pda(&list);
+    let res = context.vm.airdrop(&audit_log, 1);
+    assert!(res.is_ok());
+
+    let _ = create_audit_log(&mut context, &list);
+
+    let log = get_audit_log(&context, &audit_log);
+    assert_eq!(log.list_config, list);
+
+    let account = context.vm.get_account(&audit_log).unwrap();
+    assert_eq!(
+        account.lamports,
+        context
+            .vm
+            .minimum_balance_for_rent_exemption(account.data.len())
+    );
+}
+
+#[tokio::test]
+async fn overwrites_oldest_records_when_full() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let audit_log = create_audit_log(&mut context, &list);
+
+    let wallets: Vec<Pubkey> = (0..AUDIT_LOG_CAPACITY + 2)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    for wallet in &wallets {
+        assert!(add_wallet(&mut context, &list, wallet, Some(audit_log)));
+    }
+
+    let log = get_audit_log(&context, &audit_log);
+    assert_eq!(log.total_records, wallets.len() as u64);
+
+    let records = log.recent_records();
+    assert_eq!(records.len(), log.records.len());
+    assert_eq!(records[0].wallet, wallets[2]);
+    assert_eq!(records.last().unwrap().wallet, *wallets.last().unwrap());
+}
+
+#[tokio::test]
+async fn requires_audit_log_for_changes() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let audit_log = create_audit_log(&mut context, &list);
+
+    let wallet = Pubkey::new_unique();
+    assert!(!add_wallet(&mut context, &list, &wallet, None));
+
+    // the audit log of another list is rejected too
+    let other_list = context.create_list(Mode::Block);
+    let other_audit_log = create_audit_log(&mut context, &other_list);
+    assert!(!add_wallet(
+        &mut context,
+        &list,
+        &wallet,
+        Some(other_audit_log)
+    ));
+
+    assert!(add_wallet(&mut context, &list, &wallet, Some(audit_log)));
+    assert!(!remove_wallet(&mut context, &list, &wallet, None));
+}
+
+#[tokio::test]
+async fn fails_to_create_audit_log_for_bucket_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list_with_storage(Mode::Block, Storage::Buckets);
+    let (audit_log, _) = AuditLog::find_pda(&list);
+
+    let ix = allow_block_list_client::instructions::CreateAuditLogBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .audit_log(audit_log)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn deletes_audit_log_with_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let audit_log = create_audit_log(&mut context, &list);
+
+    let ix = allow_block_list_client::instructions::DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .audit_log(Some(audit_log))
+        .list_registry(
+            allow_block_list_client::accounts::ListRegistry::find_pda(&context.auth.pubkey()).0,
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_ok());
+
+    let account = context.vm.get_account(&audit_log).unwrap();
+    assert_eq!(account.data.len(), 0);
+    assert_eq!(account.lamports, 0);
+}
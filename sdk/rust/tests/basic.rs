@@ -5,7 +5,7 @@ use allow_block_list_client::{
 };
 use solana_instruction::AccountMeta;
 use solana_pubkey::Pubkey;
-use solana_sdk::{signer::Signer, transaction::Transaction};
+use solana_sdk::{signature::Keypair, signer::Signer, transaction::Transaction};
 
 use crate::program_test::TestContext;
 
@@ -20,6 +20,9 @@ async fn creates_list() {
     let ix = allow_block_list_client::instructions::CreateListBuilder::new()
         .authority(context.auth.pubkey())
         .list_config(list_config_address)
+        .list_registry(
+            allow_block_list_client::accounts::ListRegistry::find_pda(&context.auth.pubkey()).0,
+        )
         .mode(Mode::Allow)
         .seed(seed)
         .instruction();
@@ -72,6 +75,9 @@ async fn deletes_list() {
     let ix = allow_block_list_client::instructions::DeleteListBuilder::new()
         .authority(context.auth.pubkey())
         .list_config(list_config_address)
+        .list_registry(
+            allow_block_list_client::accounts::ListRegistry::find_pda(&context.auth.pubkey()).0,
+        )
         .instruction();
 
     let tx = Transaction::new_signed_with_payer(
@@ -90,6 +96,35 @@ async fn deletes_list() {
     assert_eq!(list_config.lamports, 0);
 }
 
+#[tokio::test]
+async fn fails_to_delete_list_of_other_authority() {
+    let mut context = TestContext::new();
+
+    let list_config_address = context.create_list(Mode::Allow);
+
+    let other = Keypair::new();
+    let _ = context.vm.airdrop(&other.pubkey(), 1_000_000_000);
+
+    let ix = allow_block_list_client::instructions::DeleteListBuilder::new()
+        .authority(other.pubkey())
+        .list_config(list_config_address)
+        .list_registry(allow_block_list_client::accounts::ListRegistry::find_pda(&other.pubkey()).0)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&other.pubkey()),
+        &[other.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let list_config = context.vm.get_account(&list_config_address).unwrap();
+    assert_eq!(list_config.data.len(), ListConfig::LEN);
+}
+
 #[tokio::test]
 async fn adds_wallet() {
     let mut context = TestContext::new();
@@ -165,6 +200,33 @@ async fn removes_wallet() {
     assert_eq!(wallet_entry.lamports, 0);
 }
 
+#[tokio::test]
+async fn fails_to_remove_list_as_wallet_entry() {
+    let mut context = TestContext::new();
+
+    let list_config_address = context.create_list(Mode::Allow);
+    let other_list_address = context.create_list(Mode::Allow);
+
+    let ix = allow_block_list_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .wallet_entry(other_list_address)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+
+    let res = context.vm.send_transaction(tx);
+    assert!(res.is_err());
+
+    let other_list = context.vm.get_account(&other_list_address).unwrap();
+    assert!(ListConfig::from_bytes(&other_list.data).is_ok());
+}
+
 #[tokio::test]
 async fn setup_list_extra_metas() {
     let mut context = TestContext::new();
@@ -0,0 +1,163 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{Bucket, ListConfig, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{AddWalletBuilder, RemoveBucketWalletsBuilder};
+use allow_block_list_client::types::{Mode, Storage};
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn wallet_with_prefix(prefix: u8) -> Pubkey {
+    let mut bytes = Pubkey::new_unique().to_bytes();
+    bytes[0] = prefix;
+    Pubkey::new_from_array(bytes)
+}
+
+#[tokio::test]
+async fn adds_wallets_to_bucket_sorted() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list_with_storage(Mode::Block, Storage::Buckets);
+
+    let wallets = [
+        wallet_with_prefix(7),
+        wallet_with_prefix(7),
+        wallet_with_prefix(7),
+    ];
+    let bucket = context.add_wallets_to_bucket(&list, &wallets[..2]);
+    let _ = context.add_wallets_to_bucket(&list, &wallets[2..]);
+
+    let bucket_account = context.vm.get_account(&bucket).unwrap();
+    let bucket_data = Bucket::from_bytes(&bucket_account.data).unwrap();
+    assert_eq!(bucket_data.list_config, list);
+    assert_eq!(bucket_data.prefix, 7);
+
+    let mut expected = wallets.to_vec();
+    expected.sort();
+    assert_eq!(bucket_data.wallets.to_vec(), expected);
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.wallets_count, 3);
+    assert_eq!(list_data.storage, Storage::Buckets as u8);
+}
+
+#[tokio::test]
+async fn removes_wallets_and_closes_empty_bucket() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list_with_storage(Mode::Block, Storage::Buckets);
+    let wallets = [wallet_with_prefix(42), wallet_with_prefix(42)];
+    let bucket = context.add_wallets_to_bucket(&list, &wallets);
+
+    for wallet in wallets {
+        let ix = RemoveBucketWalletsBuilder::new()
+            .authority(context.auth.pubkey())
+            .list_config(list)
+            .bucket(bucket)
+            .wallets(vec![wallet].into())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&context.auth.pubkey()),
+            &[context.auth.insecure_clone()],
+            context.vm.latest_blockhash(),
+        );
+        let res = context.vm.send_transaction(tx);
+        assert!(res.is_ok());
+    }
+
+    let bucket_account = context.vm.get_account(&bucket);
+    assert!(bucket_account.is_none() || bucket_account.unwrap().lamports == 0);
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.wallets_count, 0);
+}
+
+#[tokio::test]
+async fn fails_to_add_wallet_entry_to_bucket_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list_with_storage(Mode::Block, Storage::Buckets);
+    let wallet = Pubkey::new_unique();
+    let (wallet_entry, _) = WalletEntry::find_pda(&list, &wallet);
+
+    let ix = AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .wallet(wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidStorage as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_thaw_wallet_in_block_bucket() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list_with_storage(Mode::Block, Storage::Buckets);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let blocked = wallet_with_prefix(1);
+    let _ = context.add_wallets_to_bucket(&list, &[blocked]);
+    let ta = context.create_token_account_from_pubkey(&blocked);
+
+    let res = context.thaw_permissionless(&blocked, &ta).await;
+    assert!(res.is_err());
+
+    // same bucket, not listed
+    let wallet = wallet_with_prefix(1);
+    let ta = context.create_token_account_from_pubkey(&wallet);
+
+    let res = context.thaw_permissionless(&wallet, &ta).await;
+    assert!(res.is_ok());
+
+    // bucket never created
+    let wallet = wallet_with_prefix(2);
+    let ta = context.create_token_account_from_pubkey(&wallet);
+
+    let res = context.thaw_permissionless(&wallet, &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn thaws_wallet_in_allow_bucket() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list_with_storage(Mode::Allow, Storage::Buckets);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let _ = context.add_wallets_to_bucket(&list, &[wallet.pubkey()]);
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+
+    let other = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&other);
+
+    let res = context.thaw_permissionless(&other.pubkey(), &ta).await;
+    assert!(res.is_err());
+}
@@ -0,0 +1,118 @@
+pub mod program_test;
+use allow_block_list_client::{
+    accounts::{ListConfig, ListRegistry, WalletEntry},
+    errors::AblError,
+    instructions::{AddWalletBuilder, CreateListBuilder, IsWalletPermittedBuilder},
+    types::Mode,
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+use crate::program_test::{assert_error, TestContext};
+
+/// Returns the address of the highest bump below `canonical_bump` that is
+/// still valid through `create_pda`.
+fn non_canonical_pda<E>(
+    canonical_bump: u8,
+    create_pda: impl Fn(u8) -> Result<Pubkey, E>,
+) -> Option<Pubkey> {
+    (0..canonical_bump)
+        .rev()
+        .find_map(|bump| create_pda(bump).ok())
+}
+
+/// Returns a new wallet whose entry in `list` has the canonical `bump`.
+fn wallet_with_entry_bump(list: &Pubkey, bump: u8) -> Pubkey {
+    loop {
+        let wallet = Pubkey::new_unique();
+        if WalletEntry::find_pda(list, &wallet).1 == bump {
+            return wallet;
+        }
+    }
+}
+
+fn query_compute_units(context: &mut TestContext, list: &Pubkey, wallet: &Pubkey) -> u64 {
+    let ix = IsWalletPermittedBuilder::new()
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(Some(WalletEntry::find_pda(list, wallet).0))
+        .instruction();
+    context.send(ix).unwrap().compute_units_consumed
+}
+
+#[tokio::test]
+async fn checks_wallet_entries_without_searching_their_bump() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let first_bump_wallet = wallet_with_entry_bump(&list, 255);
+    let sixth_bump_wallet = wallet_with_entry_bump(&list, 250);
+    let _ = context.add_wallet_to_list(&list, &first_bump_wallet);
+    let _ = context.add_wallet_to_list(&list, &sixth_bump_wallet);
+
+    let first_bump = query_compute_units(&mut context, &list, &first_bump_wallet);
+    let sixth_bump = query_compute_units(&mut context, &list, &sixth_bump_wallet);
+
+    println!(
+        "IsWalletPermitted: {first_bump} CUs for an entry with bump 255, {sixth_bump} CUs with bump 250"
+    );
+    // searching the bump would derive five more addresses, 1500 CUs each
+    assert!(sixth_bump < first_bump + 1500);
+}
+
+#[tokio::test]
+async fn stores_canonical_wallet_entry_bumps() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet);
+
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(entry.bump, WalletEntry::find_pda(&list, &wallet).1);
+}
+
+#[tokio::test]
+async fn fails_with_non_canonical_address() {
+    let mut context = TestContext::new();
+    let auth = context.auth.insecure_clone();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list, &wallet);
+
+    // another valid address of the same seeds would hold a second entry of the wallet
+    let (_, canonical_bump) = WalletEntry::find_pda(&list, &wallet);
+    let wallet_entry = non_canonical_pda(canonical_bump, |bump| {
+        WalletEntry::create_pda(list, wallet, bump)
+    })
+    .unwrap();
+    let ix = AddWalletBuilder::new()
+        .authority(auth.pubkey())
+        .list_config(list)
+        .wallet(wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    assert_error(context.send_as(ix, &auth), AblError::InvalidBump);
+    assert!(context.vm.get_account(&wallet_entry).is_none());
+
+    let account = context.vm.get_account(&list).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, 1);
+
+    let seed = Pubkey::new_unique();
+    let (_, canonical_bump) = ListConfig::find_pda(&auth.pubkey(), &seed);
+    let list = non_canonical_pda(canonical_bump, |bump| {
+        ListConfig::create_pda(auth.pubkey(), seed, bump)
+    })
+    .unwrap();
+    let ix = CreateListBuilder::new()
+        .authority(auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&auth.pubkey()).0)
+        .mode(Mode::Block)
+        .seed(seed)
+        .instruction();
+    assert_error(context.send_as(ix, &auth), AblError::InvalidBump);
+}
@@ -0,0 +1,74 @@
+pub mod program_test;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::return_data::{DenialReason, ThawDenial};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+fn assert_denied(res: TransactionResult, list_index: u8, reason: DenialReason) {
+    let failure = res.unwrap_err();
+
+    assert_eq!(
+        failure.err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::from(reason) as u32)
+        )
+    );
+
+    let return_data = failure.meta.return_data;
+    let denial = ThawDenial::from_return_data(&return_data.program_id, &return_data.data);
+    assert_eq!(denial, Some(ThawDenial { list_index, reason }));
+}
+
+#[tokio::test]
+async fn reports_blocked_wallet() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list_config = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list_config]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let _ = context.add_wallet_to_list(&list_config, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_denied(res, 0, DenialReason::OnBlockList);
+}
+
+#[tokio::test]
+async fn reports_failing_list_index_in_composite_lists() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let allow_all_eoas_list = context.create_list(Mode::AllowAllEoas);
+    let allow_list = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[allow_all_eoas_list, allow_list]);
+
+    let wallet = solana_keypair::Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_denied(res, 1, DenialReason::NotOnAllowList);
+}
+
+#[tokio::test]
+async fn reports_non_eoa_wallet() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let block_list = context.create_list(Mode::Block);
+    let allow_all_eoas_list = context.create_list(Mode::AllowAllEoas);
+    let _ = context.setup_extra_metas(&[block_list, allow_all_eoas_list]);
+
+    // block_list is acting as the ta owner as it is an off-curve pubkey
+    let ta = context.create_token_account_from_pubkey(&block_list);
+
+    let res = context.thaw_permissionless(&block_list, &ta).await;
+    assert_denied(res, 1, DenialReason::NonEoaNotOnAllowList);
+}
@@ -0,0 +1,158 @@
+pub mod program_test;
+use allow_block_list_client::{
+    events::{parse_events, AblEvent},
+    types::{Mode, Storage},
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::{signer::Signer, transaction::Transaction};
+
+use crate::program_test::TestContext;
+
+#[tokio::test]
+async fn emits_list_created_event() {
+    let mut context = TestContext::new();
+
+    let seed = Pubkey::new_unique();
+    let (list_config_address, _) =
+        allow_block_list_client::accounts::ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let ix = allow_block_list_client::instructions::CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .list_registry(
+            allow_block_list_client::accounts::ListRegistry::find_pda(&context.auth.pubkey()).0,
+        )
+        .mode(Mode::Block)
+        .seed(seed)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx).unwrap();
+
+    assert_eq!(
+        parse_events(&res.logs),
+        vec![AblEvent::ListCreated {
+            list: list_config_address,
+            authority: context.auth.pubkey(),
+            mint: Pubkey::default(),
+            mode: Mode::Block as u8,
+            storage: Storage::Entries as u8,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn emits_wallet_events() {
+    let mut context = TestContext::new();
+
+    let wallet_address = Pubkey::new_unique();
+    let list_config_address = context.create_list(Mode::Allow);
+    let (wallet_entry, _) = allow_block_list_client::accounts::WalletEntry::find_pda(
+        &list_config_address,
+        &wallet_address,
+    );
+
+    let ix = allow_block_list_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .wallet(wallet_address)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx).unwrap();
+
+    assert_eq!(
+        parse_events(&res.logs),
+        vec![AblEvent::WalletAdded {
+            list: list_config_address,
+            wallet: wallet_address,
+            signer: context.auth.pubkey(),
+        }]
+    );
+
+    let ix = allow_block_list_client::instructions::RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx).unwrap();
+
+    assert_eq!(
+        parse_events(&res.logs),
+        vec![AblEvent::WalletRemoved {
+            list: list_config_address,
+            wallet: wallet_address,
+            signer: context.auth.pubkey(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn emits_list_deleted_event() {
+    let mut context = TestContext::new();
+
+    let list_config_address = context.create_list(Mode::Allow);
+
+    let ix = allow_block_list_client::instructions::DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config_address)
+        .list_registry(
+            allow_block_list_client::accounts::ListRegistry::find_pda(&context.auth.pubkey()).0,
+        )
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx).unwrap();
+
+    assert_eq!(
+        parse_events(&res.logs),
+        vec![AblEvent::ListDeleted {
+            list: list_config_address,
+        }]
+    );
+}
+
+#[test]
+fn ignores_data_of_other_programs() {
+    let other = Pubkey::new_unique();
+    let abl = allow_block_list_client::programs::ABL_ID;
+    let logs = [
+        format!("Program {abl} invoke [1]"),
+        format!("Program {other} invoke [2]"),
+        // [version, ListDeleted] followed by a zero list key
+        format!("Program data: AQI= {}", "A".repeat(43) + "="),
+        format!("Program {other} success"),
+        format!("Program {abl} success"),
+    ];
+
+    assert!(parse_events(&logs).is_empty());
+    assert_eq!(
+        AblEvent::from_log(&logs[2]),
+        Some(AblEvent::ListDeleted {
+            list: Pubkey::default(),
+        })
+    );
+}
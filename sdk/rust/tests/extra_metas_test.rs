@@ -0,0 +1,205 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, ListRegistry};
+use allow_block_list_client::instructions::{CreateListBuilder, SetAttributePolicyBuilder};
+use allow_block_list_client::types::{Mode, Storage};
+use solana_pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::Transaction;
+use spl_discriminator::SplDiscriminate;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
+};
+use token_acl_interface::instruction::CanThawPermissionlessInstruction;
+
+use crate::program_test::TestContext;
+
+/// Offset of the identity id in a wallet entry.
+const IDENTITY_OFFSET: u8 = 1 + 32 + 32 + 1 + 1;
+/// Offset of the attributes source in a list config.
+const ATTRIBUTES_SOURCE_OFFSET: u8 = 1 + 32 + 32 + 1 + 8 + 1 + 1 + 1 + 1 + 8 + 8;
+
+#[derive(SplDiscriminate)]
+#[discriminator_hash_input("spl-transfer-hook-interface:execute")]
+pub struct ExecuteInstruction;
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Entries,
+    Buckets,
+    Identities,
+    Attributes,
+}
+
+fn create_list(context: &mut TestContext, kind: Kind) -> Pubkey {
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let mut builder = CreateListBuilder::new();
+    builder
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .seed(seed);
+    match kind {
+        Kind::Entries => builder.mode(Mode::Block),
+        Kind::Buckets => builder.mode(Mode::Block).storage(Storage::Buckets),
+        Kind::Identities => builder.mode(Mode::Allow).identities(true),
+        Kind::Attributes => builder.mode(Mode::Attributes),
+    };
+
+    let mut ixs = vec![builder.instruction()];
+    if let Kind::Attributes = kind {
+        ixs.push(
+            SetAttributePolicyBuilder::new()
+                .authority(context.auth.pubkey())
+                .list_config(list)
+                .attributes_source(context.create_list(Mode::Allow))
+                .required_attributes(1)
+                .instruction(),
+        );
+    }
+
+    let tx = Transaction::new_signed_with_payer(
+        &ixs,
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+
+    list
+}
+
+/// Meta of the wallet block of the owner of the token account at `token_account_index`,
+/// built with spl-tlv-account-resolution.
+fn wallet_meta(kind: Kind, list_index: u8, token_account_index: u8) -> ExtraAccountMeta {
+    let owner = |length| Seed::AccountData {
+        account_index: token_account_index,
+        data_index: 32,
+        length,
+    };
+    let seeds = match kind {
+        Kind::Buckets => vec![
+            Seed::Literal {
+                bytes: b"bucket".to_vec(),
+            },
+            Seed::AccountKey { index: list_index },
+            owner(1),
+        ],
+        Kind::Attributes => vec![
+            Seed::Literal {
+                bytes: b"wallet_entry".to_vec(),
+            },
+            Seed::AccountData {
+                account_index: list_index,
+                data_index: ATTRIBUTES_SOURCE_OFFSET,
+                length: 32,
+            },
+            owner(32),
+        ],
+        Kind::Entries | Kind::Identities => vec![
+            Seed::Literal {
+                bytes: b"wallet_entry".to_vec(),
+            },
+            Seed::AccountKey { index: list_index },
+            owner(32),
+        ],
+    };
+    ExtraAccountMeta::new_with_seeds(&seeds, false, false).unwrap()
+}
+
+fn identity_meta(list_index: u8, wallet_entry_index: u8) -> ExtraAccountMeta {
+    ExtraAccountMeta::new_with_seeds(
+        &[
+            Seed::Literal {
+                bytes: b"identity".to_vec(),
+            },
+            Seed::AccountKey { index: list_index },
+            Seed::AccountData {
+                account_index: wallet_entry_index,
+                data_index: IDENTITY_OFFSET,
+                length: 32,
+            },
+        ],
+        false,
+        false,
+    )
+    .unwrap()
+}
+
+/// Extra metas account data the library writes for `metas`.
+fn expected_data<T: SplDiscriminate>(metas: &[ExtraAccountMeta]) -> Vec<u8> {
+    let mut data = vec![0; ExtraAccountMetaList::size_of(metas.len()).unwrap()];
+    ExtraAccountMetaList::init::<T>(&mut data, metas).unwrap();
+    data
+}
+
+const KINDS: [Kind; 4] = [
+    Kind::Entries,
+    Kind::Buckets,
+    Kind::Identities,
+    Kind::Attributes,
+];
+
+#[tokio::test]
+async fn writes_thaw_extra_metas_as_the_library() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let lists: Vec<(Pubkey, Kind)> = KINDS
+        .iter()
+        .map(|kind| (create_list(&mut context, *kind), *kind))
+        .collect();
+    let keys: Vec<Pubkey> = lists.iter().map(|(list, _)| *list).collect();
+    let extra_metas = context.setup_extra_metas(&keys);
+
+    let mut metas = Vec::new();
+    for (list, kind) in lists {
+        let list_index = metas.len() as u8 + 5;
+        metas.push(ExtraAccountMeta::new_with_pubkey(&list, false, false).unwrap());
+        metas.push(wallet_meta(kind, list_index, 1));
+        if let Kind::Identities = kind {
+            metas.push(identity_meta(list_index, list_index + 1));
+        }
+    }
+
+    let account = context.vm.get_account(&extra_metas).unwrap();
+    assert_eq!(
+        account.data,
+        expected_data::<CanThawPermissionlessInstruction>(&metas)
+    );
+}
+
+#[tokio::test]
+async fn writes_transfer_hook_extra_metas_as_the_library() {
+    let mut context = TestContext::new();
+
+    let token = context.create_transfer_hook_token();
+    let lists: Vec<(Pubkey, Kind)> = KINDS
+        .iter()
+        .map(|kind| (create_list(&mut context, *kind), *kind))
+        .collect();
+    let keys: Vec<Pubkey> = lists.iter().map(|(list, _)| *list).collect();
+    assert!(context
+        .setup_transfer_hook_extra_metas(&token, &keys)
+        .is_ok());
+
+    let mut metas = Vec::new();
+    for (list, kind) in lists {
+        let list_index = metas.len() as u8 + 5;
+        metas.push(ExtraAccountMeta::new_with_pubkey(&list, false, false).unwrap());
+        metas.push(wallet_meta(kind, list_index, 0));
+        metas.push(wallet_meta(kind, list_index, 2));
+        if let Kind::Identities = kind {
+            metas.push(identity_meta(list_index, list_index + 1));
+            metas.push(identity_meta(list_index, list_index + 2));
+        }
+    }
+
+    let (extra_metas, _) = Pubkey::find_program_address(
+        &[b"extra-account-metas", token.mint.as_ref()],
+        &allow_block_list_client::programs::ABL_ID,
+    );
+    let account = context.vm.get_account(&extra_metas).unwrap();
+    assert_eq!(account.data, expected_data::<ExecuteInstruction>(&metas));
+}
@@ -0,0 +1,284 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{Identity, ListConfig, ListRegistry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::events::{parse_events, AblEvent};
+use allow_block_list_client::instructions::{
+    CreateIdentityBuilder, CreateListBuilder, ForceDeleteListBuilder, ReclaimWalletEntryBuilder,
+};
+use allow_block_list_client::types::{Mode, Storage};
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+/// Discriminator of a force deleted list.
+const TOMBSTONE_DISCRIMINATOR: u8 = 6;
+
+fn force_delete(context: &mut TestContext, list: &Pubkey) -> TransactionResult {
+    let ix = ForceDeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .instruction();
+    context.send(ix)
+}
+
+fn wallet_with_prefix(prefix: u8) -> Pubkey {
+    let mut bytes = Pubkey::new_unique().to_bytes();
+    bytes[0] = prefix;
+    Pubkey::new_from_array(bytes)
+}
+
+/// Reclaims `wallet_entry` of `list` with a transaction paid by a third party.
+fn reclaim(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet_entry: &Pubkey,
+    authority: &Pubkey,
+) -> TransactionResult {
+    let caller = Keypair::new();
+    let _ = context.vm.airdrop(&caller.pubkey(), 1_000_000_000);
+
+    let ix = ReclaimWalletEntryBuilder::new()
+        .list_config(*list)
+        .wallet_entry(*wallet_entry)
+        .authority(*authority)
+        .instruction();
+    context.send_as(ix, &caller)
+}
+
+#[tokio::test]
+async fn force_deletes_list_with_wallets() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let tombstone = context.vm.get_account(&list).unwrap();
+    assert_eq!(tombstone.data[0], TOMBSTONE_DISCRIMINATOR);
+    assert!(context.vm.get_account(&wallet_entry).is_some());
+
+    // the tombstone is no longer a list
+    let wallet = Pubkey::new_unique();
+    let ix = allow_block_list_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .wallet(wallet)
+        .wallet_entry(allow_block_list_client::accounts::WalletEntry::find_pda(&list, &wallet).0)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn force_deletes_empty_list_at_once() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let account = context.vm.get_account(&list).unwrap();
+    assert_eq!(account.data.len(), 0);
+    assert_eq!(account.lamports, 0);
+}
+
+#[tokio::test]
+async fn reclaims_entries_and_closes_tombstone() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let entries = [
+        context.add_wallet_to_list(&list, &Pubkey::new_unique()),
+        context.add_wallet_to_list(&list, &Pubkey::new_unique()),
+    ];
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let authority = context.auth.pubkey();
+    let before = context.vm.get_account(&authority).unwrap().lamports;
+    let entry_lamports = context.vm.get_account(&entries[0]).unwrap().lamports;
+
+    let res = reclaim(&mut context, &list, &entries[0], &authority);
+    assert!(res.is_ok());
+
+    assert_eq!(context.vm.get_account(&entries[0]).unwrap().lamports, 0);
+    assert_eq!(
+        context.vm.get_account(&authority).unwrap().lamports,
+        before + entry_lamports
+    );
+    let tombstone = context.vm.get_account(&list).unwrap();
+    assert_eq!(tombstone.data[0], TOMBSTONE_DISCRIMINATOR);
+
+    let res = reclaim(&mut context, &list, &entries[1], &authority);
+    assert!(res.is_ok());
+
+    let tombstone = context.vm.get_account(&list).unwrap();
+    assert_eq!(tombstone.data.len(), 0);
+    assert_eq!(tombstone.lamports, 0);
+}
+
+#[tokio::test]
+async fn reclaims_identities_and_closes_tombstone() {
+    let mut context = TestContext::new();
+
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+    let ix = CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .mode(Mode::Allow)
+        .seed(seed)
+        .identities(true)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    let id = Pubkey::new_unique();
+    let (identity, _) = Identity::find_pda(&list, &id);
+    let ix = CreateIdentityBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .identity(identity)
+        .id(id)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.identities_count, 1);
+
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let authority = context.auth.pubkey();
+    let res = reclaim(&mut context, &list, &wallet_entry, &authority);
+    assert!(res.is_ok());
+
+    // the identity keeps the tombstone
+    let tombstone = context.vm.get_account(&list).unwrap();
+    assert_eq!(tombstone.data[0], TOMBSTONE_DISCRIMINATOR);
+
+    let before = context.vm.get_account(&authority).unwrap().lamports;
+    let identity_lamports = context.vm.get_account(&identity).unwrap().lamports;
+    let tombstone_lamports = tombstone.lamports;
+
+    let res = reclaim(&mut context, &list, &identity, &authority);
+    assert!(res.is_ok());
+
+    assert_eq!(context.vm.get_account(&identity).unwrap().lamports, 0);
+    let tombstone = context.vm.get_account(&list).unwrap();
+    assert_eq!(tombstone.data.len(), 0);
+    assert_eq!(
+        context.vm.get_account(&authority).unwrap().lamports,
+        before + identity_lamports + tombstone_lamports
+    );
+}
+
+#[tokio::test]
+async fn reclaims_buckets() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list_with_storage(Mode::Block, Storage::Buckets);
+    let mut wallets = [wallet_with_prefix(9), wallet_with_prefix(9)];
+    wallets.sort();
+    let bucket = context.add_wallets_to_bucket(&list, &wallets);
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let authority = context.auth.pubkey();
+    let res = reclaim(&mut context, &list, &bucket, &authority);
+    assert_eq!(
+        parse_events(&res.unwrap().logs),
+        wallets
+            .iter()
+            .map(|wallet| AblEvent::WalletRemoved {
+                list,
+                wallet: *wallet,
+                signer: authority,
+            })
+            .collect::<Vec<_>>()
+    );
+
+    assert_eq!(context.vm.get_account(&bucket).unwrap().lamports, 0);
+    assert_eq!(context.vm.get_account(&list).unwrap().lamports, 0);
+}
+
+#[tokio::test]
+async fn fails_to_reclaim_from_live_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+
+    let authority = context.auth.pubkey();
+    let res = reclaim(&mut context, &list, &wallet_entry, &authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::ListNotDeleted as u32)
+        )
+    );
+
+    let account = context.vm.get_account(&list).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, 1);
+}
+
+#[tokio::test]
+async fn fails_to_reclaim_to_other_authority() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let res = reclaim(&mut context, &list, &wallet_entry, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_reclaim_entry_of_other_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let _ = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+    let other_list = context.create_list(Mode::Block);
+    let other_entry = context.add_wallet_to_list(&other_list, &Pubkey::new_unique());
+
+    let res = force_delete(&mut context, &list);
+    assert!(res.is_ok());
+
+    let authority = context.auth.pubkey();
+    let res = reclaim(&mut context, &list, &other_entry, &authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAccountData as u32)
+        )
+    );
+}
@@ -0,0 +1,297 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{
+    AddWalletBuilder, RemoveWalletBuilder, SetGuardianBuilder, SetListLimitsBuilder,
+    SetListPausedBuilder,
+};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::{assert_error, TestContext};
+
+fn set_guardian(context: &mut TestContext, list: &Pubkey) -> Keypair {
+    let guardian = Keypair::new();
+    let _ = context.vm.airdrop(&guardian.pubkey(), 1_000_000_000);
+
+    let ix = SetGuardianBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .guardian(guardian.pubkey())
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    guardian
+}
+
+fn guardian_add_wallet(
+    context: &mut TestContext,
+    guardian: &Keypair,
+    list: &Pubkey,
+    wallet: &Pubkey,
+) -> TransactionResult {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+    let ix = AddWalletBuilder::new()
+        .authority(guardian.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    context.send_as(ix, guardian)
+}
+
+fn guardian_remove_wallet(
+    context: &mut TestContext,
+    guardian: &Keypair,
+    list: &Pubkey,
+    wallet_entry: &Pubkey,
+    list_authority: Option<Pubkey>,
+) -> TransactionResult {
+    let ix = RemoveWalletBuilder::new()
+        .authority(guardian.pubkey())
+        .list_config(*list)
+        .wallet_entry(*wallet_entry)
+        .list_authority(list_authority)
+        .instruction();
+    context.send_as(ix, guardian)
+}
+
+fn guardian_set_list_paused(
+    context: &mut TestContext,
+    guardian: &Keypair,
+    list: &Pubkey,
+    paused: bool,
+) -> TransactionResult {
+    let ix = SetListPausedBuilder::new()
+        .authority(guardian.pubkey())
+        .list_config(*list)
+        .paused(paused)
+        .fail_open(false)
+        .instruction();
+    context.send_as(ix, guardian)
+}
+
+fn wallets_count(context: &TestContext, list: &Pubkey) -> u64 {
+    let list_account = context.vm.get_account(list).unwrap();
+    ListConfig::from_bytes(&list_account.data)
+        .unwrap()
+        .wallets_count
+}
+
+#[tokio::test]
+async fn guardian_blocks_wallets() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+    let guardian = set_guardian(&mut context, &list);
+
+    let wallet = Keypair::new();
+    let res = guardian_add_wallet(&mut context, &guardian, &list, &wallet.pubkey());
+    assert!(res.is_ok());
+    assert!(res
+        .unwrap()
+        .logs
+        .iter()
+        .any(|log| log.contains("Guardian blocked wallet")));
+    assert_eq!(wallets_count(&context, &list), 1);
+
+    let ta = context.create_token_account(&wallet);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletBlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn guardian_removes_wallets_from_allow_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let guardian = set_guardian(&mut context, &list);
+
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+    assert_eq!(wallets_count(&context, &list), 1);
+
+    let entry_rent = context.vm.get_account(&wallet_entry).unwrap().lamports;
+    let authority_lamports = context.vm.get_balance(&context.auth.pubkey()).unwrap();
+
+    let list_authority = Some(context.auth.pubkey());
+    let res = guardian_remove_wallet(
+        &mut context,
+        &guardian,
+        &list,
+        &wallet_entry,
+        list_authority,
+    );
+    assert!(res.is_ok());
+    assert_eq!(wallets_count(&context, &list), 0);
+    assert!(context.vm.get_account(&wallet_entry).is_none());
+
+    // the authority paid for the entry, so it gets the rent back
+    assert_eq!(
+        context.vm.get_balance(&context.auth.pubkey()).unwrap(),
+        authority_lamports + entry_rent
+    );
+}
+
+#[tokio::test]
+async fn fails_guardian_removal_without_list_authority() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let guardian = set_guardian(&mut context, &list);
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+
+    for list_authority in [None, Some(guardian.pubkey())] {
+        let res = guardian_remove_wallet(
+            &mut context,
+            &guardian,
+            &list,
+            &wallet_entry,
+            list_authority,
+        );
+        assert_eq!(
+            res.unwrap_err().err,
+            TransactionError::InstructionError(
+                0,
+                InstructionError::Custom(AblError::InvalidAuthority as u32)
+            )
+        );
+    }
+    assert_eq!(wallets_count(&context, &list), 1);
+}
+
+#[tokio::test]
+async fn guardian_cannot_make_permissive_changes() {
+    let mut context = TestContext::new();
+
+    let allow_list = context.create_list(Mode::Allow);
+    let allow_guardian = set_guardian(&mut context, &allow_list);
+    let res = guardian_add_wallet(
+        &mut context,
+        &allow_guardian,
+        &allow_list,
+        &Pubkey::new_unique(),
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+
+    let block_list = context.create_list(Mode::Block);
+    let block_guardian = set_guardian(&mut context, &block_list);
+    let wallet_entry = context.add_wallet_to_list(&block_list, &Pubkey::new_unique());
+    let list_authority = Some(context.auth.pubkey());
+    let res = guardian_remove_wallet(
+        &mut context,
+        &block_guardian,
+        &block_list,
+        &wallet_entry,
+        list_authority,
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+    assert_eq!(wallets_count(&context, &block_list), 1);
+}
+
+#[tokio::test]
+async fn guardian_is_exempt_from_change_limit() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let guardian = set_guardian(&mut context, &list);
+
+    let ix = SetListLimitsBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .max_changes_per_epoch(1)
+        .allow_delay(0)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    // the authority uses up the limit
+    let _ = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+    let wallet = Pubkey::new_unique();
+    let (wallet_entry, _) = WalletEntry::find_pda(&list, &wallet);
+    let ix = AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .wallet(wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    assert_error(context.send(ix), AblError::RateLimitExceeded);
+
+    for _ in 0..3 {
+        let res = guardian_add_wallet(&mut context, &guardian, &list, &Pubkey::new_unique());
+        assert!(res.is_ok());
+    }
+    let res = guardian_set_list_paused(&mut context, &guardian, &list, true);
+    assert!(res.is_ok());
+    assert_eq!(wallets_count(&context, &list), 4);
+}
+
+#[tokio::test]
+async fn guardian_pauses_list_but_cannot_resume_it() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let guardian = set_guardian(&mut context, &list);
+
+    let res = guardian_set_list_paused(&mut context, &guardian, &list, true);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    assert!(ListConfig::from_bytes(&list_account.data).unwrap().paused);
+
+    let res = guardian_set_list_paused(&mut context, &guardian, &list, false);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn only_authority_sets_guardian() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let guardian = set_guardian(&mut context, &list);
+
+    let ix = SetGuardianBuilder::new()
+        .authority(guardian.pubkey())
+        .list_config(list)
+        .guardian(Pubkey::new_unique())
+        .instruction();
+    let res = context.send_as(ix, &guardian);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+}
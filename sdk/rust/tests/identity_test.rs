@@ -0,0 +1,237 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{Identity, ListConfig, ListRegistry, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{
+    CreateIdentityBuilder, CreateListBuilder, IsWalletPermittedBuilder, LinkWalletIdentityBuilder,
+    SetIdentityStatusBuilder,
+};
+use allow_block_list_client::return_data::{DenialReason, WalletVerdict};
+use allow_block_list_client::types::{IdentityStatus, Mode};
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+
+use crate::program_test::{assert_error, TestContext};
+
+fn create_identity_list(context: &mut TestContext, mode: Mode) -> (Pubkey, TransactionResult) {
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let ix = CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .mode(mode)
+        .seed(seed)
+        .identities(true)
+        .instruction();
+
+    let res = context.send(ix);
+    (list, res)
+}
+
+fn create_identity(context: &mut TestContext, list: &Pubkey) -> Pubkey {
+    let id = Pubkey::new_unique();
+    let (identity, _) = Identity::find_pda(list, &id);
+
+    let ix = CreateIdentityBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .identity(identity)
+        .id(id)
+        .instruction();
+
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    identity
+}
+
+fn link(context: &mut TestContext, list: &Pubkey, wallet_entry: &Pubkey, identity: &Pubkey) {
+    let ix = LinkWalletIdentityBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet_entry(*wallet_entry)
+        .identity(Some(*identity))
+        .instruction();
+
+    let res = context.send(ix);
+    assert!(res.is_ok());
+}
+
+fn set_status(context: &mut TestContext, list: &Pubkey, identity: &Pubkey, status: IdentityStatus) {
+    let ix = SetIdentityStatusBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .identity(*identity)
+        .status(status)
+        .instruction();
+
+    let res = context.send(ix);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn links_wallet_entry_to_identity() {
+    let mut context = TestContext::new();
+
+    let (list, res) = create_identity_list(&mut context, Mode::Allow);
+    assert!(res.is_ok());
+    let identity = create_identity(&mut context, &list);
+
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet);
+    link(&mut context, &list, &wallet_entry, &identity);
+
+    let identity_account = context.vm.get_account(&identity).unwrap();
+    let identity_data = Identity::from_bytes(&identity_account.data).unwrap();
+    assert_eq!(identity_data.list_config, list);
+    assert_eq!(identity_data.status, IdentityStatus::Allowed as u8);
+
+    let entry_account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry_data = WalletEntry::from_bytes(&entry_account.data).unwrap();
+    assert_eq!(entry_data.identity, identity_data.id);
+}
+
+#[tokio::test]
+async fn blocking_identity_applies_to_all_linked_wallets() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let (list, res) = create_identity_list(&mut context, Mode::Allow);
+    assert!(res.is_ok());
+    let _ = context.setup_extra_metas(&[list]);
+
+    let identity = create_identity(&mut context, &list);
+
+    let wallets = [Keypair::new(), Keypair::new()];
+    let mut token_accounts = vec![];
+    for wallet in &wallets {
+        let wallet_entry = context.add_wallet_to_list(&list, &wallet.pubkey());
+        link(&mut context, &list, &wallet_entry, &identity);
+        token_accounts.push(context.create_token_account(wallet));
+    }
+
+    // listed but not linked to any identity
+    let unlinked = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &unlinked.pubkey());
+    let unlinked_ta = context.create_token_account(&unlinked);
+
+    for (wallet, ta) in wallets.iter().zip(&token_accounts) {
+        let res = context.thaw_permissionless(&wallet.pubkey(), ta).await;
+        assert!(res.is_ok());
+    }
+
+    set_status(&mut context, &list, &identity, IdentityStatus::Blocked);
+
+    for (wallet, ta) in wallets.iter().zip(&token_accounts) {
+        let res = context.thaw_permissionless(&wallet.pubkey(), ta).await;
+        assert_error(res, AblError::IdentityBlocked);
+    }
+
+    let res = context
+        .thaw_permissionless(&unlinked.pubkey(), &unlinked_ta)
+        .await;
+    assert!(res.is_ok());
+
+    set_status(&mut context, &list, &identity, IdentityStatus::Allowed);
+
+    for (wallet, ta) in wallets.iter().zip(&token_accounts) {
+        let res = context.thaw_permissionless(&wallet.pubkey(), ta).await;
+        assert!(res.is_ok());
+    }
+}
+
+#[tokio::test]
+async fn unknown_identity_status_blocks_linked_wallets() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let (list, res) = create_identity_list(&mut context, Mode::Allow);
+    assert!(res.is_ok());
+    let _ = context.setup_extra_metas(&[list]);
+
+    let identity = create_identity(&mut context, &list);
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet.pubkey());
+    link(&mut context, &list, &wallet_entry, &identity);
+    let ta = context.create_token_account(&wallet);
+
+    let mut account = context.vm.get_account(&identity).unwrap();
+    account.data[1 + 32 + 32] = 2;
+    context.vm.set_account(identity, account).unwrap();
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_error(res, AblError::IdentityBlocked);
+}
+
+#[tokio::test]
+async fn unlisted_wallet_fails_to_resolve_identity() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let (list, res) = create_identity_list(&mut context, Mode::Allow);
+    assert!(res.is_ok());
+    let _ = context.setup_extra_metas(&[list]);
+
+    let unlisted = Keypair::new();
+    let unlisted_ta = context.create_token_account(&unlisted);
+
+    // the identity seed is read from the missing wallet entry
+    let (mint_config, _) = token_acl_client::accounts::MintConfig::find_pda(&context.token.mint);
+    let res = token_acl_client::create_thaw_permissionless_instruction_with_extra_metas(
+        &context.auth.pubkey(),
+        &unlisted_ta,
+        &context.token.mint,
+        &mint_config,
+        &spl_token_2022::ID,
+        &unlisted.pubkey(),
+        false,
+        |pubkey| {
+            let account = context.vm.get_account(&pubkey);
+
+            async move { Ok(account.map(|account| account.data)) }
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    // the reason is reported by IsWalletPermitted instead
+    let ix = IsWalletPermittedBuilder::new()
+        .list_config(list)
+        .wallet(unlisted.pubkey())
+        .instruction();
+    let meta = context.send(ix).unwrap();
+    let verdict =
+        WalletVerdict::from_return_data(&meta.return_data.program_id, &meta.return_data.data)
+            .unwrap();
+    assert_eq!(verdict, WalletVerdict::Denied(DenialReason::NotOnAllowList));
+}
+
+#[tokio::test]
+async fn fails_to_create_block_list_with_identities() {
+    let mut context = TestContext::new();
+
+    let (_, res) = create_identity_list(&mut context, Mode::Block);
+    assert_error(res, AblError::InvalidData);
+}
+
+#[tokio::test]
+async fn fails_to_create_identity_on_list_without_identities() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let id = Pubkey::new_unique();
+    let (identity, _) = Identity::find_pda(&list, &id);
+
+    let ix = CreateIdentityBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .identity(identity)
+        .id(id)
+        .instruction();
+
+    let res = context.send(ix);
+    assert_error(res, AblError::InvalidConfigAccount);
+}
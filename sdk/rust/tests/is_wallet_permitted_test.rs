@@ -0,0 +1,123 @@
+pub mod program_test;
+use allow_block_list_client::accounts::WalletEntry;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::IsWalletPermittedBuilder;
+use allow_block_list_client::return_data::{DenialReason, WalletVerdict};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn query(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    wallet_entry: Option<Pubkey>,
+) -> TransactionResult {
+    let ix = IsWalletPermittedBuilder::new()
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn verdict(res: TransactionResult) -> WalletVerdict {
+    let meta = res.unwrap();
+    WalletVerdict::from_return_data(&meta.return_data.program_id, &meta.return_data.data).unwrap()
+}
+
+#[tokio::test]
+async fn permits_allowed_wallet() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet);
+
+    let res = query(&mut context, &list, &wallet, Some(wallet_entry));
+    assert_eq!(verdict(res), WalletVerdict::Permitted);
+}
+
+#[tokio::test]
+async fn denies_wallet_missing_from_allow_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let wallet = Pubkey::new_unique();
+
+    let res = query(&mut context, &list, &wallet, None);
+    assert_eq!(
+        verdict(res),
+        WalletVerdict::Denied(DenialReason::NotOnAllowList)
+    );
+}
+
+#[tokio::test]
+async fn denies_blocked_wallet() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet);
+
+    let res = query(&mut context, &list, &wallet, Some(wallet_entry));
+    assert_eq!(
+        verdict(res),
+        WalletVerdict::Denied(DenialReason::OnBlockList)
+    );
+
+    let other_wallet = Pubkey::new_unique();
+    let (other_entry, _) = WalletEntry::find_pda(&list, &other_wallet);
+    let res = query(&mut context, &list, &other_wallet, Some(other_entry));
+    assert_eq!(verdict(res), WalletVerdict::Permitted);
+}
+
+#[tokio::test]
+async fn fails_block_list_query_without_entry() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+
+    let res = query(&mut context, &list, &wallet, None);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::NotEnoughAccounts as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_with_entry_of_another_wallet() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let blocked_wallet = Pubkey::new_unique();
+    let _ = context.add_wallet_to_list(&list, &blocked_wallet);
+
+    let wallet = Pubkey::new_unique();
+    let (unrelated_entry, _) = WalletEntry::find_pda(&list, &Pubkey::new_unique());
+
+    let res = query(&mut context, &list, &wallet, Some(unrelated_entry));
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAccountData as u32)
+        )
+    );
+}
@@ -0,0 +1,164 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, ListMetadata, ListRegistry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{DeleteListBuilder, UpdateListMetadataBuilder};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+fn padded<const N: usize>(text: &str) -> [u8; N] {
+    let mut padded = [0u8; N];
+    padded[..text.len()].copy_from_slice(text.as_bytes());
+    padded
+}
+
+fn update_list_metadata(
+    context: &mut TestContext,
+    list: &Pubkey,
+    name: &str,
+    uri: &str,
+    policy_hash: [u8; 32],
+) -> TransactionResult {
+    let ix = UpdateListMetadataBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .list_metadata(ListMetadata::find_pda(list).0)
+        .name(padded(name))
+        .uri(padded(uri))
+        .policy_hash(policy_hash)
+        .instruction();
+    context.send(ix)
+}
+
+fn delete_list(
+    context: &mut TestContext,
+    list: &Pubkey,
+    list_metadata: Option<Pubkey>,
+) -> TransactionResult {
+    let ix = DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .list_metadata(list_metadata)
+        .instruction();
+    context.send(ix)
+}
+
+fn metadata(context: &TestContext, list: &Pubkey) -> ListMetadata {
+    let account = context
+        .vm
+        .get_account(&ListMetadata::find_pda(list).0)
+        .unwrap();
+    ListMetadata::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn creates_list_metadata() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = update_list_metadata(
+        &mut context,
+        &list,
+        "sanctions",
+        "https://example.com/policy",
+        [7; 32],
+    );
+    assert!(res.is_ok());
+
+    let metadata = metadata(&context, &list);
+    assert_eq!(metadata.list_config, list);
+    assert_eq!(metadata.name, padded("sanctions"));
+    assert_eq!(metadata.uri, padded("https://example.com/policy"));
+    assert_eq!(metadata.policy_hash, [7; 32]);
+
+    let account = context.vm.get_account(&list).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert!(config.metadata);
+}
+
+#[tokio::test]
+async fn creates_list_metadata_at_funded_address() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let list_metadata = ListMetadata::find_pda(&list).0;
+    let res = context.vm.airdrop(&list_metadata, 1);
+    assert!(res.is_ok());
+
+    let res = update_list_metadata(&mut context, &list, "sanctions", "", [0; 32]);
+    assert!(res.is_ok());
+
+    let metadata = metadata(&context, &list);
+    assert_eq!(metadata.list_config, list);
+    assert_eq!(metadata.name, padded("sanctions"));
+
+    let account = context.vm.get_account(&list_metadata).unwrap();
+    assert_eq!(
+        account.lamports,
+        context
+            .vm
+            .minimum_balance_for_rent_exemption(account.data.len())
+    );
+}
+
+#[tokio::test]
+async fn updates_list_metadata() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = update_list_metadata(&mut context, &list, "draft", "", [0; 32]);
+    assert!(res.is_ok());
+    let res = update_list_metadata(
+        &mut context,
+        &list,
+        "sanctions",
+        "https://example.com/policy-v2",
+        [9; 32],
+    );
+    assert!(res.is_ok());
+
+    let metadata = metadata(&context, &list);
+    assert_eq!(metadata.name, padded("sanctions"));
+    assert_eq!(metadata.uri, padded("https://example.com/policy-v2"));
+    assert_eq!(metadata.policy_hash, [9; 32]);
+}
+
+#[tokio::test]
+async fn closes_metadata_with_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = update_list_metadata(&mut context, &list, "partners", "", [0; 32]);
+    assert!(res.is_ok());
+
+    let list_metadata = ListMetadata::find_pda(&list).0;
+    let res = delete_list(&mut context, &list, Some(list_metadata));
+    assert!(res.is_ok());
+
+    let metadata_account = context.vm.get_account(&list_metadata);
+    assert!(metadata_account.is_none() || metadata_account.unwrap().lamports == 0);
+}
+
+#[tokio::test]
+async fn fails_to_delete_list_without_metadata() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = update_list_metadata(&mut context, &list, "partners", "", [0; 32]);
+    assert!(res.is_ok());
+
+    let res = delete_list(&mut context, &list, None);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidListMetadata as u32)
+        )
+    );
+}
@@ -0,0 +1,216 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, ListRegistry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{CreateListBuilder, DeleteListBuilder};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+fn label(text: &str) -> [u8; 32] {
+    let mut label = [0u8; 32];
+    label[..text.len()].copy_from_slice(text.as_bytes());
+    label
+}
+
+/// Creates a list recorded in `list_registry` under `text`.
+fn create_registered_list(
+    context: &mut TestContext,
+    list_registry: &Pubkey,
+    mode: Mode,
+    text: &str,
+) -> (Pubkey, TransactionResult) {
+    let seed = Pubkey::new_unique();
+    let (list_config, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let ix = CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .list_registry(*list_registry)
+        .mode(mode)
+        .seed(seed)
+        .label(label(text))
+        .instruction();
+    (list_config, context.send(ix))
+}
+
+fn delete_list(
+    context: &mut TestContext,
+    list: &Pubkey,
+    list_registry: &Pubkey,
+) -> TransactionResult {
+    let ix = DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .list_registry(*list_registry)
+        .instruction();
+    context.send(ix)
+}
+
+fn registry(context: &TestContext, list_registry: &Pubkey) -> ListRegistry {
+    let account = context.vm.get_account(list_registry).unwrap();
+    ListRegistry::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn records_created_lists() {
+    let mut context = TestContext::new();
+
+    let list_registry = ListRegistry::find_pda(&context.auth.pubkey()).0;
+    let (allow_list, res) =
+        create_registered_list(&mut context, &list_registry, Mode::Allow, "partners");
+    assert!(res.is_ok());
+    let (block_list, res) =
+        create_registered_list(&mut context, &list_registry, Mode::Block, "sanctions");
+    assert!(res.is_ok());
+
+    let registry = registry(&context, &list_registry);
+    assert_eq!(registry.authority, context.auth.pubkey());
+    assert_eq!(registry.lists.len(), 2);
+    assert_eq!(registry.lists[0].list_config, allow_list);
+    assert_eq!(registry.lists[0].mode, Mode::Allow as u8);
+    assert_eq!(registry.lists[0].label, label("partners"));
+    assert_eq!(registry.lists[1].list_config, block_list);
+    assert_eq!(registry.lists[1].mode, Mode::Block as u8);
+    assert_eq!(registry.lists[1].label, label("sanctions"));
+}
+
+#[tokio::test]
+async fn creates_registry_at_funded_address() {
+    let mut context = TestContext::new();
+
+    // anyone can send lamports to the address before the registry is created
+    let list_registry = ListRegistry::find_pda(&context.auth.pubkey()).0;
+    let res = context.vm.airdrop(&list_registry, 1);
+    assert!(res.is_ok());
+
+    let (list, res) = create_registered_list(&mut context, &list_registry, Mode::Block, "a");
+    assert!(res.is_ok());
+
+    let registry = registry(&context, &list_registry);
+    assert_eq!(registry.lists.len(), 1);
+    assert_eq!(registry.lists[0].list_config, list);
+
+    let account = context.vm.get_account(&list_registry).unwrap();
+    assert_eq!(
+        account.lamports,
+        context
+            .vm
+            .minimum_balance_for_rent_exemption(account.data.len())
+    );
+}
+
+#[tokio::test]
+async fn removes_deleted_lists() {
+    let mut context = TestContext::new();
+
+    let list_registry = ListRegistry::find_pda(&context.auth.pubkey()).0;
+    let (first_list, _) = create_registered_list(&mut context, &list_registry, Mode::Allow, "a");
+    let (second_list, _) = create_registered_list(&mut context, &list_registry, Mode::Block, "b");
+    let lamports = context.vm.get_account(&list_registry).unwrap().lamports;
+
+    let res = delete_list(&mut context, &first_list, &list_registry);
+    assert!(res.is_ok());
+
+    let registry = registry(&context, &list_registry);
+    assert_eq!(registry.lists.len(), 1);
+    assert_eq!(registry.lists[0].list_config, second_list);
+    assert!(context.vm.get_account(&list_registry).unwrap().lamports < lamports);
+}
+
+#[tokio::test]
+async fn leaves_unregistered_lists_out() {
+    let mut context = TestContext::new();
+
+    let list_registry = ListRegistry::find_pda(&context.auth.pubkey()).0;
+    let (registered_list, _) =
+        create_registered_list(&mut context, &list_registry, Mode::Allow, "a");
+
+    // a list created before every list was registered
+    let unregistered_list = Pubkey::new_unique();
+    let mut account = context.vm.get_account(&registered_list).unwrap();
+    account.data[1 + 32 + 32] = Mode::Block as u8;
+    context.vm.set_account(unregistered_list, account).unwrap();
+
+    let registry_before = registry(&context, &list_registry);
+    assert_eq!(registry_before.lists.len(), 1);
+
+    let res = delete_list(&mut context, &unregistered_list, &list_registry);
+    assert!(res.is_ok());
+
+    let registry_after = registry(&context, &list_registry);
+    assert_eq!(registry_after, registry_before);
+    assert_eq!(registry_after.lists[0].list_config, registered_list);
+}
+
+#[tokio::test]
+async fn fails_to_record_in_other_registry() {
+    let mut context = TestContext::new();
+
+    let other_registry = ListRegistry::find_pda(&Pubkey::new_unique()).0;
+    let (_, res) = create_registered_list(&mut context, &other_registry, Mode::Allow, "a");
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidListRegistry as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_create_list_without_registry() {
+    let mut context = TestContext::new();
+
+    let list_registry = ListRegistry::find_pda(&context.auth.pubkey()).0;
+    let seed = Pubkey::new_unique();
+    let (list_config, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let mut ix = CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list_config)
+        .list_registry(list_registry)
+        .mode(Mode::Allow)
+        .seed(seed)
+        .instruction();
+    ix.accounts.pop();
+
+    let res = context.send(ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::NotEnoughAccounts as u32)
+        )
+    );
+    assert!(context.vm.get_account(&list_config).is_none());
+}
+
+#[tokio::test]
+async fn fails_to_delete_list_without_registry() {
+    let mut context = TestContext::new();
+
+    let list_registry = ListRegistry::find_pda(&context.auth.pubkey()).0;
+    let (list, _) = create_registered_list(&mut context, &list_registry, Mode::Allow, "a");
+
+    let mut ix = DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(list_registry)
+        .instruction();
+    ix.accounts.truncate(4);
+
+    let res = context.send(ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::NotEnoughAccounts as u32)
+        )
+    );
+    assert_eq!(registry(&context, &list_registry).lists.len(), 1);
+}
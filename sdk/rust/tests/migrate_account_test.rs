@@ -0,0 +1,240 @@
+pub mod program_test;
+use allow_block_list_client::{
+    accounts::{ListConfig, ListRegistry, WalletEntry},
+    instructions::{IsWalletPermittedBuilder, MigrateAccountBuilder},
+    programs::ABL_ID,
+    return_data::{DenialReason, WalletVerdict},
+    types::Mode,
+};
+use solana_pubkey::Pubkey;
+use solana_sdk::{account::Account, signer::Signer, transaction::Transaction};
+
+use crate::program_test::TestContext;
+
+/// Sets up a list config with the original layout, before any field was appended.
+fn set_v1_list(context: &mut TestContext, mode: Mode, wallets_count: u64) -> Pubkey {
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let mut data = vec![1];
+    data.extend_from_slice(context.auth.pubkey().as_ref());
+    data.extend_from_slice(seed.as_ref());
+    data.push(mode as u8);
+    data.extend_from_slice(&wallets_count.to_le_bytes());
+
+    set_program_account(context, &list, data);
+    list
+}
+
+/// Sets up a wallet entry with the original layout.
+fn set_v1_wallet_entry(context: &mut TestContext, list: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+
+    let mut data = vec![2];
+    data.extend_from_slice(wallet.as_ref());
+    data.extend_from_slice(list.as_ref());
+
+    set_program_account(context, &wallet_entry, data);
+    wallet_entry
+}
+
+fn set_program_account(context: &mut TestContext, address: &Pubkey, data: Vec<u8>) {
+    let lamports = context.vm.minimum_balance_for_rent_exemption(data.len());
+    context
+        .vm
+        .set_account(
+            *address,
+            Account {
+                lamports,
+                data,
+                owner: ABL_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+}
+
+fn migrate(context: &mut TestContext, account: &Pubkey) -> bool {
+    let ix = MigrateAccountBuilder::new()
+        .payer(context.auth.pubkey())
+        .account(*account)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx).is_ok()
+}
+
+fn verdict(context: &mut TestContext, list: &Pubkey, wallet: &Pubkey) -> WalletVerdict {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+
+    let ix = IsWalletPermittedBuilder::new()
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(Some(wallet_entry))
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let meta = context.vm.send_transaction(tx).unwrap();
+    WalletVerdict::from_return_data(&meta.return_data.program_id, &meta.return_data.data).unwrap()
+}
+
+#[tokio::test]
+async fn checks_wallets_against_older_layouts() {
+    let mut context = TestContext::new();
+
+    let list = set_v1_list(&mut context, Mode::Block, 1);
+    let wallet = Pubkey::new_unique();
+    set_v1_wallet_entry(&mut context, &list, &wallet);
+
+    assert_eq!(
+        verdict(&mut context, &list, &wallet),
+        WalletVerdict::Denied(DenialReason::OnBlockList)
+    );
+    assert_eq!(
+        verdict(&mut context, &list, &Pubkey::new_unique()),
+        WalletVerdict::Permitted
+    );
+}
+
+#[tokio::test]
+async fn migrates_list_config() {
+    let mut context = TestContext::new();
+
+    let list = set_v1_list(&mut context, Mode::Allow, 0);
+
+    // list changes need the current layout
+    let wallet = Pubkey::new_unique();
+    let (wallet_entry, _) = WalletEntry::find_pda(&list, &wallet);
+    let ix = allow_block_list_client::instructions::AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .wallet(wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_err());
+
+    assert!(migrate(&mut context, &list));
+
+    let account = context.vm.get_account(&list).unwrap();
+    assert_eq!(account.data.len(), ListConfig::LEN);
+    let rent = context
+        .vm
+        .minimum_balance_for_rent_exemption(ListConfig::LEN);
+    assert!(account.lamports >= rent);
+
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.authority, context.auth.pubkey());
+    assert_eq!(config.version, 2);
+    assert_eq!(config.mode, Mode::Allow as u8);
+    assert_eq!(config.wallets_count, 0);
+    assert_eq!(config.mint, Pubkey::default());
+    assert!(!config.paused);
+
+    context.add_wallet_to_list(&list, &wallet);
+
+    // migrating a current account is a no-op
+    assert!(migrate(&mut context, &list));
+    let account = context.vm.get_account(&list).unwrap();
+    assert_eq!(account.data.len(), ListConfig::LEN);
+}
+
+#[tokio::test]
+async fn migrates_wallet_entry() {
+    let mut context = TestContext::new();
+
+    let list = set_v1_list(&mut context, Mode::Block, 1);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = set_v1_wallet_entry(&mut context, &list, &wallet);
+
+    assert!(migrate(&mut context, &wallet_entry));
+
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    assert_eq!(account.data.len(), WalletEntry::LEN);
+
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(entry.wallet_address, wallet);
+    assert_eq!(entry.list_config, list);
+    assert_eq!(entry.version, 2);
+    assert_eq!(entry.score, 0);
+    assert_eq!(entry.identity, Pubkey::default());
+    assert_eq!(entry.active_from, 0);
+    assert_eq!(entry.bump, WalletEntry::find_pda(&list, &wallet).1);
+
+    assert_eq!(
+        verdict(&mut context, &list, &wallet),
+        WalletVerdict::Denied(DenialReason::OnBlockList)
+    );
+}
+
+#[tokio::test]
+async fn fails_to_migrate_unknown_layout() {
+    let mut context = TestContext::new();
+
+    let account = Pubkey::new_unique();
+    set_program_account(&mut context, &account, vec![1; 100]);
+
+    assert!(!migrate(&mut context, &account));
+}
+
+#[tokio::test]
+async fn fails_to_read_unknown_version() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let wallet = Pubkey::new_unique();
+    context.add_wallet_to_list(&list, &wallet);
+
+    // a later layout of the same length, which this program can't read
+    let mut account = context.vm.get_account(&list).unwrap();
+    account.data[1 + 32 + 32 + 1 + 8] = 3;
+    context.vm.set_account(list, account).unwrap();
+
+    assert!(!migrate(&mut context, &list));
+    assert_eq!(
+        verdict(&mut context, &list, &wallet),
+        WalletVerdict::Denied(DenialReason::InvalidListAccount)
+    );
+}
+
+#[tokio::test]
+async fn fails_to_create_list_with_invalid_mode() {
+    let mut context = TestContext::new();
+
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let mut ix = allow_block_list_client::instructions::CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .mode(Mode::Allow)
+        .seed(seed)
+        .instruction();
+    // discriminator, then the mode
+    ix.data[1] = 5;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_err());
+}
@@ -0,0 +1,265 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, ListRegistry, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{
+    AddWalletBuilder, CreateMintListBuilder, DeleteListBuilder, PruneListRegistryBuilder,
+};
+use allow_block_list_client::pda::find_mint_list_config_pda;
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::TestContext;
+
+fn create_mint_list(
+    context: &mut TestContext,
+    mint_config: &Pubkey,
+    signer: &Keypair,
+    mode: Mode,
+) -> (Pubkey, TransactionResult) {
+    let (list, _) = find_mint_list_config_pda(&context.token.mint);
+
+    let ix = CreateMintListBuilder::new()
+        .authority(signer.pubkey())
+        .list_config(list)
+        .mint(context.token.mint)
+        .mint_config(*mint_config)
+        .list_registry(ListRegistry::find_pda(&signer.pubkey()).0)
+        .mode(mode)
+        .instruction();
+    let res = context.send_as(ix, signer);
+
+    (list, res)
+}
+
+fn add_wallet(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    mint_config: Option<Pubkey>,
+    signer: &Keypair,
+) -> TransactionResult {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+    let ix = AddWalletBuilder::new()
+        .authority(signer.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .mint_config(mint_config)
+        .instruction();
+    context.send_as(ix, signer)
+}
+
+/// Moves the freeze authority recorded in the token-acl mint config to `new_authority`.
+fn move_freeze_authority(context: &mut TestContext, mint_config: &Pubkey, new_authority: &Pubkey) {
+    let old_authority = context.token.auth.pubkey();
+    let mut account = context.vm.get_account(mint_config).unwrap();
+    let offset = account
+        .data
+        .windows(32)
+        .position(|key| key == old_authority.as_ref())
+        .unwrap();
+    account.data[offset..offset + 32].copy_from_slice(new_authority.as_ref());
+    context.vm.set_account(*mint_config, account).unwrap();
+}
+
+fn prune_list_registry(
+    context: &mut TestContext,
+    list: &Pubkey,
+    mint_config: Option<Pubkey>,
+    signer: &Keypair,
+) -> TransactionResult {
+    let ix = PruneListRegistryBuilder::new()
+        .authority(signer.pubkey())
+        .list_registry(ListRegistry::find_pda(&signer.pubkey()).0)
+        .list_config(*list)
+        .mint_config(mint_config)
+        .instruction();
+    context.send_as(ix, signer)
+}
+
+fn registered_lists(context: &TestContext, authority: &Pubkey) -> usize {
+    let account = context
+        .vm
+        .get_account(&ListRegistry::find_pda(authority).0)
+        .unwrap();
+    ListRegistry::from_bytes(&account.data).unwrap().lists.len()
+}
+
+#[tokio::test]
+async fn freeze_authority_controls_mint_list() {
+    let mut context = TestContext::new();
+
+    let mint_config = context.setup_token_acl();
+    let freeze_authority = context.token.auth.insecure_clone();
+    let (list, res) = create_mint_list(&mut context, &mint_config, &freeze_authority, Mode::Block);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.mint, context.token.mint);
+    assert_eq!(list_data.authority, Pubkey::default());
+
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let res = add_wallet(
+        &mut context,
+        &list,
+        &wallet.pubkey(),
+        Some(mint_config),
+        &freeze_authority,
+    );
+    assert!(res.is_ok());
+
+    let ta = context.create_token_account(&wallet);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletBlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn records_mint_list_in_registry() {
+    let mut context = TestContext::new();
+
+    let mint_config = context.setup_token_acl();
+    let freeze_authority = context.token.auth.insecure_clone();
+    let (list, res) = create_mint_list(&mut context, &mint_config, &freeze_authority, Mode::Block);
+    assert!(res.is_ok());
+
+    let list_registry = ListRegistry::find_pda(&freeze_authority.pubkey()).0;
+    let account = context.vm.get_account(&list_registry).unwrap();
+    let registry = ListRegistry::from_bytes(&account.data).unwrap();
+    assert_eq!(registry.authority, freeze_authority.pubkey());
+    assert_eq!(registry.lists.len(), 1);
+    assert_eq!(registry.lists[0].list_config, list);
+    assert_eq!(registry.lists[0].mode, Mode::Block as u8);
+}
+
+#[tokio::test]
+async fn fails_to_change_mint_list_without_freeze_authority() {
+    let mut context = TestContext::new();
+
+    let mint_config = context.setup_token_acl();
+    let freeze_authority = context.token.auth.insecure_clone();
+    let (list, res) = create_mint_list(&mut context, &mint_config, &freeze_authority, Mode::Allow);
+    assert!(res.is_ok());
+
+    let other = context.auth.insecure_clone();
+    let res = add_wallet(
+        &mut context,
+        &list,
+        &Pubkey::new_unique(),
+        Some(mint_config),
+        &other,
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+
+    // the mint config is needed to find out who the freeze authority is
+    let res = add_wallet(
+        &mut context,
+        &list,
+        &Pubkey::new_unique(),
+        None,
+        &freeze_authority,
+    );
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidTokenAclMintConfig as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn only_freeze_authority_creates_mint_list() {
+    let mut context = TestContext::new();
+
+    let mint_config = context.setup_token_acl();
+    let other = context.auth.insecure_clone();
+    let (list, res) = create_mint_list(&mut context, &mint_config, &other, Mode::Allow);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+    assert!(context.vm.get_account(&list).is_none());
+}
+
+#[tokio::test]
+async fn prunes_mint_list_after_freeze_authority_moves() {
+    let mut context = TestContext::new();
+
+    let mint_config = context.setup_token_acl();
+    let freeze_authority = context.token.auth.insecure_clone();
+    let (list, res) = create_mint_list(&mut context, &mint_config, &freeze_authority, Mode::Block);
+    assert!(res.is_ok());
+
+    let res = prune_list_registry(&mut context, &list, Some(mint_config), &freeze_authority);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::ListStillControlled as u32)
+        )
+    );
+
+    let new_freeze_authority = Keypair::new();
+    move_freeze_authority(&mut context, &mint_config, &new_freeze_authority.pubkey());
+
+    let res = prune_list_registry(&mut context, &list, Some(mint_config), &freeze_authority);
+    assert!(res.is_ok());
+    assert_eq!(registered_lists(&context, &freeze_authority.pubkey()), 0);
+    assert!(context.vm.get_account(&list).is_some());
+}
+
+#[tokio::test]
+async fn prunes_mint_list_deleted_by_next_freeze_authority() {
+    let mut context = TestContext::new();
+
+    let mint_config = context.setup_token_acl();
+    let freeze_authority = context.token.auth.insecure_clone();
+    let (list, res) = create_mint_list(&mut context, &mint_config, &freeze_authority, Mode::Block);
+    assert!(res.is_ok());
+
+    let new_freeze_authority = Keypair::new();
+    context
+        .vm
+        .airdrop(&new_freeze_authority.pubkey(), 1_000_000_000)
+        .unwrap();
+    move_freeze_authority(&mut context, &mint_config, &new_freeze_authority.pubkey());
+
+    // the registry of the new freeze authority does not exist yet
+    let ix = DeleteListBuilder::new()
+        .authority(new_freeze_authority.pubkey())
+        .list_config(list)
+        .mint_config(Some(mint_config))
+        .list_registry(ListRegistry::find_pda(&new_freeze_authority.pubkey()).0)
+        .instruction();
+    let res = context.send_as(ix, &new_freeze_authority);
+    assert!(res.is_ok());
+    assert!(context.vm.get_account(&list).is_none());
+    assert_eq!(registered_lists(&context, &freeze_authority.pubkey()), 1);
+
+    let res = prune_list_registry(&mut context, &list, None, &freeze_authority);
+    assert!(res.is_ok());
+    assert_eq!(registered_lists(&context, &freeze_authority.pubkey()), 0);
+}
@@ -0,0 +1,377 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{DepositVault, ListConfig, ListRegistry, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{
+    DeleteListBuilder, EnrollBuilder, EvictWalletBuilder, ForceDeleteListBuilder,
+    ReclaimWalletEntryBuilder, RemoveWalletBuilder, SetListLimitsBuilder, SetOpenEnrollmentBuilder,
+};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+const DEPOSIT: u64 = 50_000_000;
+
+fn set_open_enrollment(
+    context: &mut TestContext,
+    list: &Pubkey,
+    open: bool,
+    deposit: u64,
+) -> TransactionResult {
+    let ix = SetOpenEnrollmentBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .deposit_vault(DepositVault::find_pda(list).0)
+        .open_enrollment(open)
+        .enrollment_deposit(deposit)
+        .instruction();
+    context.send(ix)
+}
+
+fn enroll(context: &mut TestContext, list: &Pubkey, wallet: &Keypair) -> TransactionResult {
+    let ix = EnrollBuilder::new()
+        .wallet(wallet.pubkey())
+        .list_config(*list)
+        .wallet_entry(WalletEntry::find_pda(list, &wallet.pubkey()).0)
+        .deposit_vault(DepositVault::find_pda(list).0)
+        .instruction();
+    context.send_as(ix, wallet)
+}
+
+fn evict(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet: &Pubkey,
+    refund_deposit: bool,
+) -> TransactionResult {
+    let ix = EvictWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(WalletEntry::find_pda(list, wallet).0)
+        .deposit_vault(DepositVault::find_pda(list).0)
+        .refund_deposit(refund_deposit)
+        .instruction();
+    context.send(ix)
+}
+
+fn new_wallet(context: &mut TestContext) -> Keypair {
+    let wallet = Keypair::new();
+    let _ = context.vm.airdrop(&wallet.pubkey(), 1_000_000_000);
+    wallet
+}
+
+fn lamports(context: &TestContext, address: &Pubkey) -> u64 {
+    context
+        .vm
+        .get_account(address)
+        .map(|account| account.lamports)
+        .unwrap_or_default()
+}
+
+/// Deletes `list` with a transaction paid by a third party, so the authority
+/// balance only moves by the refunded rent.
+fn delete_list(
+    context: &mut TestContext,
+    list: &Pubkey,
+    deposit_vault: Option<Pubkey>,
+) -> TransactionResult {
+    let ix = DeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .deposit_vault(deposit_vault)
+        .instruction();
+
+    let payer = new_wallet(context);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&payer.pubkey()),
+        &[payer.insecure_clone(), context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    context.vm.expire_blockhash();
+    res
+}
+
+/// Creates an allow list open for enrollment with a `DEPOSIT` and enrolls a new wallet in it.
+fn enrolled_wallet(context: &mut TestContext) -> (Pubkey, Keypair) {
+    let list = context.create_list(Mode::Allow);
+    let res = set_open_enrollment(context, &list, true, DEPOSIT);
+    assert!(res.is_ok());
+
+    let wallet = new_wallet(context);
+    let res = enroll(context, &list, &wallet);
+    assert!(res.is_ok());
+
+    (list, wallet)
+}
+
+#[tokio::test]
+async fn enrolls_wallet_with_deposit() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_open_enrollment(&mut context, &list, true, DEPOSIT);
+    assert!(res.is_ok());
+
+    let vault = DepositVault::find_pda(&list).0;
+    let vault_before = lamports(&context, &vault);
+
+    let wallet = new_wallet(&mut context);
+    let res = enroll(&mut context, &list, &wallet);
+    assert!(res.is_ok());
+
+    assert_eq!(lamports(&context, &vault), vault_before + DEPOSIT);
+
+    let (wallet_entry, bump) = WalletEntry::find_pda(&list, &wallet.pubkey());
+    let account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&account.data).unwrap();
+    assert_eq!(entry.wallet_address, wallet.pubkey());
+    assert_eq!(entry.list_config, list);
+    assert_eq!(entry.bump, bump);
+    assert!(entry.enrolled);
+    assert_eq!(entry.deposit, DEPOSIT);
+
+    let account = context.vm.get_account(&list).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, 1);
+}
+
+#[tokio::test]
+async fn enrollments_are_not_rate_limited() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_open_enrollment(&mut context, &list, true, DEPOSIT);
+    assert!(res.is_ok());
+
+    let ix = SetListLimitsBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .max_changes_per_epoch(1)
+        .allow_delay(0)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    for _ in 0..3 {
+        let wallet = new_wallet(&mut context);
+        let res = enroll(&mut context, &list, &wallet);
+        assert!(res.is_ok());
+    }
+
+    // the authority still has its change left
+    let _ = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+}
+
+#[tokio::test]
+async fn opens_enrollment_with_funded_vault_address() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let vault = DepositVault::find_pda(&list).0;
+    let res = context.vm.airdrop(&vault, 1);
+    assert!(res.is_ok());
+
+    let res = set_open_enrollment(&mut context, &list, true, DEPOSIT);
+    assert!(res.is_ok());
+
+    let account = context.vm.get_account(&vault).unwrap();
+    let deposit_vault = DepositVault::from_bytes(&account.data).unwrap();
+    assert_eq!(deposit_vault.list_config, list);
+    assert_eq!(
+        account.lamports,
+        context
+            .vm
+            .minimum_balance_for_rent_exemption(account.data.len())
+    );
+}
+
+#[tokio::test]
+async fn fails_to_enroll_in_closed_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_open_enrollment(&mut context, &list, false, 0);
+    assert!(res.is_ok());
+
+    let wallet = new_wallet(&mut context);
+    let res = enroll(&mut context, &list, &wallet);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::EnrollmentClosed as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn evicts_wallet_refunding_deposit() {
+    let mut context = TestContext::new();
+
+    let (list, wallet) = enrolled_wallet(&mut context);
+    let wallet_entry = WalletEntry::find_pda(&list, &wallet.pubkey()).0;
+    let entry_lamports = lamports(&context, &wallet_entry);
+    let wallet_before = lamports(&context, &wallet.pubkey());
+
+    let res = evict(&mut context, &list, &wallet.pubkey(), true);
+    assert!(res.is_ok());
+
+    assert_eq!(lamports(&context, &wallet_entry), 0);
+    assert_eq!(
+        lamports(&context, &wallet.pubkey()),
+        wallet_before + entry_lamports + DEPOSIT
+    );
+
+    let account = context.vm.get_account(&list).unwrap();
+    let config = ListConfig::from_bytes(&account.data).unwrap();
+    assert_eq!(config.wallets_count, 0);
+}
+
+#[tokio::test]
+async fn evicts_wallet_keeping_deposit() {
+    let mut context = TestContext::new();
+
+    let (list, wallet) = enrolled_wallet(&mut context);
+    let wallet_entry = WalletEntry::find_pda(&list, &wallet.pubkey()).0;
+    let entry_lamports = lamports(&context, &wallet_entry);
+    let wallet_before = lamports(&context, &wallet.pubkey());
+    let vault = DepositVault::find_pda(&list).0;
+    let vault_before = lamports(&context, &vault);
+
+    let res = evict(&mut context, &list, &wallet.pubkey(), false);
+    assert!(res.is_ok());
+
+    // the wallet only gets its entry rent back, the deposit goes to the authority
+    assert_eq!(
+        lamports(&context, &wallet.pubkey()),
+        wallet_before + entry_lamports
+    );
+    assert_eq!(lamports(&context, &vault), vault_before - DEPOSIT);
+}
+
+#[tokio::test]
+async fn fails_to_remove_enrolled_wallet() {
+    let mut context = TestContext::new();
+
+    let (list, wallet) = enrolled_wallet(&mut context);
+
+    let ix = RemoveWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .wallet_entry(WalletEntry::find_pda(&list, &wallet.pubkey()).0)
+        .instruction();
+    let res = context.send(ix);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::EnrolledWallet as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_open_enrollment_on_block_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = set_open_enrollment(&mut context, &list, true, DEPOSIT);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidData as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn closes_deposit_vault_with_list() {
+    let mut context = TestContext::new();
+
+    let (list, wallet) = enrolled_wallet(&mut context);
+    let res = evict(&mut context, &list, &wallet.pubkey(), true);
+    assert!(res.is_ok());
+
+    let vault = DepositVault::find_pda(&list).0;
+    let res = delete_list(&mut context, &list, None);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidDepositVault as u32)
+        )
+    );
+
+    let authority = context.auth.pubkey();
+    let authority_before = lamports(&context, &authority);
+    let list_lamports = lamports(&context, &list);
+    let vault_lamports = lamports(&context, &vault);
+
+    let res = delete_list(&mut context, &list, Some(vault));
+    assert!(res.is_ok());
+
+    assert_eq!(lamports(&context, &vault), 0);
+    assert_eq!(
+        lamports(&context, &authority),
+        authority_before + list_lamports + vault_lamports
+    );
+}
+
+#[tokio::test]
+async fn reclaims_deposits_before_closing_vault() {
+    let mut context = TestContext::new();
+
+    let (list, wallet) = enrolled_wallet(&mut context);
+    let vault = DepositVault::find_pda(&list).0;
+    let wallet_entry = WalletEntry::find_pda(&list, &wallet.pubkey()).0;
+
+    let ix = ForceDeleteListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .deposit_vault(Some(vault))
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    // the vault is kept along with the tombstone until the deposits are refunded
+    assert!(lamports(&context, &vault) > DEPOSIT);
+
+    let entry_lamports = lamports(&context, &wallet_entry);
+    let wallet_before = lamports(&context, &wallet.pubkey());
+    let authority_before = lamports(&context, &context.auth.pubkey());
+    let vault_rent = lamports(&context, &vault) - DEPOSIT;
+    let tombstone_lamports = lamports(&context, &list);
+
+    let ix = ReclaimWalletEntryBuilder::new()
+        .list_config(list)
+        .wallet_entry(wallet_entry)
+        .authority(context.auth.pubkey())
+        .wallet(Some(wallet.pubkey()))
+        .deposit_vault(Some(vault))
+        .instruction();
+    let caller = new_wallet(&mut context);
+    let res = context.send_as(ix, &caller);
+    assert!(res.is_ok());
+
+    assert_eq!(
+        lamports(&context, &wallet.pubkey()),
+        wallet_before + entry_lamports + DEPOSIT
+    );
+    assert_eq!(lamports(&context, &vault), 0);
+    assert_eq!(lamports(&context, &list), 0);
+    assert_eq!(
+        lamports(&context, &context.auth.pubkey()),
+        authority_before + vault_rent + tombstone_lamports
+    );
+}
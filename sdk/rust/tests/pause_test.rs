@@ -0,0 +1,135 @@
+pub mod program_test;
+use allow_block_list_client::accounts::ListConfig;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::SetListPausedBuilder;
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn set_list_paused(
+    context: &mut TestContext,
+    list: &Pubkey,
+    paused: bool,
+    fail_open: bool,
+) -> TransactionResult {
+    let ix = SetListPausedBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .paused(paused)
+        .fail_open(fail_open)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+#[tokio::test]
+async fn stores_pause_settings() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = set_list_paused(&mut context, &list, true, true);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert!(list_data.paused);
+    assert!(list_data.fail_open);
+}
+
+#[tokio::test]
+async fn fail_closed_list_denies_everyone() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = set_list_paused(&mut context, &list, true, false);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::ListPaused as u32)
+        )
+    );
+
+    let res = set_list_paused(&mut context, &list, false, false);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fail_open_list_passes_everyone() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    let res = set_list_paused(&mut context, &list, true, true);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_with_invalid_flag() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+
+    let mut ix = SetListPausedBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .paused(true)
+        .fail_open(false)
+        .instruction();
+    // [discriminator, paused, fail_open]
+    ix.data[1] = 2;
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidData as u32)
+        )
+    );
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    assert!(!ListConfig::from_bytes(&list_account.data).unwrap().paused);
+}
@@ -1,17 +1,20 @@
-use allow_block_list_client::types::Mode;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::types::{Mode, Storage};
 use litesvm::types::TransactionResult;
 use litesvm::LiteSVM;
 use solana_instruction::{AccountMeta, Instruction};
 use solana_pubkey::Pubkey;
-use solana_sdk::transaction::Transaction;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::transaction::{Transaction, TransactionError};
 use solana_sdk::{signature::Keypair, signer::Signer};
 use solana_system_interface::instruction::create_account;
 use solana_system_interface::program::ID;
 use spl_associated_token_account_client::address::get_associated_token_address_with_program_id;
 use spl_associated_token_account_client::instruction::create_associated_token_account;
 use spl_token_2022::extension::default_account_state::instruction::initialize_default_account_state;
+use spl_token_2022::extension::transfer_hook::instruction::initialize as initialize_transfer_hook;
 use spl_token_2022::extension::ExtensionType;
-use spl_token_2022::instruction::initialize_mint2;
+use spl_token_2022::instruction::{initialize_mint2, mint_to};
 use spl_token_2022::state::{AccountState, Mint};
 
 pub struct TestContext {
@@ -31,6 +34,14 @@ impl Default for TestContext {
     }
 }
 
+/// Asserts the first instruction of a transaction failed with `error`.
+pub fn assert_error(res: TransactionResult, error: AblError) {
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(0, InstructionError::Custom(error as u32))
+    );
+}
+
 impl TestContext {
     pub fn new() -> Self {
         let mut vm = LiteSVM::new();
@@ -112,6 +123,54 @@ impl TestContext {
         }
     }
 
+    /// Creates a mint using the allow block list as its transfer hook program.
+    pub fn create_transfer_hook_token(&mut self) -> TokenContext {
+        let auth = Keypair::new();
+        let auth_pubkey = auth.pubkey();
+
+        let res = self.vm.airdrop(&auth_pubkey, 1_000_000_000_000);
+        assert!(res.is_ok());
+
+        let mint_size =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferHook])
+                .unwrap();
+        let mint_kp = Keypair::new();
+        let mint_pk = mint_kp.pubkey();
+        let token_program_id = &spl_token_2022::ID;
+
+        let ix1 = create_account(
+            &auth_pubkey,
+            &mint_pk,
+            self.vm.minimum_balance_for_rent_exemption(mint_size),
+            mint_size as u64,
+            token_program_id,
+        );
+
+        let ix2 = initialize_transfer_hook(
+            token_program_id,
+            &mint_pk,
+            Some(auth_pubkey),
+            Some(allow_block_list_client::programs::ABL_ID),
+        )
+        .unwrap();
+
+        let ix3 = initialize_mint2(token_program_id, &mint_pk, &auth_pubkey, None, 6).unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix1, ix2, ix3],
+            Some(&auth_pubkey),
+            &[auth.insecure_clone(), mint_kp],
+            self.vm.latest_blockhash(),
+        );
+        let res = self.vm.send_transaction(tx);
+        assert!(res.is_ok());
+
+        TokenContext {
+            mint: mint_pk,
+            auth,
+        }
+    }
+
     fn create_token_account_with_params(
         vm: &mut LiteSVM,
         mint: &Pubkey,
@@ -165,7 +224,50 @@ impl TestContext {
         )
     }
 
+    /// Creates a token account for `owner` on `token` holding `amount` tokens.
+    pub fn create_funded_token_account(
+        &mut self,
+        token: &TokenContext,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> Pubkey {
+        let token_account = Self::create_token_account_with_params(
+            &mut self.vm,
+            &token.mint,
+            owner,
+            &self.auth,
+            false,
+        );
+
+        if amount > 0 {
+            let ix = mint_to(
+                &spl_token_2022::ID,
+                &token.mint,
+                &token_account,
+                &token.auth.pubkey(),
+                &[],
+                amount,
+            )
+            .unwrap();
+
+            let tx = Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&token.auth.pubkey()),
+                &[token.auth.insecure_clone()],
+                self.vm.latest_blockhash(),
+            );
+            let res = self.vm.send_transaction(tx);
+            assert!(res.is_ok());
+        }
+
+        token_account
+    }
+
     pub fn create_list(&mut self, mode: Mode) -> Pubkey {
+        self.create_list_with_storage(mode, Storage::Entries)
+    }
+
+    pub fn create_list_with_storage(&mut self, mode: Mode, storage: Storage) -> Pubkey {
         let seed = Pubkey::new_unique();
 
         let (list_config_address, _) =
@@ -174,8 +276,12 @@ impl TestContext {
         let ix = allow_block_list_client::instructions::CreateListBuilder::new()
             .authority(self.auth.pubkey())
             .list_config(list_config_address)
+            .list_registry(
+                allow_block_list_client::accounts::ListRegistry::find_pda(&self.auth.pubkey()).0,
+            )
             .mode(mode)
             .seed(seed)
+            .storage(storage)
             .instruction();
 
         let tx = Transaction::new_signed_with_payer(
@@ -225,7 +331,118 @@ impl TestContext {
         extra_metas
     }
 
+    pub fn setup_transfer_hook_extra_metas(
+        &mut self,
+        token: &TokenContext,
+        lists: &[Pubkey],
+    ) -> TransactionResult {
+        let (extra_metas, _) = Pubkey::find_program_address(
+            &[b"extra-account-metas", token.mint.as_ref()],
+            &allow_block_list_client::programs::ABL_ID,
+        );
+
+        let ix = allow_block_list_client::instructions::SetupTransferHookExtraMetasBuilder::new()
+            .authority(token.auth.pubkey())
+            .mint(token.mint)
+            .extra_metas(extra_metas)
+            .add_remaining_accounts(
+                lists
+                    .iter()
+                    .map(|list| AccountMeta::new_readonly(*list, false))
+                    .collect::<Vec<_>>()
+                    .as_slice(),
+            )
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&token.auth.pubkey()),
+            &[token.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+
+        self.vm.send_transaction(tx)
+    }
+
+    pub async fn transfer(
+        &mut self,
+        token: &TokenContext,
+        owner: &Keypair,
+        source: &Pubkey,
+        destination: &Pubkey,
+        amount: u64,
+    ) -> TransactionResult {
+        let ix = spl_token_2022::offchain::create_transfer_checked_instruction_with_extra_metas(
+            &spl_token_2022::ID,
+            source,
+            &token.mint,
+            destination,
+            &owner.pubkey(),
+            &[],
+            amount,
+            6,
+            |pubkey| {
+                let account = self.vm.get_account(&pubkey);
+
+                async move {
+                    match account {
+                        Some(account) => Ok(Some(account.data)),
+                        None => Ok(None),
+                    }
+                }
+            },
+        )
+        .await
+        .unwrap();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.auth.pubkey()),
+            &[self.auth.insecure_clone(), owner.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        self.vm.send_transaction(tx)
+    }
+
+    pub fn create_risk_score_list(&mut self, risk_threshold: u8) -> Pubkey {
+        let seed = Pubkey::new_unique();
+
+        let (list_config_address, _) =
+            allow_block_list_client::accounts::ListConfig::find_pda(&self.auth.pubkey(), &seed);
+
+        let ix = allow_block_list_client::instructions::CreateListBuilder::new()
+            .authority(self.auth.pubkey())
+            .list_config(list_config_address)
+            .list_registry(
+                allow_block_list_client::accounts::ListRegistry::find_pda(&self.auth.pubkey()).0,
+            )
+            .mode(Mode::RiskScore)
+            .seed(seed)
+            .risk_threshold(risk_threshold)
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.auth.pubkey()),
+            &[self.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        let res = self.vm.send_transaction(tx);
+        assert!(res.is_ok());
+
+        list_config_address
+    }
+
     pub fn add_wallet_to_list(&mut self, list: &Pubkey, wallet_address: &Pubkey) -> Pubkey {
+        self.add_scored_wallet_to_list(list, wallet_address, 0)
+    }
+
+    pub fn add_scored_wallet_to_list(
+        &mut self,
+        list: &Pubkey,
+        wallet_address: &Pubkey,
+        score: u8,
+    ) -> Pubkey {
         let (wallet_entry, _) =
             allow_block_list_client::accounts::WalletEntry::find_pda(&list, &wallet_address);
 
@@ -234,6 +451,7 @@ impl TestContext {
             .list_config(*list)
             .wallet(*wallet_address)
             .wallet_entry(wallet_entry)
+            .score(score)
             .instruction();
 
         let tx = Transaction::new_signed_with_payer(
@@ -248,6 +466,29 @@ impl TestContext {
         wallet_entry
     }
 
+    pub fn add_wallets_to_bucket(&mut self, list: &Pubkey, wallets: &[Pubkey]) -> Pubkey {
+        let (bucket, _) =
+            allow_block_list_client::accounts::Bucket::find_pda(list, &wallets[0].to_bytes()[0]);
+
+        let ix = allow_block_list_client::instructions::AddBucketWalletsBuilder::new()
+            .authority(self.auth.pubkey())
+            .list_config(*list)
+            .bucket(bucket)
+            .wallets(wallets.to_vec().into())
+            .instruction();
+
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&self.auth.pubkey()),
+            &[self.auth.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        let res = self.vm.send_transaction(tx);
+        assert!(res.is_ok());
+
+        bucket
+    }
+
     pub async fn get_thaw_permissionless_ix(
         &mut self,
         signer: &Pubkey,
@@ -296,6 +537,26 @@ impl TestContext {
         self.vm.send_transaction(tx)
     }
 
+    /// Sends `ix` signed and paid for by the list authority.
+    pub fn send(&mut self, ix: Instruction) -> TransactionResult {
+        let auth = self.auth.insecure_clone();
+        self.send_as(ix, &auth)
+    }
+
+    /// Sends `ix` signed and paid for by `signer`, expiring the blockhash so
+    /// the same instruction can be sent again.
+    pub fn send_as(&mut self, ix: Instruction, signer: &Keypair) -> TransactionResult {
+        let tx = Transaction::new_signed_with_payer(
+            &[ix],
+            Some(&signer.pubkey()),
+            &[signer.insecure_clone()],
+            self.vm.latest_blockhash(),
+        );
+        let res = self.vm.send_transaction(tx);
+        self.vm.expire_blockhash();
+        res
+    }
+
     pub fn setup_token_acl(&mut self) -> Pubkey {
         let (mint_cfg_pk, _) = token_acl_client::accounts::MintConfig::find_pda(&self.token.mint);
 
@@ -0,0 +1,434 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{Identity, ListConfig, ListRegistry, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{
+    AddWalletBuilder, CreateIdentityBuilder, CreateListBuilder, LinkWalletIdentityBuilder,
+    SetAttributePolicyBuilder, SetIdentityStatusBuilder, SetListLimitsBuilder,
+    SetListPausedBuilder, SetWalletAttributesBuilder,
+};
+use allow_block_list_client::types::{IdentityStatus, Mode};
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::TransactionError;
+
+use crate::program_test::{assert_error, TestContext};
+
+fn set_list_limits(
+    context: &mut TestContext,
+    list: &Pubkey,
+    max_changes_per_epoch: u64,
+    allow_delay: u64,
+) -> TransactionResult {
+    let ix = SetListLimitsBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .max_changes_per_epoch(max_changes_per_epoch)
+        .allow_delay(allow_delay)
+        .instruction();
+    context.send(ix)
+}
+
+fn try_add_wallet(context: &mut TestContext, list: &Pubkey, wallet: &Pubkey) -> TransactionResult {
+    let (wallet_entry, _) = WalletEntry::find_pda(list, wallet);
+    let ix = AddWalletBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet(*wallet)
+        .wallet_entry(wallet_entry)
+        .instruction();
+    context.send(ix)
+}
+
+fn set_list_paused(context: &mut TestContext, list: &Pubkey, paused: bool) -> TransactionResult {
+    let ix = SetListPausedBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .paused(paused)
+        .fail_open(true)
+        .instruction();
+    context.send(ix)
+}
+
+fn set_wallet_attributes(
+    context: &mut TestContext,
+    list: &Pubkey,
+    wallet_entry: &Pubkey,
+    attributes: u64,
+) -> TransactionResult {
+    let ix = SetWalletAttributesBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .wallet_entry(*wallet_entry)
+        .attributes(attributes)
+        .instruction();
+    context.send(ix)
+}
+
+fn set_attribute_policy(
+    context: &mut TestContext,
+    list: &Pubkey,
+    source: &Pubkey,
+    required: u64,
+) -> TransactionResult {
+    let ix = SetAttributePolicyBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .attributes_source(*source)
+        .required_attributes(required)
+        .forbidden_attributes(0)
+        .instruction();
+    context.send(ix)
+}
+
+fn set_identity_status(
+    context: &mut TestContext,
+    list: &Pubkey,
+    identity: &Pubkey,
+    status: IdentityStatus,
+) -> TransactionResult {
+    let ix = SetIdentityStatusBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .identity(*identity)
+        .status(status)
+        .instruction();
+    context.send(ix)
+}
+
+fn advance_clock(context: &mut TestContext, epochs: u64, seconds: i64) {
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.epoch += epochs;
+    clock.unix_timestamp += seconds;
+    context.vm.set_sysvar(&clock);
+    context.vm.expire_blockhash();
+}
+
+#[tokio::test]
+async fn limits_changes_per_epoch() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = set_list_limits(&mut context, &list, 2, 0);
+    assert!(res.is_ok());
+
+    let _ = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+    let _ = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+
+    let res = try_add_wallet(&mut context, &list, &Pubkey::new_unique());
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::RateLimitExceeded as u32)
+        )
+    );
+
+    // the counter resets on the next epoch
+    advance_clock(&mut context, 1, 0);
+    let res = try_add_wallet(&mut context, &list, &Pubkey::new_unique());
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.max_changes_per_epoch, 2);
+    assert_eq!(list_data.changes_in_epoch, 1);
+    assert_eq!(list_data.wallets_count, 3);
+}
+
+#[tokio::test]
+async fn limits_policy_changes_per_epoch() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = set_list_limits(&mut context, &list, 1, 0);
+    assert!(res.is_ok());
+
+    let res = set_list_paused(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let res = set_list_paused(&mut context, &list, false);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::RateLimitExceeded as u32)
+        )
+    );
+
+    advance_clock(&mut context, 1, 0);
+    let res = set_list_paused(&mut context, &list, false);
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn delays_allow_list_additions() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Allow);
+    let _ = context.setup_extra_metas(&[list]);
+    let res = set_list_limits(&mut context, &list, 0, 3600);
+    assert!(res.is_ok());
+
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let entry_account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry_data = WalletEntry::from_bytes(&entry_account.data).unwrap();
+    assert!(entry_data.active_from > 0);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletNotAllowed as u32)
+        )
+    );
+
+    advance_clock(&mut context, 0, 3600);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn block_list_additions_take_effect_immediately() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+    let res = set_list_limits(&mut context, &list, 0, 3600);
+    assert!(res.is_ok());
+
+    let wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletBlocked as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn delays_loosening_limits() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_list_limits(&mut context, &list, 10, 600);
+    assert!(res.is_ok());
+
+    for (max_changes_per_epoch, allow_delay) in [(0, 600), (11, 600), (10, 599)] {
+        let res = set_list_limits(&mut context, &list, max_changes_per_epoch, allow_delay);
+        assert!(res.is_ok());
+
+        let list_account = context.vm.get_account(&list).unwrap();
+        let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+        assert_eq!(list_data.max_changes_per_epoch, 10);
+        assert_eq!(list_data.allow_delay, 600);
+        assert_eq!(
+            list_data.pending_max_changes_per_epoch,
+            max_changes_per_epoch
+        );
+        assert_eq!(list_data.pending_allow_delay, allow_delay);
+
+        let res = set_list_limits(&mut context, &list, max_changes_per_epoch, allow_delay);
+        assert_error(res, AblError::ChangeTimelocked);
+    }
+
+    advance_clock(&mut context, 0, 7 * 24 * 60 * 60);
+    let res = set_list_limits(&mut context, &list, 10, 599);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.allow_delay, 599);
+    assert_eq!(list_data.pending_limits_from, 0);
+}
+
+#[tokio::test]
+async fn tightening_limits_cancels_pending_loosening() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_list_limits(&mut context, &list, 10, 600);
+    assert!(res.is_ok());
+
+    let res = set_list_limits(&mut context, &list, 0, 0);
+    assert!(res.is_ok());
+
+    let res = set_list_limits(&mut context, &list, 5, 1200);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.max_changes_per_epoch, 5);
+    assert_eq!(list_data.allow_delay, 1200);
+    assert_eq!(list_data.pending_limits_from, 0);
+
+    // the cancelled loosening starts over
+    advance_clock(&mut context, 0, 7 * 24 * 60 * 60);
+    let res = set_list_limits(&mut context, &list, 0, 0);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.max_changes_per_epoch, 5);
+    assert!(list_data.pending_limits_from > 0);
+}
+
+#[tokio::test]
+async fn limits_attribute_changes_per_epoch() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+    let res = set_list_limits(&mut context, &list, 1, 0);
+    assert!(res.is_ok());
+
+    let res = set_wallet_attributes(&mut context, &list, &wallet_entry, 1);
+    assert!(res.is_ok());
+
+    let res = set_wallet_attributes(&mut context, &list, &wallet_entry, 3);
+    assert_error(res, AblError::RateLimitExceeded);
+}
+
+#[tokio::test]
+async fn delays_attribute_changes_on_allowing_lists() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_list_limits(&mut context, &list, 0, 3600);
+    assert!(res.is_ok());
+    let wallet_entry = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+    advance_clock(&mut context, 0, 3600);
+
+    let res = set_wallet_attributes(&mut context, &list, &wallet_entry, 1);
+    assert!(res.is_ok());
+
+    let now = context.vm.get_sysvar::<Clock>().unix_timestamp;
+    let entry_account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry_data = WalletEntry::from_bytes(&entry_account.data).unwrap();
+    assert_eq!(entry_data.attributes, 1);
+    assert_eq!(entry_data.active_from, now + 3600);
+}
+
+#[tokio::test]
+async fn delays_permissive_policy_changes() {
+    let mut context = TestContext::new();
+
+    let registry = context.create_list(Mode::Allow);
+    let policy = context.create_list(Mode::Attributes);
+    let res = set_list_limits(&mut context, &policy, 0, 3600);
+    assert!(res.is_ok());
+
+    // pointing the policy at another list is staged
+    let res = set_attribute_policy(&mut context, &policy, &registry, 1);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&policy).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.attributes_source, policy);
+    assert_eq!(list_data.pending_attributes_source, registry);
+    assert!(list_data.pending_policy_from > 0);
+
+    let res = set_attribute_policy(&mut context, &policy, &registry, 1);
+    assert_error(res, AblError::ChangeTimelocked);
+
+    advance_clock(&mut context, 0, 3600);
+    let res = set_attribute_policy(&mut context, &policy, &registry, 1);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&policy).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.attributes_source, registry);
+    assert_eq!(list_data.required_attributes, 1);
+    assert_eq!(list_data.pending_policy_from, 0);
+
+    // requiring more attributes applies immediately
+    let res = set_attribute_policy(&mut context, &policy, &registry, 3);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&policy).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.required_attributes, 3);
+}
+
+#[tokio::test]
+async fn fails_to_use_block_list_as_attributes_source() {
+    let mut context = TestContext::new();
+
+    let source = context.create_list(Mode::Block);
+    let policy = context.create_list(Mode::Attributes);
+
+    let res = set_attribute_policy(&mut context, &policy, &source, 1);
+    assert_error(res, AblError::InvalidData);
+}
+
+#[tokio::test]
+async fn delays_allowing_blocked_identity() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+    let ix = CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .mode(Mode::Allow)
+        .seed(seed)
+        .identities(true)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+    let _ = context.setup_extra_metas(&[list]);
+
+    let id = Pubkey::new_unique();
+    let (identity, _) = Identity::find_pda(&list, &id);
+    let ix = CreateIdentityBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .identity(identity)
+        .id(id)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    let wallet = Keypair::new();
+    let wallet_entry = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ix = LinkWalletIdentityBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .wallet_entry(wallet_entry)
+        .identity(Some(identity))
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+    let ta = context.create_token_account(&wallet);
+
+    let res = set_list_limits(&mut context, &list, 0, 3600);
+    assert!(res.is_ok());
+
+    let res = set_identity_status(&mut context, &list, &identity, IdentityStatus::Blocked);
+    assert!(res.is_ok());
+    let res = set_identity_status(&mut context, &list, &identity, IdentityStatus::Allowed);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_error(res, AblError::IdentityBlocked);
+
+    advance_clock(&mut context, 0, 3600);
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
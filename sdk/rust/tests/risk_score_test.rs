@@ -0,0 +1,154 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, ListRegistry, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::{CreateListBuilder, SetRiskThresholdBuilder};
+use allow_block_list_client::types::{Mode, Storage};
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn set_risk_threshold(
+    context: &mut TestContext,
+    list: &Pubkey,
+    risk_threshold: u8,
+) -> TransactionResult {
+    let ix = SetRiskThresholdBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .risk_threshold(risk_threshold)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+#[tokio::test]
+async fn stores_score_and_threshold() {
+    let mut context = TestContext::new();
+
+    let list = context.create_risk_score_list(50);
+    let wallet = Pubkey::new_unique();
+    let wallet_entry = context.add_scored_wallet_to_list(&list, &wallet, 70);
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(list_data.mode, Mode::RiskScore as u8);
+    assert_eq!(list_data.risk_threshold, 50);
+
+    let entry_account = context.vm.get_account(&wallet_entry).unwrap();
+    let entry_data = WalletEntry::from_bytes(&entry_account.data).unwrap();
+    assert_eq!(entry_data.score, 70);
+}
+
+#[tokio::test]
+async fn blocks_wallets_at_or_above_threshold() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_risk_score_list(50);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let low = Keypair::new();
+    let _ = context.add_scored_wallet_to_list(&list, &low.pubkey(), 49);
+    let ta = context.create_token_account(&low);
+    let res = context.thaw_permissionless(&low.pubkey(), &ta).await;
+    assert!(res.is_ok());
+
+    let high = Keypair::new();
+    let _ = context.add_scored_wallet_to_list(&list, &high.pubkey(), 50);
+    let ta = context.create_token_account(&high);
+    let res = context.thaw_permissionless(&high.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletRiskTooHigh as u32)
+        )
+    );
+
+    // unscored wallets are not screened
+    let unlisted = Keypair::new();
+    let ta = context.create_token_account(&unlisted);
+    let res = context.thaw_permissionless(&unlisted.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn relaxing_threshold_unblocks_wallets() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_risk_score_list(50);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let _ = context.add_scored_wallet_to_list(&list, &wallet.pubkey(), 60);
+    let ta = context.create_token_account(&wallet);
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_err());
+
+    let res = set_risk_threshold(&mut context, &list, 61);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_set_threshold_on_non_risk_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+
+    let res = set_risk_threshold(&mut context, &list, 10);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidData as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_create_risk_score_bucket_list() {
+    let mut context = TestContext::new();
+
+    let seed = Pubkey::new_unique();
+    let (list, _) = ListConfig::find_pda(&context.auth.pubkey(), &seed);
+
+    let ix = CreateListBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .list_registry(ListRegistry::find_pda(&context.auth.pubkey()).0)
+        .mode(Mode::RiskScore)
+        .seed(seed)
+        .storage(Storage::Buckets)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    let res = context.vm.send_transaction(tx);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidStorage as u32)
+        )
+    );
+}
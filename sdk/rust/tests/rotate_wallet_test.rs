@@ -0,0 +1,268 @@
+pub mod program_test;
+use allow_block_list_client::accounts::{ListConfig, WalletEntry};
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::events::{parse_events, AblEvent};
+use allow_block_list_client::instructions::{
+    RotateWalletBuilder, SetListLimitsBuilder, SetSelfRotationBuilder,
+};
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::clock::Clock;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn set_self_rotation(context: &mut TestContext, list: &Pubkey, enabled: bool) -> TransactionResult {
+    let ix = SetSelfRotationBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .self_rotation(enabled)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Rotates `old_wallet` to `new_wallet`, co-signed by the list authority if `with_authority`.
+fn rotate(
+    context: &mut TestContext,
+    list: &Pubkey,
+    old_wallet: &Keypair,
+    new_wallet: &Keypair,
+    with_authority: bool,
+) -> TransactionResult {
+    let ix = RotateWalletBuilder::new()
+        .old_wallet(old_wallet.pubkey())
+        .new_wallet(new_wallet.pubkey())
+        .list_config(*list)
+        .old_entry(WalletEntry::find_pda(list, &old_wallet.pubkey()).0)
+        .new_entry(WalletEntry::find_pda(list, &new_wallet.pubkey()).0)
+        .authority(with_authority.then(|| context.auth.pubkey()))
+        .instruction();
+
+    let mut signers = vec![new_wallet.insecure_clone(), old_wallet.insecure_clone()];
+    if with_authority {
+        signers.push(context.auth.insecure_clone());
+    }
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&new_wallet.pubkey()),
+        &signers,
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+fn new_wallet(context: &mut TestContext) -> Keypair {
+    let wallet = Keypair::new();
+    let _ = context.vm.airdrop(&wallet.pubkey(), 1_000_000_000);
+    wallet
+}
+
+#[tokio::test]
+async fn rotates_wallet_with_authority() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let old_wallet = Keypair::new();
+    let old_entry = context.add_scored_wallet_to_list(&list, &old_wallet.pubkey(), 7);
+    let old_entry_lamports = context.vm.get_account(&old_entry).unwrap().lamports;
+    let new_wallet = new_wallet(&mut context);
+
+    let res = rotate(&mut context, &list, &old_wallet, &new_wallet, true);
+    assert_eq!(
+        parse_events(&res.unwrap().logs),
+        vec![
+            AblEvent::WalletRemoved {
+                list,
+                wallet: old_wallet.pubkey(),
+                signer: old_wallet.pubkey(),
+            },
+            AblEvent::WalletAdded {
+                list,
+                wallet: new_wallet.pubkey(),
+                signer: old_wallet.pubkey(),
+            },
+        ]
+    );
+
+    let old_account = context.vm.get_account(&old_entry).unwrap();
+    assert_eq!(old_account.lamports, 0);
+
+    let (new_entry, bump) = WalletEntry::find_pda(&list, &new_wallet.pubkey());
+    let new_account = context.vm.get_account(&new_entry).unwrap();
+    assert_eq!(new_account.lamports, old_entry_lamports);
+    let entry = WalletEntry::from_bytes(&new_account.data).unwrap();
+    assert_eq!(entry.wallet_address, new_wallet.pubkey());
+    assert_eq!(entry.list_config, list);
+    assert_eq!(entry.score, 7);
+    assert_eq!(entry.bump, bump);
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let config = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert_eq!(config.wallets_count, 1);
+}
+
+#[tokio::test]
+async fn rotated_wallet_waits_for_allow_delay() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let ix = SetListLimitsBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .max_changes_per_epoch(0)
+        .allow_delay(3600)
+        .instruction();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    assert!(context.vm.send_transaction(tx).is_ok());
+
+    let old_wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &old_wallet.pubkey());
+
+    // the old wallet took effect long ago
+    let mut clock = context.vm.get_sysvar::<Clock>();
+    clock.unix_timestamp += 86_400;
+    context.vm.set_sysvar(&clock);
+
+    let new_wallet = new_wallet(&mut context);
+    let res = rotate(&mut context, &list, &old_wallet, &new_wallet, true);
+    assert!(res.is_ok());
+
+    let new_entry = WalletEntry::find_pda(&list, &new_wallet.pubkey()).0;
+    let new_account = context.vm.get_account(&new_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&new_account.data).unwrap();
+    assert_eq!(entry.active_from, clock.unix_timestamp + 3600);
+}
+
+#[tokio::test]
+async fn fails_to_rotate_without_authority_by_default() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let old_wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &old_wallet.pubkey());
+    let new_wallet = new_wallet(&mut context);
+
+    let res = rotate(&mut context, &list, &old_wallet, &new_wallet, false);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidAuthority as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn rotates_wallet_on_its_own_with_self_rotation() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_self_rotation(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let old_wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &old_wallet.pubkey());
+    let new_wallet = new_wallet(&mut context);
+
+    let res = rotate(&mut context, &list, &old_wallet, &new_wallet, false);
+    assert!(res.is_ok());
+
+    let (new_entry, _) = WalletEntry::find_pda(&list, &new_wallet.pubkey());
+    let new_account = context.vm.get_account(&new_entry).unwrap();
+    let entry = WalletEntry::from_bytes(&new_account.data).unwrap();
+    assert_eq!(entry.wallet_address, new_wallet.pubkey());
+}
+
+#[tokio::test]
+async fn self_rotations_are_not_rate_limited() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let res = set_self_rotation(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let mut wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &wallet.pubkey());
+
+    let ix = SetListLimitsBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(list)
+        .max_changes_per_epoch(1)
+        .allow_delay(0)
+        .instruction();
+    let res = context.send(ix);
+    assert!(res.is_ok());
+
+    for _ in 0..3 {
+        let next_wallet = new_wallet(&mut context);
+        let res = rotate(&mut context, &list, &wallet, &next_wallet, false);
+        assert!(res.is_ok());
+        wallet = next_wallet;
+    }
+
+    // the authority still has its change left
+    let _ = context.add_wallet_to_list(&list, &Pubkey::new_unique());
+}
+
+#[tokio::test]
+async fn fails_to_enable_self_rotation_on_block_list() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = set_self_rotation(&mut context, &list, true);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::InvalidData as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_rotate_to_listed_wallet() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let old_wallet = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &old_wallet.pubkey());
+    let new_wallet = new_wallet(&mut context);
+    let _ = context.add_wallet_to_list(&list, &new_wallet.pubkey());
+
+    let res = rotate(&mut context, &list, &old_wallet, &new_wallet, true);
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::EntryAlreadyExists as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn fails_to_rotate_without_old_wallet_entry() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Allow);
+    let old_wallet = Keypair::new();
+    let new_wallet = new_wallet(&mut context);
+
+    let res = rotate(&mut context, &list, &old_wallet, &new_wallet, true);
+    assert!(res.is_err());
+}
@@ -0,0 +1,133 @@
+pub mod program_test;
+use allow_block_list_client::accounts::ListConfig;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::instructions::SetSelfThawOnlyBuilder;
+use allow_block_list_client::types::Mode;
+use litesvm::types::TransactionResult;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+use crate::program_test::TestContext;
+
+fn set_self_thaw_only(
+    context: &mut TestContext,
+    list: &Pubkey,
+    enabled: bool,
+) -> TransactionResult {
+    let ix = SetSelfThawOnlyBuilder::new()
+        .authority(context.auth.pubkey())
+        .list_config(*list)
+        .self_thaw_only(enabled)
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.auth.pubkey()),
+        &[context.auth.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+/// Thaws `token_account` with its owner initiating and paying for the thaw.
+async fn self_thaw(
+    context: &mut TestContext,
+    owner: &Keypair,
+    token_account: &Pubkey,
+) -> TransactionResult {
+    let ix = context
+        .get_thaw_permissionless_ix(&owner.pubkey(), &owner.pubkey(), token_account)
+        .await;
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&owner.pubkey()),
+        &[owner.insecure_clone()],
+        context.vm.latest_blockhash(),
+    );
+    context.vm.send_transaction(tx)
+}
+
+#[tokio::test]
+async fn stores_self_thaw_only() {
+    let mut context = TestContext::new();
+
+    let list = context.create_list(Mode::Block);
+    let res = set_self_thaw_only(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let list_account = context.vm.get_account(&list).unwrap();
+    let list_data = ListConfig::from_bytes(&list_account.data).unwrap();
+    assert!(list_data.self_thaw_only);
+}
+
+#[tokio::test]
+async fn denies_thaw_by_other_caller() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let ta = context.create_token_account(&wallet);
+
+    let res = set_self_thaw_only(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let res = context.thaw_permissionless(&wallet.pubkey(), &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::ThawNotByOwner as u32)
+        )
+    );
+}
+
+#[tokio::test]
+async fn lets_owner_thaw_itself() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let _ = context.vm.airdrop(&wallet.pubkey(), 1_000_000_000);
+    let ta = context.create_token_account(&wallet);
+
+    let res = set_self_thaw_only(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let res = self_thaw(&mut context, &wallet, &ta).await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn self_thawing_owner_is_still_checked() {
+    let mut context = TestContext::new();
+
+    let _ = context.setup_token_acl();
+    let list = context.create_list(Mode::Block);
+    let _ = context.setup_extra_metas(&[list]);
+
+    let wallet = Keypair::new();
+    let _ = context.vm.airdrop(&wallet.pubkey(), 1_000_000_000);
+    let _ = context.add_wallet_to_list(&list, &wallet.pubkey());
+    let ta = context.create_token_account(&wallet);
+
+    let res = set_self_thaw_only(&mut context, &list, true);
+    assert!(res.is_ok());
+
+    let res = self_thaw(&mut context, &wallet, &ta).await;
+    assert_eq!(
+        res.unwrap_err().err,
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(AblError::WalletBlocked as u32)
+        )
+    );
+}
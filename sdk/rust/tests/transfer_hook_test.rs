@@ -0,0 +1,109 @@
+pub mod program_test;
+use allow_block_list_client::errors::AblError;
+use allow_block_list_client::types::Mode;
+use solana_keypair::Keypair;
+use solana_sdk::signer::Signer;
+
+use crate::program_test::{assert_error, TestContext};
+
+#[tokio::test]
+async fn transfers_between_unblocked_wallets() {
+    let mut context = TestContext::new();
+
+    let token = context.create_transfer_hook_token();
+    let list = context.create_list(Mode::Block);
+    let res = context.setup_transfer_hook_extra_metas(&token, &[list]);
+    assert!(res.is_ok());
+
+    let sender = Keypair::new();
+    let recipient = Keypair::new();
+    let source = context.create_funded_token_account(&token, &sender.pubkey(), 100);
+    let destination = context.create_funded_token_account(&token, &recipient.pubkey(), 0);
+
+    let res = context
+        .transfer(&token, &sender, &source, &destination, 10)
+        .await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_transfer_from_blocked_source() {
+    let mut context = TestContext::new();
+
+    let token = context.create_transfer_hook_token();
+    let list = context.create_list(Mode::Block);
+    let res = context.setup_transfer_hook_extra_metas(&token, &[list]);
+    assert!(res.is_ok());
+
+    let sender = Keypair::new();
+    let recipient = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &sender.pubkey());
+    let source = context.create_funded_token_account(&token, &sender.pubkey(), 100);
+    let destination = context.create_funded_token_account(&token, &recipient.pubkey(), 0);
+
+    let res = context
+        .transfer(&token, &sender, &source, &destination, 10)
+        .await;
+    assert_error(res, AblError::WalletBlocked);
+}
+
+#[tokio::test]
+async fn fails_to_transfer_to_blocked_destination() {
+    let mut context = TestContext::new();
+
+    let token = context.create_transfer_hook_token();
+    let list = context.create_list(Mode::Block);
+    let res = context.setup_transfer_hook_extra_metas(&token, &[list]);
+    assert!(res.is_ok());
+
+    let sender = Keypair::new();
+    let recipient = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &recipient.pubkey());
+    let source = context.create_funded_token_account(&token, &sender.pubkey(), 100);
+    let destination = context.create_funded_token_account(&token, &recipient.pubkey(), 0);
+
+    let res = context
+        .transfer(&token, &sender, &source, &destination, 10)
+        .await;
+    assert_error(res, AblError::WalletBlocked);
+}
+
+#[tokio::test]
+async fn requires_both_wallets_on_allow_list() {
+    let mut context = TestContext::new();
+
+    let token = context.create_transfer_hook_token();
+    let list = context.create_list(Mode::Allow);
+    let res = context.setup_transfer_hook_extra_metas(&token, &[list]);
+    assert!(res.is_ok());
+
+    let sender = Keypair::new();
+    let recipient = Keypair::new();
+    let _ = context.add_wallet_to_list(&list, &sender.pubkey());
+    let source = context.create_funded_token_account(&token, &sender.pubkey(), 100);
+    let destination = context.create_funded_token_account(&token, &recipient.pubkey(), 0);
+
+    let res = context
+        .transfer(&token, &sender, &source, &destination, 10)
+        .await;
+    assert_error(res, AblError::WalletNotAllowed);
+
+    let _ = context.add_wallet_to_list(&list, &recipient.pubkey());
+
+    let res = context
+        .transfer(&token, &sender, &source, &destination, 10)
+        .await;
+    assert!(res.is_ok());
+}
+
+#[tokio::test]
+async fn fails_to_setup_extra_metas_without_hook_authority() {
+    let mut context = TestContext::new();
+
+    let mut token = context.create_transfer_hook_token();
+    let list = context.create_list(Mode::Block);
+
+    token.auth = context.auth.insecure_clone();
+    let res = context.setup_transfer_hook_extra_metas(&token, &[list]);
+    assert_error(res, AblError::InvalidAuthority);
+}
@@ -15,6 +15,10 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -47,6 +51,39 @@ export type ListConfig = {
   seed: Address;
   mode: number;
   walletsCount: bigint;
+  version: number;
+  storage: number;
+  riskThreshold: number;
+  identities: number;
+  requiredAttributes: bigint;
+  forbiddenAttributes: bigint;
+  attributesSource: Address;
+  maxChangesPerEpoch: bigint;
+  changesEpoch: bigint;
+  changesInEpoch: bigint;
+  allowDelay: bigint;
+  guardian: Address;
+  paused: boolean;
+  failOpen: boolean;
+  activeFrom: bigint;
+  activeUntil: bigint;
+  denyWhenInactive: boolean;
+  mint: Address;
+  auditLog: boolean;
+  selfRotation: boolean;
+  openEnrollment: boolean;
+  enrollmentDeposit: bigint;
+  selfThawOnly: boolean;
+  metadata: boolean;
+  depositVault: boolean;
+  pendingRequiredAttributes: bigint;
+  pendingForbiddenAttributes: bigint;
+  pendingAttributesSource: Address;
+  pendingPolicyFrom: bigint;
+  pendingMaxChangesPerEpoch: bigint;
+  pendingAllowDelay: bigint;
+  pendingLimitsFrom: bigint;
+  identitiesCount: bigint;
 };
 
 export type ListConfigArgs = {
@@ -54,6 +91,39 @@ export type ListConfigArgs = {
   seed: Address;
   mode: number;
   walletsCount: number | bigint;
+  version: number;
+  storage: number;
+  riskThreshold: number;
+  identities: number;
+  requiredAttributes: number | bigint;
+  forbiddenAttributes: number | bigint;
+  attributesSource: Address;
+  maxChangesPerEpoch: number | bigint;
+  changesEpoch: number | bigint;
+  changesInEpoch: number | bigint;
+  allowDelay: number | bigint;
+  guardian: Address;
+  paused: boolean;
+  failOpen: boolean;
+  activeFrom: number | bigint;
+  activeUntil: number | bigint;
+  denyWhenInactive: boolean;
+  mint: Address;
+  auditLog: boolean;
+  selfRotation: boolean;
+  openEnrollment: boolean;
+  enrollmentDeposit: number | bigint;
+  selfThawOnly: boolean;
+  metadata: boolean;
+  depositVault: boolean;
+  pendingRequiredAttributes: number | bigint;
+  pendingForbiddenAttributes: number | bigint;
+  pendingAttributesSource: Address;
+  pendingPolicyFrom: number | bigint;
+  pendingMaxChangesPerEpoch: number | bigint;
+  pendingAllowDelay: number | bigint;
+  pendingLimitsFrom: number | bigint;
+  identitiesCount: number | bigint;
 };
 
 export function getListConfigEncoder(): Encoder<ListConfigArgs> {
@@ -64,6 +134,39 @@ export function getListConfigEncoder(): Encoder<ListConfigArgs> {
       ['seed', getAddressEncoder()],
       ['mode', getU8Encoder()],
       ['walletsCount', getU64Encoder()],
+      ['version', getU8Encoder()],
+      ['storage', getU8Encoder()],
+      ['riskThreshold', getU8Encoder()],
+      ['identities', getU8Encoder()],
+      ['requiredAttributes', getU64Encoder()],
+      ['forbiddenAttributes', getU64Encoder()],
+      ['attributesSource', getAddressEncoder()],
+      ['maxChangesPerEpoch', getU64Encoder()],
+      ['changesEpoch', getU64Encoder()],
+      ['changesInEpoch', getU64Encoder()],
+      ['allowDelay', getU64Encoder()],
+      ['guardian', getAddressEncoder()],
+      ['paused', getBooleanEncoder()],
+      ['failOpen', getBooleanEncoder()],
+      ['activeFrom', getI64Encoder()],
+      ['activeUntil', getI64Encoder()],
+      ['denyWhenInactive', getBooleanEncoder()],
+      ['mint', getAddressEncoder()],
+      ['auditLog', getBooleanEncoder()],
+      ['selfRotation', getBooleanEncoder()],
+      ['openEnrollment', getBooleanEncoder()],
+      ['enrollmentDeposit', getU64Encoder()],
+      ['selfThawOnly', getBooleanEncoder()],
+      ['metadata', getBooleanEncoder()],
+      ['depositVault', getBooleanEncoder()],
+      ['pendingRequiredAttributes', getU64Encoder()],
+      ['pendingForbiddenAttributes', getU64Encoder()],
+      ['pendingAttributesSource', getAddressEncoder()],
+      ['pendingPolicyFrom', getI64Encoder()],
+      ['pendingMaxChangesPerEpoch', getU64Encoder()],
+      ['pendingAllowDelay', getU64Encoder()],
+      ['pendingLimitsFrom', getI64Encoder()],
+      ['identitiesCount', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: LIST_CONFIG_DISCRIMINATOR })
   );
@@ -76,6 +179,39 @@ export function getListConfigDecoder(): Decoder<ListConfig> {
     ['seed', getAddressDecoder()],
     ['mode', getU8Decoder()],
     ['walletsCount', getU64Decoder()],
+    ['version', getU8Decoder()],
+    ['storage', getU8Decoder()],
+    ['riskThreshold', getU8Decoder()],
+    ['identities', getU8Decoder()],
+    ['requiredAttributes', getU64Decoder()],
+    ['forbiddenAttributes', getU64Decoder()],
+    ['attributesSource', getAddressDecoder()],
+    ['maxChangesPerEpoch', getU64Decoder()],
+    ['changesEpoch', getU64Decoder()],
+    ['changesInEpoch', getU64Decoder()],
+    ['allowDelay', getU64Decoder()],
+    ['guardian', getAddressDecoder()],
+    ['paused', getBooleanDecoder()],
+    ['failOpen', getBooleanDecoder()],
+    ['activeFrom', getI64Decoder()],
+    ['activeUntil', getI64Decoder()],
+    ['denyWhenInactive', getBooleanDecoder()],
+    ['mint', getAddressDecoder()],
+    ['auditLog', getBooleanDecoder()],
+    ['selfRotation', getBooleanDecoder()],
+    ['openEnrollment', getBooleanDecoder()],
+    ['enrollmentDeposit', getU64Decoder()],
+    ['selfThawOnly', getBooleanDecoder()],
+    ['metadata', getBooleanDecoder()],
+    ['depositVault', getBooleanDecoder()],
+    ['pendingRequiredAttributes', getU64Decoder()],
+    ['pendingForbiddenAttributes', getU64Decoder()],
+    ['pendingAttributesSource', getAddressDecoder()],
+    ['pendingPolicyFrom', getI64Decoder()],
+    ['pendingMaxChangesPerEpoch', getU64Decoder()],
+    ['pendingAllowDelay', getU64Decoder()],
+    ['pendingLimitsFrom', getI64Decoder()],
+    ['identitiesCount', getU64Decoder()],
   ]);
 }
 
@@ -137,7 +273,7 @@ export async function fetchAllMaybeListConfig(
 }
 
 export function getListConfigSize(): number {
-  return 74;
+  return 343;
 }
 
 export async function fetchListConfigFromSeeds(
@@ -15,8 +15,14 @@ import {
   fetchEncodedAccounts,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -43,9 +49,28 @@ export type WalletEntry = {
   discriminator: number;
   walletAddress: Address;
   listConfig: Address;
+  version: number;
+  score: number;
+  identity: Address;
+  attributes: bigint;
+  activeFrom: bigint;
+  bump: number;
+  enrolled: boolean;
+  deposit: bigint;
 };
 
-export type WalletEntryArgs = { walletAddress: Address; listConfig: Address };
+export type WalletEntryArgs = {
+  walletAddress: Address;
+  listConfig: Address;
+  version: number;
+  score: number;
+  identity: Address;
+  attributes: number | bigint;
+  activeFrom: number | bigint;
+  bump: number;
+  enrolled: boolean;
+  deposit: number | bigint;
+};
 
 export function getWalletEntryEncoder(): Encoder<WalletEntryArgs> {
   return transformEncoder(
@@ -53,6 +78,14 @@ export function getWalletEntryEncoder(): Encoder<WalletEntryArgs> {
       ['discriminator', getU8Encoder()],
       ['walletAddress', getAddressEncoder()],
       ['listConfig', getAddressEncoder()],
+      ['version', getU8Encoder()],
+      ['score', getU8Encoder()],
+      ['identity', getAddressEncoder()],
+      ['attributes', getU64Encoder()],
+      ['activeFrom', getI64Encoder()],
+      ['bump', getU8Encoder()],
+      ['enrolled', getBooleanEncoder()],
+      ['deposit', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: WALLET_ENTRY_DISCRIMINATOR })
   );
@@ -63,6 +96,14 @@ export function getWalletEntryDecoder(): Decoder<WalletEntry> {
     ['discriminator', getU8Decoder()],
     ['walletAddress', getAddressDecoder()],
     ['listConfig', getAddressDecoder()],
+    ['version', getU8Decoder()],
+    ['score', getU8Decoder()],
+    ['identity', getAddressDecoder()],
+    ['attributes', getU64Decoder()],
+    ['activeFrom', getI64Decoder()],
+    ['bump', getU8Decoder()],
+    ['enrolled', getBooleanDecoder()],
+    ['deposit', getU64Decoder()],
   ]);
 }
 
@@ -124,7 +165,7 @@ export async function fetchAllMaybeWalletEntry(
 }
 
 export function getWalletEntrySize(): number {
-  return 65;
+  return 125;
 }
 
 export async function fetchWalletEntryFromSeeds(
@@ -10,6 +10,8 @@ import {
   combineCodec,
   getStructDecoder,
   getStructEncoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
@@ -45,6 +47,8 @@ export type AddWalletInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMintConfig extends string | IAccountMeta<string> = string,
+  TAccountAuditLog extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -66,23 +70,49 @@ export type AddWalletInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMintConfig extends string
+        ? ReadonlyAccount<TAccountMintConfig>
+        : TAccountMintConfig,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
       ...TRemainingAccounts,
     ]
   >;
 
-export type AddWalletInstructionData = { discriminator: number };
+export type AddWalletInstructionData = {
+  discriminator: number;
+  score: number;
+  attributes: bigint;
+};
 
-export type AddWalletInstructionDataArgs = {};
+export type AddWalletInstructionDataArgs = {
+  score?: number;
+  attributes?: number | bigint;
+};
 
 export function getAddWalletInstructionDataEncoder(): Encoder<AddWalletInstructionDataArgs> {
   return transformEncoder(
-    getStructEncoder([['discriminator', getU8Encoder()]]),
-    (value) => ({ ...value, discriminator: ADD_WALLET_DISCRIMINATOR })
+    getStructEncoder([
+      ['discriminator', getU8Encoder()],
+      ['score', getU8Encoder()],
+      ['attributes', getU64Encoder()],
+    ]),
+    (value) => ({
+      ...value,
+      discriminator: ADD_WALLET_DISCRIMINATOR,
+      score: value.score ?? 0,
+      attributes: value.attributes ?? 0,
+    })
   );
 }
 
 export function getAddWalletInstructionDataDecoder(): Decoder<AddWalletInstructionData> {
-  return getStructDecoder([['discriminator', getU8Decoder()]]);
+  return getStructDecoder([
+    ['discriminator', getU8Decoder()],
+    ['score', getU8Decoder()],
+    ['attributes', getU64Decoder()],
+  ]);
 }
 
 export function getAddWalletInstructionDataCodec(): Codec<
@@ -101,12 +131,18 @@ export type AddWalletInput<
   TAccountWallet extends string = string,
   TAccountWalletEntry extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMintConfig extends string = string,
+  TAccountAuditLog extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   listConfig: Address<TAccountListConfig>;
   wallet: Address<TAccountWallet>;
   walletEntry: Address<TAccountWalletEntry>;
   systemProgram?: Address<TAccountSystemProgram>;
+  mintConfig?: Address<TAccountMintConfig>;
+  auditLog?: Address<TAccountAuditLog>;
+  score?: AddWalletInstructionDataArgs['score'];
+  attributes?: AddWalletInstructionDataArgs['attributes'];
 };
 
 export function getAddWalletInstruction<
@@ -115,6 +151,8 @@ export function getAddWalletInstruction<
   TAccountWallet extends string,
   TAccountWalletEntry extends string,
   TAccountSystemProgram extends string,
+  TAccountMintConfig extends string,
+  TAccountAuditLog extends string,
   TProgramAddress extends Address = typeof ABL_PROGRAM_ADDRESS,
 >(
   input: AddWalletInput<
@@ -122,7 +160,9 @@ export function getAddWalletInstruction<
     TAccountListConfig,
     TAccountWallet,
     TAccountWalletEntry,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMintConfig,
+    TAccountAuditLog
   >,
   config?: { programAddress?: TProgramAddress }
 ): AddWalletInstruction<
@@ -131,7 +171,9 @@ export function getAddWalletInstruction<
   TAccountListConfig,
   TAccountWallet,
   TAccountWalletEntry,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMintConfig,
+  TAccountAuditLog
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ABL_PROGRAM_ADDRESS;
@@ -143,12 +185,17 @@ export function getAddWalletInstruction<
     wallet: { value: input.wallet ?? null, isWritable: false },
     walletEntry: { value: input.walletEntry ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    mintConfig: { value: input.mintConfig ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
     ResolvedAccount
   >;
 
+  // Original args.
+  const args = { ...input };
+
   // Resolve default values.
   if (!accounts.systemProgram.value) {
     accounts.systemProgram.value =
@@ -163,16 +210,22 @@ export function getAddWalletInstruction<
       getAccountMeta(accounts.wallet),
       getAccountMeta(accounts.walletEntry),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.mintConfig),
+      getAccountMeta(accounts.auditLog),
     ],
     programAddress,
-    data: getAddWalletInstructionDataEncoder().encode({}),
+    data: getAddWalletInstructionDataEncoder().encode(
+      args as AddWalletInstructionDataArgs
+    ),
   } as AddWalletInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountListConfig,
     TAccountWallet,
     TAccountWalletEntry,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMintConfig,
+    TAccountAuditLog
   >;
 
   return instruction;
@@ -189,6 +242,8 @@ export type ParsedAddWalletInstruction<
     wallet: TAccountMetas[2];
     walletEntry: TAccountMetas[3];
     systemProgram: TAccountMetas[4];
+    mintConfig?: TAccountMetas[5] | undefined;
+    auditLog?: TAccountMetas[6] | undefined;
   };
   data: AddWalletInstructionData;
 };
@@ -201,7 +256,7 @@ export function parseAddWalletInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedAddWalletInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -211,6 +266,12 @@ export function parseAddWalletInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ABL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -219,6 +280,8 @@ export function parseAddWalletInstruction<
       wallet: getNextAccount(),
       walletEntry: getNextAccount(),
       systemProgram: getNextAccount(),
+      mintConfig: getNextOptionalAccount(),
+      auditLog: getNextOptionalAccount(),
     },
     data: getAddWalletInstructionDataDecoder().decode(instruction.data),
   };
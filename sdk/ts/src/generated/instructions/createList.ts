@@ -8,8 +8,14 @@
 
 import {
   combineCodec,
+  fixDecoderSize,
+  fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getStructDecoder,
   getStructEncoder,
   getU8Decoder,
@@ -25,6 +31,7 @@ import {
   type IInstructionWithAccounts,
   type IInstructionWithData,
   type ReadonlyAccount,
+  type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
   type WritableSignerAccount,
@@ -32,10 +39,15 @@ import {
 import { ABL_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
+  Storage,
   getModeDecoder,
   getModeEncoder,
+  getStorageDecoder,
+  getStorageEncoder,
   type Mode,
   type ModeArgs,
+  type Storage,
+  type StorageArgs,
 } from '../types';
 
 export const CREATE_LIST_DISCRIMINATOR = 1;
@@ -51,6 +63,7 @@ export type CreateListInstruction<
   TAccountSystemProgram extends
     | string
     | IAccountMeta<string> = '11111111111111111111111111111111',
+  TAccountListRegistry extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -66,6 +79,9 @@ export type CreateListInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountListRegistry extends string
+        ? WritableAccount<TAccountListRegistry>
+        : TAccountListRegistry,
       ...TRemainingAccounts,
     ]
   >;
@@ -74,9 +90,20 @@ export type CreateListInstructionData = {
   discriminator: number;
   mode: Mode;
   seed: Address;
+  storage: Storage;
+  riskThreshold: number;
+  identities: boolean;
+  label: ReadonlyUint8Array;
 };
 
-export type CreateListInstructionDataArgs = { mode: ModeArgs; seed: Address };
+export type CreateListInstructionDataArgs = {
+  mode: ModeArgs;
+  seed: Address;
+  storage?: StorageArgs;
+  riskThreshold?: number;
+  identities?: boolean;
+  label?: ReadonlyUint8Array;
+};
 
 export function getCreateListInstructionDataEncoder(): Encoder<CreateListInstructionDataArgs> {
   return transformEncoder(
@@ -84,8 +111,19 @@ export function getCreateListInstructionDataEncoder(): Encoder<CreateListInstruc
       ['discriminator', getU8Encoder()],
       ['mode', getModeEncoder()],
       ['seed', getAddressEncoder()],
+      ['storage', getStorageEncoder()],
+      ['riskThreshold', getU8Encoder()],
+      ['identities', getBooleanEncoder()],
+      ['label', fixEncoderSize(getBytesEncoder(), 32)],
     ]),
-    (value) => ({ ...value, discriminator: CREATE_LIST_DISCRIMINATOR })
+    (value) => ({
+      ...value,
+      discriminator: CREATE_LIST_DISCRIMINATOR,
+      storage: value.storage ?? Storage.Entries,
+      riskThreshold: value.riskThreshold ?? 0,
+      identities: value.identities ?? false,
+      label: value.label ?? new Uint8Array(32),
+    })
   );
 }
 
@@ -94,6 +132,10 @@ export function getCreateListInstructionDataDecoder(): Decoder<CreateListInstruc
     ['discriminator', getU8Decoder()],
     ['mode', getModeDecoder()],
     ['seed', getAddressDecoder()],
+    ['storage', getStorageDecoder()],
+    ['riskThreshold', getU8Decoder()],
+    ['identities', getBooleanDecoder()],
+    ['label', fixDecoderSize(getBytesDecoder(), 32)],
   ]);
 }
 
@@ -111,31 +153,40 @@ export type CreateListInput<
   TAccountAuthority extends string = string,
   TAccountListConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountListRegistry extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   listConfig: Address<TAccountListConfig>;
   systemProgram?: Address<TAccountSystemProgram>;
+  listRegistry: Address<TAccountListRegistry>;
   mode: CreateListInstructionDataArgs['mode'];
   seed: CreateListInstructionDataArgs['seed'];
+  storage?: CreateListInstructionDataArgs['storage'];
+  riskThreshold?: CreateListInstructionDataArgs['riskThreshold'];
+  identities?: CreateListInstructionDataArgs['identities'];
+  label?: CreateListInstructionDataArgs['label'];
 };
 
 export function getCreateListInstruction<
   TAccountAuthority extends string,
   TAccountListConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountListRegistry extends string,
   TProgramAddress extends Address = typeof ABL_PROGRAM_ADDRESS,
 >(
   input: CreateListInput<
     TAccountAuthority,
     TAccountListConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountListRegistry
   >,
   config?: { programAddress?: TProgramAddress }
 ): CreateListInstruction<
   TProgramAddress,
   TAccountAuthority,
   TAccountListConfig,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountListRegistry
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ABL_PROGRAM_ADDRESS;
@@ -145,6 +196,7 @@ export function getCreateListInstruction<
     authority: { value: input.authority ?? null, isWritable: true },
     listConfig: { value: input.listConfig ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    listRegistry: { value: input.listRegistry ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -166,6 +218,7 @@ export function getCreateListInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.listConfig),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.listRegistry),
     ],
     programAddress,
     data: getCreateListInstructionDataEncoder().encode(
@@ -175,7 +228,8 @@ export function getCreateListInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountListConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountListRegistry
   >;
 
   return instruction;
@@ -190,6 +244,7 @@ export type ParsedCreateListInstruction<
     authority: TAccountMetas[0];
     listConfig: TAccountMetas[1];
     systemProgram: TAccountMetas[2];
+    listRegistry: TAccountMetas[3];
   };
   data: CreateListInstructionData;
 };
@@ -202,7 +257,7 @@ export function parseCreateListInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedCreateListInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -218,6 +273,7 @@ export function parseCreateListInstruction<
       authority: getNextAccount(),
       listConfig: getNextAccount(),
       systemProgram: getNextAccount(),
+      listRegistry: getNextAccount(),
     },
     data: getCreateListInstructionDataDecoder().decode(instruction.data),
   };
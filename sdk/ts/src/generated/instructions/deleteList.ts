@@ -22,6 +22,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type TransactionSigner,
   type WritableAccount,
   type WritableSignerAccount,
@@ -39,6 +40,11 @@ export type DeleteListInstruction<
   TProgram extends string = typeof ABL_PROGRAM_ADDRESS,
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountListConfig extends string | IAccountMeta<string> = string,
+  TAccountMintConfig extends string | IAccountMeta<string> = string,
+  TAccountAuditLog extends string | IAccountMeta<string> = string,
+  TAccountListRegistry extends string | IAccountMeta<string> = string,
+  TAccountListMetadata extends string | IAccountMeta<string> = string,
+  TAccountDepositVault extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -51,6 +57,21 @@ export type DeleteListInstruction<
       TAccountListConfig extends string
         ? WritableAccount<TAccountListConfig>
         : TAccountListConfig,
+      TAccountMintConfig extends string
+        ? ReadonlyAccount<TAccountMintConfig>
+        : TAccountMintConfig,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountListRegistry extends string
+        ? WritableAccount<TAccountListRegistry>
+        : TAccountListRegistry,
+      TAccountListMetadata extends string
+        ? WritableAccount<TAccountListMetadata>
+        : TAccountListMetadata,
+      TAccountDepositVault extends string
+        ? WritableAccount<TAccountDepositVault>
+        : TAccountDepositVault,
       ...TRemainingAccounts,
     ]
   >;
@@ -83,22 +104,50 @@ export function getDeleteListInstructionDataCodec(): Codec<
 export type DeleteListInput<
   TAccountAuthority extends string = string,
   TAccountListConfig extends string = string,
+  TAccountMintConfig extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountListRegistry extends string = string,
+  TAccountListMetadata extends string = string,
+  TAccountDepositVault extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   listConfig: Address<TAccountListConfig>;
+  mintConfig?: Address<TAccountMintConfig>;
+  auditLog?: Address<TAccountAuditLog>;
+  listRegistry: Address<TAccountListRegistry>;
+  listMetadata?: Address<TAccountListMetadata>;
+  depositVault?: Address<TAccountDepositVault>;
 };
 
 export function getDeleteListInstruction<
   TAccountAuthority extends string,
   TAccountListConfig extends string,
+  TAccountMintConfig extends string,
+  TAccountAuditLog extends string,
+  TAccountListRegistry extends string,
+  TAccountListMetadata extends string,
+  TAccountDepositVault extends string,
   TProgramAddress extends Address = typeof ABL_PROGRAM_ADDRESS,
 >(
-  input: DeleteListInput<TAccountAuthority, TAccountListConfig>,
+  input: DeleteListInput<
+    TAccountAuthority,
+    TAccountListConfig,
+    TAccountMintConfig,
+    TAccountAuditLog,
+    TAccountListRegistry,
+    TAccountListMetadata,
+    TAccountDepositVault
+  >,
   config?: { programAddress?: TProgramAddress }
 ): DeleteListInstruction<
   TProgramAddress,
   TAccountAuthority,
-  TAccountListConfig
+  TAccountListConfig,
+  TAccountMintConfig,
+  TAccountAuditLog,
+  TAccountListRegistry,
+  TAccountListMetadata,
+  TAccountDepositVault
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ABL_PROGRAM_ADDRESS;
@@ -107,6 +156,11 @@ export function getDeleteListInstruction<
   const originalAccounts = {
     authority: { value: input.authority ?? null, isWritable: true },
     listConfig: { value: input.listConfig ?? null, isWritable: true },
+    mintConfig: { value: input.mintConfig ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    listRegistry: { value: input.listRegistry ?? null, isWritable: true },
+    listMetadata: { value: input.listMetadata ?? null, isWritable: true },
+    depositVault: { value: input.depositVault ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -118,13 +172,23 @@ export function getDeleteListInstruction<
     accounts: [
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.listConfig),
+      getAccountMeta(accounts.mintConfig),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.listRegistry),
+      getAccountMeta(accounts.listMetadata),
+      getAccountMeta(accounts.depositVault),
     ],
     programAddress,
     data: getDeleteListInstructionDataEncoder().encode({}),
   } as DeleteListInstruction<
     TProgramAddress,
     TAccountAuthority,
-    TAccountListConfig
+    TAccountListConfig,
+    TAccountMintConfig,
+    TAccountAuditLog,
+    TAccountListRegistry,
+    TAccountListMetadata,
+    TAccountDepositVault
   >;
 
   return instruction;
@@ -138,6 +202,11 @@ export type ParsedDeleteListInstruction<
   accounts: {
     authority: TAccountMetas[0];
     listConfig: TAccountMetas[1];
+    mintConfig?: TAccountMetas[2] | undefined;
+    auditLog?: TAccountMetas[3] | undefined;
+    listRegistry: TAccountMetas[4];
+    listMetadata?: TAccountMetas[5] | undefined;
+    depositVault?: TAccountMetas[6] | undefined;
   };
   data: DeleteListInstructionData;
 };
@@ -150,7 +219,7 @@ export function parseDeleteListInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedDeleteListInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -160,11 +229,22 @@ export function parseDeleteListInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ABL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       authority: getNextAccount(),
       listConfig: getNextAccount(),
+      mintConfig: getNextOptionalAccount(),
+      auditLog: getNextOptionalAccount(),
+      listRegistry: getNextAccount(),
+      listMetadata: getNextOptionalAccount(),
+      depositVault: getNextOptionalAccount(),
     },
     data: getDeleteListInstructionDataDecoder().decode(instruction.data),
   };
@@ -6,8 +6,36 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addBucketWallets';
 export * from './addWallet';
+export * from './addWalletIdempotent';
+export * from './createAuditLog';
+export * from './createIdentity';
 export * from './createList';
+export * from './createMintList';
 export * from './deleteList';
+export * from './enroll';
+export * from './evictWallet';
+export * from './forceDeleteList';
+export * from './isWalletPermitted';
+export * from './linkWalletIdentity';
+export * from './migrateAccount';
+export * from './pruneListRegistry';
+export * from './reclaimWalletEntry';
+export * from './removeBucketWallets';
 export * from './removeWallet';
+export * from './rotateWallet';
+export * from './setActivationWindow';
+export * from './setAttributePolicy';
+export * from './setGuardian';
+export * from './setIdentityStatus';
+export * from './setListLimits';
+export * from './setListPaused';
+export * from './setOpenEnrollment';
+export * from './setRiskThreshold';
+export * from './setSelfRotation';
+export * from './setSelfThawOnly';
+export * from './setWalletAttributes';
 export * from './setupExtraMetas';
+export * from './setupTransferHookExtraMetas';
+export * from './updateListMetadata';
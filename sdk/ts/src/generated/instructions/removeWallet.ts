@@ -22,6 +22,7 @@ import {
   type IInstruction,
   type IInstructionWithAccounts,
   type IInstructionWithData,
+  type ReadonlyAccount,
   type TransactionSigner,
   type WritableAccount,
   type WritableSignerAccount,
@@ -40,6 +41,9 @@ export type RemoveWalletInstruction<
   TAccountAuthority extends string | IAccountMeta<string> = string,
   TAccountListConfig extends string | IAccountMeta<string> = string,
   TAccountWalletEntry extends string | IAccountMeta<string> = string,
+  TAccountMintConfig extends string | IAccountMeta<string> = string,
+  TAccountAuditLog extends string | IAccountMeta<string> = string,
+  TAccountListAuthority extends string | IAccountMeta<string> = string,
   TRemainingAccounts extends readonly IAccountMeta<string>[] = [],
 > = IInstruction<TProgram> &
   IInstructionWithData<Uint8Array> &
@@ -55,6 +59,15 @@ export type RemoveWalletInstruction<
       TAccountWalletEntry extends string
         ? WritableAccount<TAccountWalletEntry>
         : TAccountWalletEntry,
+      TAccountMintConfig extends string
+        ? ReadonlyAccount<TAccountMintConfig>
+        : TAccountMintConfig,
+      TAccountAuditLog extends string
+        ? WritableAccount<TAccountAuditLog>
+        : TAccountAuditLog,
+      TAccountListAuthority extends string
+        ? WritableAccount<TAccountListAuthority>
+        : TAccountListAuthority,
       ...TRemainingAccounts,
     ]
   >;
@@ -88,29 +101,44 @@ export type RemoveWalletInput<
   TAccountAuthority extends string = string,
   TAccountListConfig extends string = string,
   TAccountWalletEntry extends string = string,
+  TAccountMintConfig extends string = string,
+  TAccountAuditLog extends string = string,
+  TAccountListAuthority extends string = string,
 > = {
   authority: TransactionSigner<TAccountAuthority>;
   listConfig: Address<TAccountListConfig>;
   walletEntry: Address<TAccountWalletEntry>;
+  mintConfig?: Address<TAccountMintConfig>;
+  auditLog?: Address<TAccountAuditLog>;
+  listAuthority?: Address<TAccountListAuthority>;
 };
 
 export function getRemoveWalletInstruction<
   TAccountAuthority extends string,
   TAccountListConfig extends string,
   TAccountWalletEntry extends string,
+  TAccountMintConfig extends string,
+  TAccountAuditLog extends string,
+  TAccountListAuthority extends string,
   TProgramAddress extends Address = typeof ABL_PROGRAM_ADDRESS,
 >(
   input: RemoveWalletInput<
     TAccountAuthority,
     TAccountListConfig,
-    TAccountWalletEntry
+    TAccountWalletEntry,
+    TAccountMintConfig,
+    TAccountAuditLog,
+    TAccountListAuthority
   >,
   config?: { programAddress?: TProgramAddress }
 ): RemoveWalletInstruction<
   TProgramAddress,
   TAccountAuthority,
   TAccountListConfig,
-  TAccountWalletEntry
+  TAccountWalletEntry,
+  TAccountMintConfig,
+  TAccountAuditLog,
+  TAccountListAuthority
 > {
   // Program address.
   const programAddress = config?.programAddress ?? ABL_PROGRAM_ADDRESS;
@@ -120,6 +148,9 @@ export function getRemoveWalletInstruction<
     authority: { value: input.authority ?? null, isWritable: true },
     listConfig: { value: input.listConfig ?? null, isWritable: true },
     walletEntry: { value: input.walletEntry ?? null, isWritable: true },
+    mintConfig: { value: input.mintConfig ?? null, isWritable: false },
+    auditLog: { value: input.auditLog ?? null, isWritable: true },
+    listAuthority: { value: input.listAuthority ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -132,6 +163,9 @@ export function getRemoveWalletInstruction<
       getAccountMeta(accounts.authority),
       getAccountMeta(accounts.listConfig),
       getAccountMeta(accounts.walletEntry),
+      getAccountMeta(accounts.mintConfig),
+      getAccountMeta(accounts.auditLog),
+      getAccountMeta(accounts.listAuthority),
     ],
     programAddress,
     data: getRemoveWalletInstructionDataEncoder().encode({}),
@@ -139,7 +173,10 @@ export function getRemoveWalletInstruction<
     TProgramAddress,
     TAccountAuthority,
     TAccountListConfig,
-    TAccountWalletEntry
+    TAccountWalletEntry,
+    TAccountMintConfig,
+    TAccountAuditLog,
+    TAccountListAuthority
   >;
 
   return instruction;
@@ -154,6 +191,9 @@ export type ParsedRemoveWalletInstruction<
     authority: TAccountMetas[0];
     listConfig: TAccountMetas[1];
     walletEntry: TAccountMetas[2];
+    mintConfig?: TAccountMetas[3] | undefined;
+    auditLog?: TAccountMetas[4] | undefined;
+    listAuthority?: TAccountMetas[5] | undefined;
   };
   data: RemoveWalletInstructionData;
 };
@@ -166,7 +206,7 @@ export function parseRemoveWalletInstruction<
     IInstructionWithAccounts<TAccountMetas> &
     IInstructionWithData<Uint8Array>
 ): ParsedRemoveWalletInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 3) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -176,12 +216,21 @@ export function parseRemoveWalletInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === ABL_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       authority: getNextAccount(),
       listConfig: getNextAccount(),
       walletEntry: getNextAccount(),
+      mintConfig: getNextOptionalAccount(),
+      auditLog: getNextOptionalAccount(),
+      listAuthority: getNextOptionalAccount(),
     },
     data: getRemoveWalletInstructionDataDecoder().decode(instruction.data),
   };
@@ -6,5 +6,12 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './auditLog';
+export * from './bucket';
+export * from './depositVault';
+export * from './identity';
 export * from './listConfig';
+export * from './listMetadata';
+export * from './listRegistry';
+export * from './mintListConfig';
 export * from './walletEntry';
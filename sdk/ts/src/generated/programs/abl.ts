@@ -13,11 +13,39 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedAddBucketWalletsInstruction,
+  type ParsedAddWalletIdempotentInstruction,
   type ParsedAddWalletInstruction,
+  type ParsedCreateAuditLogInstruction,
+  type ParsedCreateIdentityInstruction,
   type ParsedCreateListInstruction,
+  type ParsedCreateMintListInstruction,
   type ParsedDeleteListInstruction,
+  type ParsedEnrollInstruction,
+  type ParsedEvictWalletInstruction,
+  type ParsedForceDeleteListInstruction,
+  type ParsedIsWalletPermittedInstruction,
+  type ParsedLinkWalletIdentityInstruction,
+  type ParsedMigrateAccountInstruction,
+  type ParsedPruneListRegistryInstruction,
+  type ParsedReclaimWalletEntryInstruction,
+  type ParsedRemoveBucketWalletsInstruction,
   type ParsedRemoveWalletInstruction,
+  type ParsedRotateWalletInstruction,
+  type ParsedSetActivationWindowInstruction,
+  type ParsedSetAttributePolicyInstruction,
+  type ParsedSetGuardianInstruction,
+  type ParsedSetIdentityStatusInstruction,
+  type ParsedSetListLimitsInstruction,
+  type ParsedSetListPausedInstruction,
+  type ParsedSetOpenEnrollmentInstruction,
+  type ParsedSetRiskThresholdInstruction,
+  type ParsedSetSelfRotationInstruction,
+  type ParsedSetSelfThawOnlyInstruction,
+  type ParsedSetWalletAttributesInstruction,
   type ParsedSetupExtraMetasInstruction,
+  type ParsedSetupTransferHookExtraMetasInstruction,
+  type ParsedUpdateListMetadataInstruction,
 } from '../instructions';
 
 export const ABL_PROGRAM_ADDRESS =
@@ -26,6 +54,12 @@ export const ABL_PROGRAM_ADDRESS =
 export enum AblAccount {
   ListConfig,
   WalletEntry,
+  Bucket,
+  Identity,
+  AuditLog,
+  DepositVault,
+  ListRegistry,
+  ListMetadata,
 }
 
 export function identifyAblAccount(
@@ -38,6 +72,24 @@ export function identifyAblAccount(
   if (containsBytes(data, getU8Encoder().encode(2), 0)) {
     return AblAccount.WalletEntry;
   }
+  if (containsBytes(data, getU8Encoder().encode(3), 0)) {
+    return AblAccount.Bucket;
+  }
+  if (containsBytes(data, getU8Encoder().encode(4), 0)) {
+    return AblAccount.Identity;
+  }
+  if (containsBytes(data, getU8Encoder().encode(5), 0)) {
+    return AblAccount.AuditLog;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return AblAccount.DepositVault;
+  }
+  if (containsBytes(data, getU8Encoder().encode(8), 0)) {
+    return AblAccount.ListRegistry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return AblAccount.ListMetadata;
+  }
   throw new Error(
     'The provided account could not be identified as a abl account.'
   );
@@ -49,6 +101,34 @@ export enum AblInstruction {
   RemoveWallet,
   SetupExtraMetas,
   DeleteList,
+  IsWalletPermitted,
+  AddBucketWallets,
+  RemoveBucketWallets,
+  SetupTransferHookExtraMetas,
+  SetRiskThreshold,
+  CreateIdentity,
+  SetIdentityStatus,
+  LinkWalletIdentity,
+  SetWalletAttributes,
+  SetAttributePolicy,
+  SetListLimits,
+  SetGuardian,
+  SetListPaused,
+  SetActivationWindow,
+  CreateMintList,
+  CreateAuditLog,
+  MigrateAccount,
+  AddWalletIdempotent,
+  ForceDeleteList,
+  ReclaimWalletEntry,
+  SetSelfRotation,
+  RotateWallet,
+  SetOpenEnrollment,
+  Enroll,
+  EvictWallet,
+  SetSelfThawOnly,
+  UpdateListMetadata,
+  PruneListRegistry,
 }
 
 export function identifyAblInstruction(
@@ -70,6 +150,90 @@ export function identifyAblInstruction(
   if (containsBytes(data, getU8Encoder().encode(5), 0)) {
     return AblInstruction.DeleteList;
   }
+  if (containsBytes(data, getU8Encoder().encode(6), 0)) {
+    return AblInstruction.IsWalletPermitted;
+  }
+  if (containsBytes(data, getU8Encoder().encode(7), 0)) {
+    return AblInstruction.AddBucketWallets;
+  }
+  if (containsBytes(data, getU8Encoder().encode(9), 0)) {
+    return AblInstruction.RemoveBucketWallets;
+  }
+  if (containsBytes(data, getU8Encoder().encode(10), 0)) {
+    return AblInstruction.SetupTransferHookExtraMetas;
+  }
+  if (containsBytes(data, getU8Encoder().encode(11), 0)) {
+    return AblInstruction.SetRiskThreshold;
+  }
+  if (containsBytes(data, getU8Encoder().encode(12), 0)) {
+    return AblInstruction.CreateIdentity;
+  }
+  if (containsBytes(data, getU8Encoder().encode(13), 0)) {
+    return AblInstruction.SetIdentityStatus;
+  }
+  if (containsBytes(data, getU8Encoder().encode(14), 0)) {
+    return AblInstruction.LinkWalletIdentity;
+  }
+  if (containsBytes(data, getU8Encoder().encode(15), 0)) {
+    return AblInstruction.SetWalletAttributes;
+  }
+  if (containsBytes(data, getU8Encoder().encode(16), 0)) {
+    return AblInstruction.SetAttributePolicy;
+  }
+  if (containsBytes(data, getU8Encoder().encode(17), 0)) {
+    return AblInstruction.SetListLimits;
+  }
+  if (containsBytes(data, getU8Encoder().encode(18), 0)) {
+    return AblInstruction.SetGuardian;
+  }
+  if (containsBytes(data, getU8Encoder().encode(19), 0)) {
+    return AblInstruction.SetListPaused;
+  }
+  if (containsBytes(data, getU8Encoder().encode(20), 0)) {
+    return AblInstruction.SetActivationWindow;
+  }
+  if (containsBytes(data, getU8Encoder().encode(21), 0)) {
+    return AblInstruction.CreateMintList;
+  }
+  if (containsBytes(data, getU8Encoder().encode(22), 0)) {
+    return AblInstruction.CreateAuditLog;
+  }
+  if (containsBytes(data, getU8Encoder().encode(23), 0)) {
+    return AblInstruction.MigrateAccount;
+  }
+  if (containsBytes(data, getU8Encoder().encode(24), 0)) {
+    return AblInstruction.AddWalletIdempotent;
+  }
+  if (containsBytes(data, getU8Encoder().encode(25), 0)) {
+    return AblInstruction.ForceDeleteList;
+  }
+  if (containsBytes(data, getU8Encoder().encode(26), 0)) {
+    return AblInstruction.ReclaimWalletEntry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(27), 0)) {
+    return AblInstruction.SetSelfRotation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(28), 0)) {
+    return AblInstruction.RotateWallet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(29), 0)) {
+    return AblInstruction.SetOpenEnrollment;
+  }
+  if (containsBytes(data, getU8Encoder().encode(30), 0)) {
+    return AblInstruction.Enroll;
+  }
+  if (containsBytes(data, getU8Encoder().encode(31), 0)) {
+    return AblInstruction.EvictWallet;
+  }
+  if (containsBytes(data, getU8Encoder().encode(32), 0)) {
+    return AblInstruction.SetSelfThawOnly;
+  }
+  if (containsBytes(data, getU8Encoder().encode(33), 0)) {
+    return AblInstruction.UpdateListMetadata;
+  }
+  if (containsBytes(data, getU8Encoder().encode(34), 0)) {
+    return AblInstruction.PruneListRegistry;
+  }
   throw new Error(
     'The provided instruction could not be identified as a abl instruction.'
   );
@@ -92,4 +256,88 @@ export type ParsedAblInstruction<
     } & ParsedSetupExtraMetasInstruction<TProgram>)
   | ({
       instructionType: AblInstruction.DeleteList;
-    } & ParsedDeleteListInstruction<TProgram>);
+    } & ParsedDeleteListInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.IsWalletPermitted;
+    } & ParsedIsWalletPermittedInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.AddBucketWallets;
+    } & ParsedAddBucketWalletsInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.RemoveBucketWallets;
+    } & ParsedRemoveBucketWalletsInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetupTransferHookExtraMetas;
+    } & ParsedSetupTransferHookExtraMetasInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetRiskThreshold;
+    } & ParsedSetRiskThresholdInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.CreateIdentity;
+    } & ParsedCreateIdentityInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetIdentityStatus;
+    } & ParsedSetIdentityStatusInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.LinkWalletIdentity;
+    } & ParsedLinkWalletIdentityInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetWalletAttributes;
+    } & ParsedSetWalletAttributesInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetAttributePolicy;
+    } & ParsedSetAttributePolicyInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetListLimits;
+    } & ParsedSetListLimitsInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetGuardian;
+    } & ParsedSetGuardianInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetListPaused;
+    } & ParsedSetListPausedInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetActivationWindow;
+    } & ParsedSetActivationWindowInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.CreateMintList;
+    } & ParsedCreateMintListInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.CreateAuditLog;
+    } & ParsedCreateAuditLogInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.MigrateAccount;
+    } & ParsedMigrateAccountInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.AddWalletIdempotent;
+    } & ParsedAddWalletIdempotentInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.ForceDeleteList;
+    } & ParsedForceDeleteListInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.ReclaimWalletEntry;
+    } & ParsedReclaimWalletEntryInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetSelfRotation;
+    } & ParsedSetSelfRotationInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.RotateWallet;
+    } & ParsedRotateWalletInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetOpenEnrollment;
+    } & ParsedSetOpenEnrollmentInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.Enroll;
+    } & ParsedEnrollInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.EvictWallet;
+    } & ParsedEvictWalletInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.SetSelfThawOnly;
+    } & ParsedSetSelfThawOnlyInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.UpdateListMetadata;
+    } & ParsedUpdateListMetadataInstruction<TProgram>)
+  | ({
+      instructionType: AblInstruction.PruneListRegistry;
+    } & ParsedPruneListRegistryInstruction<TProgram>);
@@ -6,4 +6,8 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './auditRecord';
+export * from './identityStatus';
 export * from './mode';
+export * from './registeredList';
+export * from './storage';
@@ -19,6 +19,8 @@ export enum Mode {
   Allow,
   AllowAllEoas,
   Block,
+  RiskScore,
+  Attributes,
 }
 
 export type ModeArgs = Mode;